@@ -1,9 +1,11 @@
-use std::error::Error;
 use std::path::{Path, PathBuf};
 use std::{fs, io};
 
-use crate::generate::generate;
+use crate::error::{locate, parse_diagnostics, Diagnostic, QObjectBuildError};
+use crate::generate::generate_all;
 use crate::moc::MocConfig;
+use crate::qgadget::QGadgetConfig;
+use crate::qmltypes::{generate_qmldir, generate_qmltypes, QmlModule};
 use crate::qobject::QObjectConfig;
 use crate::CcBuild;
 
@@ -31,7 +33,18 @@ pub fn build(
     moc: &MocConfig,
     path: &Path,
     obj: &QObjectConfig,
-) -> Result<(), Box<dyn Error>> {
+) -> Result<(), QObjectBuildError> {
+    build_all(cpp, moc, path, &[], &[obj])
+}
+
+/// Build many gadgets and classes into one C++ source file, one moc file and one Rust file.
+pub fn build_all(
+    cpp: &CcBuild,
+    moc: &MocConfig,
+    path: &Path,
+    gadgets: &[&QGadgetConfig],
+    objs: &[&QObjectConfig],
+) -> Result<(), QObjectBuildError> {
     let moc_path: PathBuf = path.with_extension("moc");
     let rs_path: PathBuf = path.with_extension("rs");
     let output_name = path.file_stem().unwrap().to_str().unwrap();
@@ -43,22 +56,78 @@ pub fn build(
         .ok_or_else(|| invalid_arg("input path is not valid UTF-8"))?;
 
     // Generate
-    let (cpp_code, rust_code) = generate(&moc_name, &[obj]);
+    let (cpp_code, rust_code) = generate_all(&moc_name, gadgets, objs)?;
     let cpp_changed = write_when_changed(path, &cpp_code)?;
     write_when_changed(&rs_path, &rust_code)?;
 
     if cpp_changed {
         // MOC
-        moc.build(path, &moc_path)?;
+        if let Err(mut err) = moc.build(path, &moc_path) {
+            // run moc again in the next build
+            let _ = fs::remove_file(path);
+            if let QObjectBuildError::Moc { diagnostics, .. } = &mut err {
+                add_context(diagnostics, path, &cpp_code);
+            }
+            return Err(err);
+        }
     }
 
     // C++
-    cpp.clone()
-        .cpp(true)
-        .file(path)
-        .static_flag(true)
-        .warnings(false)
-        .try_compile(output_name)?;
+    let mut cpp = cpp.clone();
+    cpp.cpp(true).file(path).static_flag(true).warnings(false);
+    if let Err(error) = cpp.try_compile(output_name) {
+        let mut diagnostics = compiler_diagnostics(&cpp, path);
+        add_context(&mut diagnostics, path, &cpp_code);
+        return Err(QObjectBuildError::Compile { diagnostics, error });
+    }
+
+    Ok(())
+}
+
+/// Describe the generated items the diagnostics for the generated file `path` refer to.
+fn add_context(diagnostics: &mut [Diagnostic], path: &Path, code: &str) {
+    for diagnostic in diagnostics {
+        if diagnostic.file.file_name() != path.file_name() {
+            continue;
+        }
+        if let Some(line) = diagnostic.line {
+            diagnostic.context = locate(code, line);
+        }
+    }
+}
+
+/// Run only the syntax check of the C++ compiler to get its diagnostics.
+///
+/// `cc` forwards the compiler output only as cargo warnings.
+fn compiler_diagnostics(cpp: &CcBuild, path: &Path) -> Vec<Diagnostic> {
+    let tool = match cpp.try_get_compiler() {
+        Ok(tool) => tool,
+        Err(_) => return vec![],
+    };
+    if tool.is_like_msvc() {
+        return vec![];
+    }
+    match tool.to_command().arg("-fsyntax-only").arg(path).output() {
+        Ok(output) => parse_diagnostics(&String::from_utf8_lossy(&output.stderr)),
+        Err(_) => vec![],
+    }
+}
 
+/// Write `qmldir` and `plugins.qmltypes` files of a QML module.
+///
+/// The files are written to the directory of the module or to `out_dir` if the module has none.
+pub fn build_qml_module(
+    module: &QmlModule,
+    out_dir: &Path,
+    gadgets: &[&QGadgetConfig],
+    objs: &[&QObjectConfig],
+) -> io::Result<()> {
+    let dir = module.dir.as_deref().unwrap_or(out_dir);
+    fs::create_dir_all(dir)?;
+    write_when_changed(&dir.join("qmldir"), &generate_qmldir(module))?;
+    write_when_changed(
+        &dir.join("plugins.qmltypes"),
+        &generate_qmltypes(module, gadgets, objs),
+    )?;
     Ok(())
 }
@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 
+use crate::qgadget::QGadgetConfig;
 use crate::qobject::QObjectConfig;
 use crate::{Include, QObjectMethod, QObjectProp, QObjectSignal};
 
@@ -9,26 +10,22 @@ pub trait Dependent {
 
 impl Dependent for QObjectProp {
     fn dependencies(&self, includes: &mut HashSet<Include>) {
-        if let Some(include) = self.type_ref.include() {
-            includes.insert(include.clone());
-        }
+        includes.extend(self.type_ref.includes());
     }
 }
 
 impl Dependent for QObjectMethod {
     fn dependencies(&self, includes: &mut HashSet<Include>) {
         if let Some(rtype) = &self.rtype {
-            if let Some(include) = rtype.include() {
-                includes.insert(include.clone());
-            }
+            includes.extend(rtype.includes());
         }
-        includes.extend(self.args.iter().flat_map(|(_, ty)| ty.include().clone()));
+        includes.extend(self.args.iter().flat_map(|(_, ty)| ty.includes()));
     }
 }
 
 impl Dependent for QObjectSignal {
     fn dependencies(&self, includes: &mut HashSet<Include>) {
-        includes.extend(self.args.iter().flat_map(|(_, ty)| ty.include().clone()));
+        includes.extend(self.args.iter().flat_map(|(_, ty)| ty.includes()));
     }
 }
 
@@ -37,8 +34,12 @@ impl Dependent for QObjectConfig {
         self.properties
             .iter()
             .for_each(|p| p.dependencies(includes));
-        self.methods.iter().for_each(|p| p.dependencies(includes));
-        self.signals.iter().for_each(|p| p.dependencies(includes));
+        self.all_methods()
+            .iter()
+            .for_each(|p| p.dependencies(includes));
+        self.emitted_signals()
+            .iter()
+            .for_each(|p| p.dependencies(includes));
         if let Some(include) = self.base_class.include() {
             includes.insert(include.clone());
         }
@@ -48,3 +49,14 @@ impl Dependent for QObjectConfig {
         }
     }
 }
+
+impl Dependent for QGadgetConfig {
+    fn dependencies(&self, includes: &mut HashSet<Include>) {
+        self.properties
+            .iter()
+            .for_each(|p| p.dependencies(includes));
+        self.methods.iter().for_each(|p| p.dependencies(includes));
+        includes.insert(Include::System("QObject".into())); // required for Q_GADGET
+        includes.insert(Include::System("QVariant".into()));
+    }
+}
@@ -0,0 +1,346 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DiagnosticKind {
+    Error,
+    Warning,
+    Note,
+}
+
+impl fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiagnosticKind::Error => f.write_str("error"),
+            DiagnosticKind::Warning => f.write_str("warning"),
+            DiagnosticKind::Note => f.write_str("note"),
+        }
+    }
+}
+
+/// Diagnostic of moc or the C++ compiler.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Diagnostic {
+    pub file: PathBuf,
+    pub line: Option<u32>,
+    pub column: Option<u32>,
+    pub kind: DiagnosticKind,
+    pub message: String,
+    /// Generated item the diagnostic refers to, like ``property `name` of class `Foo` ``.
+    pub context: Option<String>,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.file.display())?;
+        if let Some(line) = self.line {
+            write!(f, ":{}", line)?;
+        }
+        write!(f, ": {}: {}", self.kind, self.message)?;
+        if let Some(context) = &self.context {
+            write!(f, " (in {})", context)?;
+        }
+        Ok(())
+    }
+}
+
+const DIAGNOSTIC_KINDS: &[(&str, DiagnosticKind)] = &[
+    (": error: ", DiagnosticKind::Error),
+    (": Error: ", DiagnosticKind::Error),
+    (": fatal error: ", DiagnosticKind::Error),
+    (": warning: ", DiagnosticKind::Warning),
+    (": Warning: ", DiagnosticKind::Warning),
+    (": note: ", DiagnosticKind::Note),
+    (": Note: ", DiagnosticKind::Note),
+];
+
+/// Parse a diagnostic in the format `file[:line[:column]]: kind: message`.
+fn parse_diagnostic(line: &str) -> Option<Diagnostic> {
+    let (pos, needle, kind) = DIAGNOSTIC_KINDS
+        .iter()
+        .filter_map(|(needle, kind)| line.find(needle).map(|pos| (pos, *needle, *kind)))
+        .min_by_key(|(pos, _, _)| *pos)?;
+
+    let mut file = &line[..pos];
+    let mut numbers = vec![];
+    while numbers.len() < 2 {
+        match file.rfind(':') {
+            Some(idx) => match file[idx + 1..].parse::<u32>() {
+                Ok(number) => {
+                    numbers.insert(0, number);
+                    file = &file[..idx];
+                }
+                Err(_) => break,
+            },
+            None => break,
+        }
+    }
+    if file.is_empty() {
+        return None;
+    }
+
+    Some(Diagnostic {
+        file: file.into(),
+        line: numbers.first().copied(),
+        column: numbers.get(1).copied(),
+        kind,
+        message: line[pos + needle.len()..].trim().to_string(),
+        context: None,
+    })
+}
+
+/// Parse diagnostics of moc or a GCC compatible compiler.
+pub(crate) fn parse_diagnostics(output: &str) -> Vec<Diagnostic> {
+    output.lines().filter_map(parse_diagnostic).collect()
+}
+
+fn identifier_before_paren(text: &str) -> Option<&str> {
+    let name = text[..text.find('(')?].split_whitespace().last()?;
+    let name = name.trim_start_matches(['*', '&']);
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+fn property_name(text: &str) -> Option<&str> {
+    const KEYWORDS: &[&str] = &[
+        "MEMBER",
+        "READ",
+        "WRITE",
+        "RESET",
+        "NOTIFY",
+        "REVISION",
+        "DESIGNABLE",
+        "SCRIPTABLE",
+        "STORED",
+        "USER",
+        "CONSTANT",
+        "FINAL",
+    ];
+    let start = text.find('(')? + 1;
+    let end = text.rfind(')')?;
+    let words: Vec<&str> = text[start..end].split_whitespace().collect();
+    let end = words
+        .iter()
+        .position(|word| KEYWORDS.contains(word))
+        .unwrap_or(words.len());
+    if end < 2 {
+        None
+    } else {
+        Some(words[end - 1])
+    }
+}
+
+/// Describe the generated item at `line` (1-based) of the generated C++ code.
+pub(crate) fn locate(code: &str, line: u32) -> Option<String> {
+    let lines: Vec<&str> = code.lines().collect();
+    let idx = (line as usize).checked_sub(1)?;
+    let text = lines.get(idx)?.trim();
+
+    // out-of-line definition like `void Foo::bar(int value) {`
+    if let Some(name) = identifier_before_paren(text) {
+        if let Some(pos) = name.rfind("::") {
+            return Some(format!(
+                "method `{}` of class `{}`",
+                &name[pos + 2..],
+                &name[..pos]
+            ));
+        }
+    }
+
+    let class = lines[..=idx].iter().rev().find_map(|line| {
+        let line = line.trim_end();
+        if line.ends_with(';') {
+            return None;
+        }
+        line.strip_prefix("class ")
+            .and_then(|rest| rest.split([' ', '{']).next())
+    })?;
+
+    if text.starts_with("Q_PROPERTY(") {
+        if let Some(name) = property_name(text) {
+            return Some(format!("property `{}` of class `{}`", name, class));
+        }
+    } else if !text.starts_with("Q_") {
+        if let Some(name) = identifier_before_paren(text) {
+            return Some(format!("method `{}` of class `{}`", name, class));
+        }
+    }
+    Some(format!("class `{}`", class))
+}
+
+/// Error of building generated classes.
+#[derive(Debug)]
+pub enum QObjectBuildError {
+    /// The configuration of classes is invalid.
+    Generate(Vec<String>),
+    /// moc failed.
+    Moc {
+        diagnostics: Vec<Diagnostic>,
+        output: String,
+    },
+    /// Compiling the generated C++ code failed.
+    Compile {
+        diagnostics: Vec<Diagnostic>,
+        error: cc::Error,
+    },
+    Io(io::Error),
+}
+
+impl QObjectBuildError {
+    /// Diagnostics of moc or the C++ compiler.
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        match self {
+            QObjectBuildError::Moc { diagnostics, .. } => diagnostics,
+            QObjectBuildError::Compile { diagnostics, .. } => diagnostics,
+            _ => &[],
+        }
+    }
+
+    /// Cargo instructions to show the error as warnings.
+    pub fn cargo_warnings(&self) -> Vec<String> {
+        let mut result = match self {
+            QObjectBuildError::Generate(errors) => errors
+                .iter()
+                .map(|error| format!("cargo:warning={}", error))
+                .collect(),
+            _ => vec![format!("cargo:warning={}", self)],
+        };
+        result.extend(
+            self.diagnostics()
+                .iter()
+                .map(|diagnostic| format!("cargo:warning={}", diagnostic)),
+        );
+        result
+    }
+}
+
+impl fmt::Display for QObjectBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QObjectBuildError::Generate(errors) => {
+                write!(f, "invalid configuration: {}", errors.join("; "))
+            }
+            QObjectBuildError::Moc { diagnostics, .. } => {
+                write!(f, "moc failed with {} diagnostics", diagnostics.len())
+            }
+            QObjectBuildError::Compile { error, .. } => {
+                write!(f, "failed to compile generated C++ code: {}", error)
+            }
+            QObjectBuildError::Io(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for QObjectBuildError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            QObjectBuildError::Compile { error, .. } => Some(error),
+            QObjectBuildError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for QObjectBuildError {
+    fn from(error: io::Error) -> Self {
+        QObjectBuildError::Io(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CODE: &str = "class Foo;\n\
+                        \n\
+                        class Foo : public QObject {\n\
+                        \x20 Q_OBJECT\n\
+                        \n\
+                        \x20 Q_PROPERTY(QString name READ name NOTIFY nameChanged);\n\
+                        \n\
+                        public:\n\
+                        \x20 QString name() const;\n\
+                        };\n\
+                        \n\
+                        QString Foo::name() const {\n";
+
+    #[test]
+    fn test_parse_moc_diagnostic() {
+        let diagnostics = parse_diagnostics("/tmp/out/qffi_Foo.cpp:12: Error: Undefined interface");
+        assert_eq!(
+            vec![Diagnostic {
+                file: "/tmp/out/qffi_Foo.cpp".into(),
+                line: Some(12),
+                column: None,
+                kind: DiagnosticKind::Error,
+                message: "Undefined interface".into(),
+                context: None,
+            }],
+            diagnostics
+        );
+    }
+
+    #[test]
+    fn test_parse_compiler_diagnostic() {
+        let diagnostics =
+            parse_diagnostics("C:\\out\\qffi_Foo.cpp:12:5: warning: unused variable 'x'\nmore");
+        assert_eq!(1, diagnostics.len());
+        assert_eq!(PathBuf::from("C:\\out\\qffi_Foo.cpp"), diagnostics[0].file);
+        assert_eq!(Some(12), diagnostics[0].line);
+        assert_eq!(Some(5), diagnostics[0].column);
+        assert_eq!(DiagnosticKind::Warning, diagnostics[0].kind);
+    }
+
+    #[test]
+    fn test_parse_no_diagnostic() {
+        assert!(parse_diagnostics("In file included from qffi_Foo.cpp:3").is_empty());
+    }
+
+    #[test]
+    fn test_locate_property() {
+        assert_eq!(
+            Some("property `name` of class `Foo`".to_string()),
+            locate(CODE, 6)
+        );
+    }
+
+    #[test]
+    fn test_locate_method() {
+        assert_eq!(
+            Some("method `name` of class `Foo`".to_string()),
+            locate(CODE, 9)
+        );
+        assert_eq!(
+            Some("method `name` of class `Foo`".to_string()),
+            locate(CODE, 12)
+        );
+    }
+
+    #[test]
+    fn test_locate_class() {
+        assert_eq!(Some("class `Foo`".to_string()), locate(CODE, 4));
+        assert_eq!(None, locate(CODE, 1));
+        assert_eq!(None, locate(CODE, 100));
+    }
+
+    #[test]
+    fn test_display_diagnostic() {
+        let diagnostic = Diagnostic {
+            file: "qffi_Foo.cpp".into(),
+            line: Some(6),
+            column: None,
+            kind: DiagnosticKind::Error,
+            message: "Parse error".into(),
+            context: Some("property `name` of class `Foo`".into()),
+        };
+        assert_eq!(
+            "qffi_Foo.cpp:6: error: Parse error (in property `name` of class `Foo`)",
+            diagnostic.to_string()
+        );
+    }
+}
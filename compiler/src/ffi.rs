@@ -93,7 +93,7 @@ impl FfiFunction {
         let mut args: Vec<String> = self
             .args
             .iter()
-            .map(|arg| format!("{} {}", arg.1.cpp_type(), arg.0))
+            .map(|arg| format!("{} {}", arg.1.ffi_arg_type().cpp_type(), arg.0))
             .collect();
         let rtype: String = if let Some(rty) = &self.rtype {
             if rty.return_safe() {
@@ -136,7 +136,7 @@ impl FfiFunction {
         let mut args: Vec<String> = self
             .args
             .iter()
-            .map(|arg| format!("{}: {}", arg.0, arg.1.rust_type()))
+            .map(|arg| format!("{}: {}", arg.0, arg.1.ffi_arg_type().rust_type()))
             .collect();
         let rtype: String = if let Some(rty) = &self.rtype {
             if rty.return_safe() {
@@ -202,6 +202,8 @@ impl FfiBridge {
 
 #[cfg(test)]
 mod tests {
+    use crate::typeref::QString;
+
     use super::*;
 
     #[test]
@@ -215,7 +217,7 @@ mod tests {
         let def = &FfiFunction::new("test")
             .arg_with_type("arg0", TypeRef::qt_core_object("CppType"))
             .generate_cpp_def();
-        assert_eq!("extern \"C\" void test(CppType arg0);", def);
+        assert_eq!("extern \"C\" void test(const CppType& arg0);", def);
     }
 
     #[test]
@@ -224,7 +226,10 @@ mod tests {
             .arg_with_type("arg0", TypeRef::qt_core_object("CppType0"))
             .arg_with_type("arg1", TypeRef::qt_core_object("CppType1"))
             .generate_cpp_def();
-        assert_eq!(def, "extern \"C\" void test(CppType0 arg0, CppType1 arg1);");
+        assert_eq!(
+            def,
+            "extern \"C\" void test(const CppType0& arg0, const CppType1& arg1);"
+        );
     }
 
     #[test]
@@ -235,7 +240,27 @@ mod tests {
             .ret_type(TypeRef::qt_core_object("RetCppType"))
             .generate_cpp_def();
         assert_eq!(
-            "extern \"C\" void test(CppType0 arg0, CppType1 arg1, RetCppType* out__);",
+            "extern \"C\" void test(const CppType0& arg0, const CppType1& arg1, RetCppType* out__);",
+            def
+        );
+    }
+
+    #[test]
+    fn test_cpp_def_return_safe_arg() {
+        let def = &FfiFunction::new("test")
+            .arg::<i32>("arg0")
+            .generate_cpp_def();
+        assert_eq!("extern \"C\" void test(qint32 arg0);", def);
+    }
+
+    #[test]
+    fn test_rust_def_by_value_arg() {
+        let def = &FfiFunction::new("test")
+            .arg::<QString>("arg0")
+            .arg::<i32>("arg1")
+            .generate_rust_def();
+        assert_eq!(
+            "  pub fn test(arg0: &qt5qml::core::QString, arg1: i32) -> ();",
             def
         );
     }
@@ -3,29 +3,65 @@ use std::ffi::CStr;
 use std::fmt::Write;
 
 use crate::dependent::Dependent;
+use crate::error::QObjectBuildError;
 use crate::ffi::{FfiBridge, FfiFunction, ImplCode};
 use crate::generate_rust::generate_rust;
-use crate::qobject::{QObjectConfig, QObjectMethod, QObjectProp, QObjectSignal};
-use crate::typeref::{Include, TypeRef};
+use crate::qgadget::QGadgetConfig;
+use crate::qobject::{
+    PanicPolicy, QObjectConfig, QObjectEnum, QObjectMethod, QObjectProp, QObjectSignal,
+};
+use crate::typeref::{Include, QVariant, TypeRef};
 use crate::utils::to_snake_case;
 
 trait GenerateCppCode: Dependent {
+    fn class_name(&self) -> &str;
     fn fill_ffi_functions(&self, ffi: &mut FfiBridge);
     fn generate_forward_definitions(&self, result: &mut String);
     fn generate_classes(&self, result: &mut String, friend_func: &[&FfiFunction]);
     fn generate_implementations(&self, result: &mut String);
 }
 
-pub fn generate(moc_name: &str, objects: &[&QObjectConfig]) -> (String, String) {
+#[cfg(test)]
+pub fn generate(
+    moc_name: &str,
+    objects: &[&QObjectConfig],
+) -> Result<(String, String), QObjectBuildError> {
+    generate_all(moc_name, &[], objects)
+}
+
+/// Generate code for gadgets and classes.
+///
+/// Gadgets are generated before the classes, so they can be used by value in the classes.
+/// The gadgets and classes are validated before, all errors are returned at once.
+pub fn generate_all(
+    moc_name: &str,
+    gadgets: &[&QGadgetConfig],
+    objects: &[&QObjectConfig],
+) -> Result<(String, String), QObjectBuildError> {
+    let errors: Vec<String> = gadgets
+        .iter()
+        .map(|gadget| gadget.validate())
+        .chain(objects.iter().map(|obj| obj.validate()))
+        .filter_map(Result::err)
+        .flatten()
+        .collect();
+    if !errors.is_empty() {
+        return Err(QObjectBuildError::Generate(errors));
+    }
+
+    let mut classes: Vec<&dyn GenerateCppCode> = vec![];
+    classes.extend(gadgets.iter().map(|g| *g as &dyn GenerateCppCode));
+    classes.extend(objects.iter().map(|o| *o as &dyn GenerateCppCode));
+
     let mut ffi = FfiBridge::new();
-    for obj in objects {
-        obj.fill_ffi_functions(&mut ffi);
+    for cls in &classes {
+        cls.fill_ffi_functions(&mut ffi);
     }
 
-    (
-        generate_cpp(moc_name, objects, &ffi),
-        generate_rust(objects, &ffi),
-    )
+    Ok((
+        generate_cpp(moc_name, &classes, &ffi),
+        generate_rust(gadgets, objects, &ffi),
+    ))
 }
 
 fn generate_include(include: &Include) -> String {
@@ -52,6 +88,11 @@ fn generate_base_function_def(
 }
 
 fn generate_prop_def(writer: &mut String, prop: &QObjectProp) {
+    let member = prop
+        .member
+        .as_ref()
+        .map(|member| format!(" MEMBER {}", member))
+        .unwrap_or_default();
     let read = prop
         .getter
         .as_ref()
@@ -62,38 +103,79 @@ fn generate_prop_def(writer: &mut String, prop: &QObjectProp) {
         .as_ref()
         .map(|setter| format!(" WRITE {}", setter))
         .unwrap_or_default();
+    let reset = prop
+        .reset
+        .as_ref()
+        .map(|reset| format!(" RESET {}", reset))
+        .unwrap_or_default();
     let notify = prop
         .signal
         .as_ref()
         .map(|signal| format!(" NOTIFY {}", signal))
         .unwrap_or_default();
+    let revision = prop
+        .revision
+        .map(|revision| format!(" REVISION {}", revision))
+        .unwrap_or_default();
+    let designable = prop
+        .designable
+        .map(|value| format!(" DESIGNABLE {}", value))
+        .unwrap_or_default();
+    let scriptable = prop
+        .scriptable
+        .map(|value| format!(" SCRIPTABLE {}", value))
+        .unwrap_or_default();
+    let stored = prop
+        .stored
+        .map(|value| format!(" STORED {}", value))
+        .unwrap_or_default();
+    let user = if prop.user { " USER true" } else { "" };
     let const_ = if prop.const_ { " CONSTANT" } else { "" };
+    let final_ = if prop.final_ { " FINAL" } else { "" };
 
     let _ = writeln!(
         writer,
-        "  Q_PROPERTY({} {}{}{}{}{});",
+        "  Q_PROPERTY({} {}{}{}{}{}{}{}{}{}{}{}{}{});",
         prop.type_ref.cpp_type(),
         prop.name,
+        member,
         read,
         write,
+        reset,
         notify,
-        const_
+        revision,
+        designable,
+        scriptable,
+        stored,
+        user,
+        const_,
+        final_
     );
 }
 
-fn generate_method_impl(meth: &QObjectMethod) -> String {
+fn generate_method_decl(meth: &QObjectMethod) -> String {
     let scriptable = if meth.scriptable { "Q_SCRIPTABLE " } else { "" };
     let invokable = if meth.invokable { "Q_INVOKABLE " } else { "" };
     let const_ = if meth.const_ { " const" } else { "" };
     let override_ = if meth.override_ { " override" } else { "" };
 
     format!(
-        "  {}{}{}{}{} {{\n    {}\n  }}",
+        "  {}{}{}{}{};",
         scriptable,
         invokable,
         generate_base_function_def(&meth.name, &meth.args, &meth.rtype),
         const_,
         override_,
+    )
+}
+
+fn generate_method_impl(cls: &str, meth: &QObjectMethod) -> String {
+    let const_ = if meth.const_ { " const" } else { "" };
+
+    format!(
+        "{}{} {{\n    {}\n}}",
+        generate_base_function_def(&format!("{}::{}", cls, meth.name), &meth.args, &meth.rtype),
+        const_,
         generate_ffi_impl(meth),
     )
 }
@@ -128,6 +210,56 @@ fn generate_ffi_impl(meth: &QObjectMethod) -> String {
     }
 }
 
+fn generate_auto_prop_decl(prop: &QObjectProp) -> String {
+    format!(
+        "  {} {}() const;\n  void {}({} value);\n",
+        prop.type_ref.cpp_type(),
+        prop.getter.as_ref().unwrap(),
+        prop.setter.as_ref().unwrap(),
+        prop.auto_value_type().cpp_type(),
+    )
+}
+
+fn generate_auto_prop_impl(cls: &QObjectConfig, prop: &QObjectProp) -> String {
+    let member = prop.auto_member();
+    let emit = match cls
+        .signals
+        .iter()
+        .find(|signal| Some(&signal.name) == prop.signal.as_ref())
+    {
+        Some(signal) if signal.args.is_empty() => format!("\n    Q_EMIT {}();", signal.name),
+        Some(signal) => format!("\n    Q_EMIT {}({});", signal.name, member),
+        None => String::new(),
+    };
+
+    format!(
+        "{0} {1}::{2}() const {{\n    return {3};\n}}\n\n\
+         void {1}::{4}({5} value) {{\n    if ({3} == value)\n        return;\n    {3} = value;{6}\n}}\n\n",
+        prop.type_ref.cpp_type(),
+        cls.name,
+        prop.getter.as_ref().unwrap(),
+        member,
+        prop.setter.as_ref().unwrap(),
+        prop.auto_value_type().cpp_type(),
+        emit,
+    )
+}
+
+fn generate_enum(enum_: &QObjectEnum) -> String {
+    let mut result = format!("  enum {} {{\n", enum_.name);
+    for (name, value) in enum_.resolved_values() {
+        let _ = writeln!(result, "    {} = {},", name, value);
+    }
+    result.push_str("  };\n");
+    if let Some(flags) = &enum_.flags {
+        let _ = writeln!(result, "  Q_DECLARE_FLAGS({}, {})", flags, enum_.name);
+        let _ = writeln!(result, "  Q_FLAG({})", flags);
+    } else {
+        let _ = writeln!(result, "  Q_ENUM({})", enum_.name);
+    }
+    result
+}
+
 fn generate_signal(signal: &QObjectSignal) -> String {
     format!(
         "{};",
@@ -135,7 +267,7 @@ fn generate_signal(signal: &QObjectSignal) -> String {
     )
 }
 
-fn generate_cpp(moc_name: &str, objects: &[&QObjectConfig], ffi: &FfiBridge) -> String {
+fn generate_cpp(moc_name: &str, objects: &[&dyn GenerateCppCode], ffi: &FfiBridge) -> String {
     let mut result: String = String::with_capacity(4 * 1024);
 
     // header
@@ -154,18 +286,15 @@ fn generate_cpp(moc_name: &str, objects: &[&QObjectConfig], ffi: &FfiBridge) ->
         result.push('\n');
     }
 
-    // forward/extern definitions
+    // forward definitions
     result.push('\n');
     for obj in objects {
         obj.generate_forward_definitions(&mut result);
     }
-    for function in ffi.get_rust_functions() {
-        let _ = writeln!(result, "{}", function.generate_cpp_def());
-        result.push('\n');
-    }
     for function in ffi.get_cpp_functions() {
-        let _ = writeln!(result, "{}", function.generate_cpp_def());
-        result.push('\n');
+        if function.get_friend_class().is_some() {
+            let _ = writeln!(result, "{}", function.generate_cpp_def());
+        }
     }
 
     // classes
@@ -174,11 +303,28 @@ fn generate_cpp(moc_name: &str, objects: &[&QObjectConfig], ffi: &FfiBridge) ->
         let friends: Vec<&FfiFunction> = ffi
             .get_cpp_functions()
             .iter()
-            .filter(|f| f.get_friend_class().map(|f| f == obj.name).unwrap_or(false))
+            .filter(|f| {
+                f.get_friend_class()
+                    .map(|f| f == obj.class_name())
+                    .unwrap_or(false)
+            })
             .collect();
         obj.generate_classes(&mut result, &friends);
     }
 
+    // extern definitions
+    result.push('\n');
+    for function in ffi.get_rust_functions() {
+        let _ = writeln!(result, "{}", function.generate_cpp_def());
+        result.push('\n');
+    }
+    for function in ffi.get_cpp_functions() {
+        if function.get_friend_class().is_none() {
+            let _ = writeln!(result, "{}", function.generate_cpp_def());
+            result.push('\n');
+        }
+    }
+
     // impls
     result.push('\n');
     for obj in objects {
@@ -213,25 +359,55 @@ fn gen_cpp_meth_call(meth: &QObjectMethod) -> String {
 }
 
 fn gen_rust_meth_call(cls: &str, meth: &QObjectMethod) -> String {
-    let params: Vec<&str> = meth.args.iter().map(|a| &a.0 as &str).collect();
-    let ret = match &meth.rtype {
-        Some(ref rty) if !rty.return_safe() => "*out__ = ",
-        _ => "",
+    let private = if meth.const_ {
+        format!("&*(self_ as *const {}Private)", cls)
+    } else {
+        format!("&mut *(self_ as *mut {}Private)", cls)
     };
-    format!(
-        "unsafe {{ {}(*(self_ as *mut {}Private)).{}({}) }}",
-        ret,
+    let mut params: Vec<String> = meth.args.iter().map(gen_rust_arg_value).collect();
+    params.insert(0, private);
+    let call = format!(
+        "<{0}Private as {0}Impl>::{1}({2})",
         cls,
         to_snake_case(&meth.name),
         params.join(", ")
-    )
+    );
+    match &meth.rtype {
+        Some(ref rty) if !rty.return_safe() => {
+            format!("unsafe {{ *out__ = {} }}", rty.rust_into_ffi(&call))
+        }
+        Some(ref rty) => format!("unsafe {{ {} }}", rty.rust_into_ffi(&call)),
+        None => format!("unsafe {{ {} }}", call),
+    }
+}
+
+/// Rust expression for the value of the FFI argument `arg` in the Rust API type.
+///
+/// Arguments which are not return safe are passed by reference and are cloned for the call,
+/// unless the conversion into the Rust API type only needs a reference.
+fn gen_rust_arg_value(arg: &(String, TypeRef)) -> String {
+    if arg.1.return_safe() || arg.1.converts_from_ref() {
+        arg.1.rust_from_ffi(&arg.0)
+    } else {
+        arg.1.rust_from_ffi(&format!("{}.clone()", arg.0))
+    }
+}
+
+/// Rust implementation of the FFI function of a method with handling of panics.
+fn gen_rust_meth_impl(cls: &str, meth: &QObjectMethod, policy: &PanicPolicy) -> ImplCode {
+    let rtype = meth.rtype.as_ref().filter(|rtype| rtype.return_safe());
+    ImplCode::Rust(policy.catch_unwind(meth.get_ffi_name(), &gen_rust_meth_call(cls, meth), rtype))
 }
 
 impl GenerateCppCode for QObjectConfig {
+    fn class_name(&self) -> &str {
+        &self.name
+    }
+
     fn fill_ffi_functions(&self, ffi: &mut FfiBridge) {
         let class_type = TypeRef::new(self.name.clone(), self.name.clone(), false, None);
 
-        for meth in self.methods.iter().chain(self.slots.iter()) {
+        for meth in self.all_methods().iter().chain(self.slots.iter()) {
             if let Some(_proxy_class) = &meth.proxy_class {
                 let mut args = meth.args.clone();
                 let cls_ref = if meth.const_ {
@@ -256,32 +432,41 @@ impl GenerateCppCode for QObjectConfig {
                     meth.get_ffi_name(),
                     args,
                     meth.rtype.clone(),
-                    ImplCode::Rust(gen_rust_meth_call(&self.name, &meth)),
+                    gen_rust_meth_impl(&self.name, meth, &self.panic_policy),
                     None,
                 ));
             }
         }
 
+        let private_new = format!("Qffi_{}_private_new", &self.name);
         ffi.rust_function(FfiFunction::new_complete(
-            &format!("Qffi_{}_private_new", &self.name),
+            &private_new,
             vec![(
                 "qobject".into(),
                 TypeRef::generated(self.name.clone()).with_mut_ptr(),
             )],
             Some(TypeRef::void_mut_ptr()),
-            ImplCode::Rust(format!(
-                "Box::into_raw(Box::new({}Private::new(qobject))) as *mut std::ffi::c_void",
-                &self.name
+            ImplCode::Rust(self.panic_policy.catch_unwind_constructor(
+                &private_new,
+                &format!(
+                    "Box::into_raw(Box::new(<{0}Private as {0}Impl>::new(qobject))) as *mut std::ffi::c_void",
+                    &self.name
+                ),
             )),
             None,
         ));
+        let private_delete = format!("Qffi_{}_private_delete", self.name);
         ffi.rust_function(FfiFunction::new_complete(
-            &format!("Qffi_{}_private_delete", self.name),
+            &private_delete,
             vec![("self_".into(), TypeRef::void_mut_ptr())],
             None,
-            ImplCode::Rust(format!(
-                "unsafe {{ drop(Box::from_raw(self_ as *mut {}Private)) }};",
-                &self.name
+            ImplCode::Rust(self.panic_policy.catch_unwind(
+                &private_delete,
+                &format!(
+                    "unsafe {{ drop(Box::from_raw(self_ as *mut {}Private)) }};",
+                    &self.name
+                ),
+                None,
             )),
             None,
         ));
@@ -300,7 +485,7 @@ impl GenerateCppCode for QObjectConfig {
             None,
         ));
 
-        for signal in &self.signals {
+        for signal in &self.emitted_signals() {
             let mut args = signal.args.clone();
             args.insert(
                 0,
@@ -321,6 +506,43 @@ impl GenerateCppCode for QObjectConfig {
             ));
         }
 
+        for prop in self.properties.iter().filter(|prop| prop.auto) {
+            let getter = prop.getter.as_ref().unwrap();
+            let setter = prop.setter.as_ref().unwrap();
+            let getter_body = if prop.type_ref.return_safe() {
+                format!("return self_.{}();", getter)
+            } else {
+                format!(
+                    "new(out__) {}(self_.{}());",
+                    prop.type_ref.cpp_type(),
+                    getter
+                )
+            };
+            ffi.cpp_function(FfiFunction::new_complete(
+                &format!("Qffi_{}_{}", self.name, getter),
+                vec![(
+                    "self_".into(),
+                    TypeRef::generated(self.name.clone()).with_const_ref(),
+                )],
+                Some(prop.type_ref.clone()),
+                ImplCode::Cpp(getter_body),
+                None,
+            ));
+            ffi.cpp_function(FfiFunction::new_complete(
+                &format!("Qffi_{}_{}", self.name, setter),
+                vec![
+                    (
+                        "self_".into(),
+                        TypeRef::generated(self.name.clone()).with_mut_ref(),
+                    ),
+                    ("value".into(), prop.auto_value_type()),
+                ],
+                None,
+                ImplCode::Cpp(format!("self_.{}(value);", setter)),
+                None,
+            ));
+        }
+
         if self.qml {
             ffi.cpp_function(
                 FfiFunction::new(&format!("Qffi_{}_registerType", self.name))
@@ -334,11 +556,48 @@ impl GenerateCppCode for QObjectConfig {
                         self.name
                     )),
             );
+            ffi.cpp_function(
+                FfiFunction::new(&format!("Qffi_{}_registerSingletonType", self.name))
+                    .arg::<&CStr>("uri")
+                    .arg::<i32>("version_major")
+                    .arg::<i32>("version_minor")
+                    .arg::<&CStr>("qml_name")
+                    .arg_with_type("factory", self.singleton_factory_type())
+                    .arg_with_type("data", TypeRef::void_mut_ptr())
+                    .ret::<i32>()
+                    .cpp_impl(&format!(
+                        "return qmlRegisterSingletonType<{}>(\n    \
+                         uri, version_major, version_minor, qml_name,\n    \
+                         [factory, data](QQmlEngine*, QJSEngine*) -> QObject* {{ return factory(data); }});",
+                        self.name
+                    )),
+            );
+            ffi.cpp_function(
+                FfiFunction::new(&format!("Qffi_{}_registerUncreatableType", self.name))
+                    .arg::<&CStr>("uri")
+                    .arg::<i32>("version_major")
+                    .arg::<i32>("version_minor")
+                    .arg::<&CStr>("qml_name")
+                    .arg::<&CStr>("reason")
+                    .ret::<i32>()
+                    .cpp_impl(&format!(
+                        "return qmlRegisterUncreatableType<{}>(\n    \
+                         uri, version_major, version_minor, qml_name, QString::fromUtf8(reason));",
+                        self.name
+                    )),
+            );
         }
     }
 
     fn generate_forward_definitions(&self, result: &mut String) {
         let _ = writeln!(result, "class {};", self.name);
+        if self.qml {
+            let _ = writeln!(
+                result,
+                "using Qffi_{0}_SingletonFactory = {0}* (*)(void* data);",
+                self.name
+            );
+        }
     }
 
     fn generate_classes(&self, result: &mut String, friend_funcs: &[&FfiFunction]) {
@@ -351,6 +610,15 @@ impl GenerateCppCode for QObjectConfig {
         );
         result.push_str("  Q_OBJECT\n");
 
+        // enums
+        if !self.enums.is_empty() {
+            result.push('\n');
+            result.push_str("public:\n");
+            for enum_ in &self.enums {
+                result.push_str(&generate_enum(enum_));
+            }
+        }
+
         // properties
         result.push('\n');
         for prop in &self.properties {
@@ -360,26 +628,18 @@ impl GenerateCppCode for QObjectConfig {
         // ctor and dtor
         result.push('\n');
         result.push_str("public:\n");
-        let _ = writeln!(
-            result,
-            "  {0}(QObject* parent = nullptr) \
-  : {1}(parent) \
-  {{ _d = Qffi_{0}_private_new(this); }}\n",
-            &self.name,
-            self.base_class.cpp_type()
-        );
-        let _ = writeln!(
-            result,
-            "  ~{0}() {{ Qffi_{0}_private_delete(_d); }}",
-            &self.name
-        );
+        let _ = writeln!(result, "  {}(QObject* parent = nullptr);", &self.name);
+        let _ = writeln!(result, "  ~{}();", &self.name);
 
         // methods
         result.push('\n');
-        for meth in &self.methods {
-            result.push_str(&generate_method_impl(meth));
+        for meth in &self.all_methods() {
+            result.push_str(&generate_method_decl(meth));
             result.push('\n');
         }
+        for prop in self.properties.iter().filter(|prop| prop.auto) {
+            result.push_str(&generate_auto_prop_decl(prop));
+        }
 
         // signals
         result.push('\n');
@@ -393,7 +653,7 @@ impl GenerateCppCode for QObjectConfig {
         result.push('\n');
         result.push_str("public Q_SLOTS:\n");
         for slot in &self.slots {
-            result.push_str(&generate_method_impl(slot));
+            result.push_str(&generate_method_decl(slot));
             result.push('\n');
         }
 
@@ -401,6 +661,24 @@ impl GenerateCppCode for QObjectConfig {
         result.push('\n');
         result.push_str("public:\n");
         result.push_str("  void* _d;\n");
+        for prop in &self.properties {
+            if let Some(member) = &prop.member {
+                let _ = writeln!(result, "  {} {}{{}};", prop.type_ref.cpp_type(), member);
+            }
+        }
+
+        if self.properties.iter().any(|prop| prop.auto) {
+            result.push('\n');
+            result.push_str("private:\n");
+            for prop in self.properties.iter().filter(|prop| prop.auto) {
+                let _ = writeln!(
+                    result,
+                    "  {} {}{{}};",
+                    prop.type_ref.cpp_type(),
+                    prop.auto_member()
+                );
+            }
+        }
 
         // friends
         result.push('\n');
@@ -411,104 +689,1003 @@ impl GenerateCppCode for QObjectConfig {
 
         // class end
         result.push_str("};\n");
-    }
-
-    fn generate_implementations(&self, _result: &mut String) {}
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::typeref::QString;
-
-    use super::*;
-
-    #[test]
-    fn generate_simple_class() {
-        let mut obj = QObjectConfig::new("Dummy");
-        let obj_clone = obj.clone();
-        let obj = obj
-            .inherit(TypeRef::qt_core_object("QObject"))
-            .property(
-                QObjectProp::new_with_type(TypeRef::qstring(), "dummy")
-                    .read("dummy")
-                    .notify("dummyChanged"),
-            )
-            .method(
-                QObjectMethod::new("dummy")
-                    .ret::<QString>()
-                    .attach(&obj_clone),
-            );
-        let (code, _) = generate("dummy.moc", &[&obj]);
-
-        println!("{}", code);
-
-        assert!(code.contains("#include <QObject>"));
-        assert!(code.contains("#include <QString>"));
-        assert!(code.contains("Q_OBJECT"));
-        assert!(code.contains("Q_PROPERTY(QString dummy READ dummy NOTIFY dummyChanged);"));
-        assert!(code.contains("class Dummy : public QObject"));
-        assert!(code.contains("QString dummy()"));
-        assert!(code.contains("#include \"dummy.moc\""));
-        assert!(code.contains("Qffi_Dummy_dummy(_d, &out__);"));
-        assert!(code.contains("void Qffi_Dummy_dummy(void* self_, QString* out__);"));
-        assert!(code.contains("void* Qffi_Dummy_private_new(Dummy* qobject);"));
-        assert!(code.contains("void Qffi_Dummy_private_delete(void* self_);"));
-    }
 
-    #[test]
-    fn test_cpp_impl() {
-        let def = generate_ffi_impl(
-            &(QObjectMethod::new("test")
-                .arg_with_type("arg0", TypeRef::qt_core_object("CppType0"))
-                .arg_with_type("arg1", TypeRef::qt_core_object("CppType1"))
-                .attach(&QObjectConfig::new("Test"))),
-        );
-        assert_eq!("Qffi_Test_test(_d, arg0, arg1);", def.trim());
+        for enum_ in &self.enums {
+            if let Some(flags) = &enum_.flags {
+                let _ = writeln!(
+                    result,
+                    "Q_DECLARE_OPERATORS_FOR_FLAGS({}::{})",
+                    self.name, flags
+                );
+            }
+        }
     }
 
-    #[test]
-    fn test_cpp_impl_with_return() {
-        let def = generate_ffi_impl(
-            &QObjectMethod::new("test")
-                .arg_with_type("arg0", TypeRef::qt_core_object("CppType0"))
-                .ret_type(TypeRef::qt_core_object("RetCppType"))
-                .attach(&QObjectConfig::new("Test")),
+    fn generate_implementations(&self, result: &mut String) {
+        // ctor and dtor
+        let _ = writeln!(
+            result,
+            "{0}::{0}(QObject* parent)\n  : {1}(parent)\n{{\n    _d = Qffi_{0}_private_new(this);\n}}\n",
+            &self.name,
+            self.base_class.cpp_type()
         );
-        assert_eq!(
-            r#"
-    RetCppType out__;
-    Qffi_Test_test(_d, arg0, &out__);
-    return out__;"#
-                .trim(),
-            def.trim()
+        let _ = writeln!(
+            result,
+            "{0}::~{0}()\n{{\n    Qffi_{0}_private_delete(_d);\n}}\n",
+            &self.name
         );
-    }
 
-    #[test]
-    fn test_cpp_class_with_signal() {
-        let mut obj = QObjectConfig::new("Dummy");
-        let obj = obj
-            .inherit(TypeRef::qobject())
-            .signal(QObjectSignal::new("testSignal").arg_with_type("arg0", TypeRef::qobject_ptr()));
-        let (code, _) = generate("dummy.moc", &[&obj]);
+        // methods and slots
+        for meth in self.all_methods().iter().chain(self.slots.iter()) {
+            result.push_str(&generate_method_impl(&self.name, meth));
+            result.push_str("\n\n");
+        }
 
-        println!("{}", code);
+        // auto properties
+        for prop in self.properties.iter().filter(|prop| prop.auto) {
+            result.push_str(&generate_auto_prop_impl(self, prop));
+        }
+    }
+}
 
-        assert!(code.contains("void Qffi_Dummy_testSignal(Dummy* self_, QObject* arg0)"));
-        assert!(code.contains("Q_EMIT self_->testSignal(arg0)"));
+impl GenerateCppCode for QGadgetConfig {
+    fn class_name(&self) -> &str {
+        &self.name
     }
 
-    #[test]
-    fn test_cpp_class_with_slot() {
-        let mut obj = QObjectConfig::new("Dummy");
-        let obj = obj
-            .inherit(TypeRef::qobject())
-            .slot(QObjectMethod::new("testSlot").arg::<&QString>("arg0"));
-        let (code, _) = generate("dummy.moc", &[&obj]);
+    fn fill_ffi_functions(&self, ffi: &mut FfiBridge) {
+        for meth in self.accessors().iter().chain(self.methods.iter()) {
+            let mut args = meth.args.clone();
+            args.insert(0, ("self_".into(), TypeRef::void_mut_ptr()));
 
-        println!("{}", code);
+            ffi.rust_function(FfiFunction::new_complete(
+                meth.get_ffi_name(),
+                args,
+                meth.rtype.clone(),
+                gen_rust_meth_impl(&self.name, meth, &PanicPolicy::Abort),
+                None,
+            ));
+        }
 
-        assert!(code.contains("public Q_SLOTS:"));
-        assert!(code.contains("void testSlot(const QString& arg0)"));
+        let private_default = format!("Qffi_{}_private_default", self.name);
+        ffi.rust_function(FfiFunction::new_complete(
+            &private_default,
+            vec![],
+            Some(TypeRef::void_mut_ptr()),
+            ImplCode::Rust(PanicPolicy::Abort.catch_unwind_constructor(
+                &private_default,
+                &format!(
+                    "Box::into_raw(Box::new({}Private::default())) as *mut std::ffi::c_void",
+                    self.name
+                ),
+            )),
+            None,
+        ));
+        let private_clone = format!("Qffi_{}_private_clone", self.name);
+        ffi.rust_function(FfiFunction::new_complete(
+            &private_clone,
+            vec![("self_".into(), TypeRef::void_mut_ptr())],
+            Some(TypeRef::void_mut_ptr()),
+            ImplCode::Rust(PanicPolicy::Abort.catch_unwind_constructor(
+                &private_clone,
+                &format!(
+                    "let value = unsafe {{ (*(self_ as *const {0}Private)).clone() }};\n  \
+                     Box::into_raw(Box::new(value)) as *mut std::ffi::c_void",
+                    self.name
+                ),
+            )),
+            None,
+        ));
+        let private_delete = format!("Qffi_{}_private_delete", self.name);
+        ffi.rust_function(FfiFunction::new_complete(
+            &private_delete,
+            vec![("self_".into(), TypeRef::void_mut_ptr())],
+            None,
+            ImplCode::Rust(PanicPolicy::Abort.catch_unwind(
+                &private_delete,
+                &format!(
+                    "unsafe {{ drop(Box::from_raw(self_ as *mut {}Private)) }};",
+                    self.name
+                ),
+                None,
+            )),
+            None,
+        ));
+
+        ffi.cpp_function(FfiFunction::new_complete(
+            &format!("Qffi_{}_staticMetaObject", self.name),
+            vec![],
+            Some(TypeRef::qt_core_object("QMetaObject").with_const_ptr()),
+            ImplCode::Cpp(format!("return &{}::staticMetaObject;", self.name)),
+            None,
+        ));
+        ffi.cpp_function(FfiFunction::new_complete(
+            &format!("Qffi_{}_registerMetaType", self.name),
+            vec![],
+            Some(TypeRef::from_type::<i32>()),
+            ImplCode::Cpp(format!(
+                "return qRegisterMetaType<{0}>(\"{0}\");",
+                self.name
+            )),
+            None,
+        ));
+        ffi.cpp_function(FfiFunction::new_complete(
+            &format!("Qffi_{}_toVariant", self.name),
+            vec![("self_".into(), self.type_ref().with_const_ref())],
+            Some(TypeRef::from_type::<QVariant>()),
+            ImplCode::Cpp("new(out__) QVariant(QVariant::fromValue(self_));".to_string()),
+            None,
+        ));
+        ffi.cpp_function(FfiFunction::new_complete(
+            &format!("Qffi_{}_fromVariant", self.name),
+            vec![
+                (
+                    "value".into(),
+                    TypeRef::from_type::<QVariant>().with_const_ref(),
+                ),
+                ("out".into(), self.type_ref().with_mut_ref()),
+            ],
+            Some(TypeRef::from_type::<bool>()),
+            ImplCode::Cpp(format!(
+                "if (!value.canConvert<{0}>()) return false;\n  \
+                 out = value.value<{0}>();\n  \
+                 return true;",
+                self.name
+            )),
+            None,
+        ));
+    }
+
+    fn generate_forward_definitions(&self, result: &mut String) {
+        let _ = writeln!(result, "class {};", self.name);
+    }
+
+    fn generate_classes(&self, result: &mut String, friend_funcs: &[&FfiFunction]) {
+        // class
+        let _ = writeln!(result, "class {} {{", &self.name);
+        result.push_str("  Q_GADGET\n");
+
+        // properties
+        result.push('\n');
+        for prop in &self.properties {
+            generate_prop_def(result, prop);
+        }
+
+        // ctor, copy and dtor
+        result.push('\n');
+        result.push_str("public:\n");
+        let _ = writeln!(result, "  {}();", &self.name);
+        let _ = writeln!(result, "  {0}(const {0}& other);", &self.name);
+        let _ = writeln!(result, "  {0}& operator=(const {0}& other);", &self.name);
+        let _ = writeln!(result, "  ~{}();", &self.name);
+
+        // methods
+        result.push('\n');
+        for meth in self.accessors().iter().chain(self.methods.iter()) {
+            result.push_str(&generate_method_decl(meth));
+            result.push('\n');
+        }
+
+        // private member
+        result.push('\n');
+        result.push_str("public:\n");
+        result.push_str("  void* _d;\n");
+
+        // friends
+        result.push('\n');
+        for friend in friend_funcs {
+            result.push_str(&friend.generate_friend_cpp_impl());
+            result.push('\n');
+        }
+
+        // class end
+        result.push_str("};\n");
+        let _ = writeln!(result, "Q_DECLARE_METATYPE({})", self.name);
+    }
+
+    fn generate_implementations(&self, result: &mut String) {
+        // ctor, copy and dtor
+        let _ = writeln!(
+            result,
+            "{0}::{0}()\n  : _d(Qffi_{0}_private_default())\n{{ }}\n",
+            &self.name
+        );
+        let _ = writeln!(
+            result,
+            "{0}::{0}(const {0}& other)\n  : _d(Qffi_{0}_private_clone(other._d))\n{{ }}\n",
+            &self.name
+        );
+        let _ = writeln!(
+            result,
+            "{0}& {0}::operator=(const {0}& other)\n{{\n    \
+             if (this != &other) {{\n        \
+             void* d = Qffi_{0}_private_clone(other._d);\n        \
+             Qffi_{0}_private_delete(_d);\n        \
+             _d = d;\n    \
+             }}\n    \
+             return *this;\n}}\n",
+            &self.name
+        );
+        let _ = writeln!(
+            result,
+            "{0}::~{0}()\n{{\n    Qffi_{0}_private_delete(_d);\n}}\n",
+            &self.name
+        );
+
+        // methods
+        for meth in self.accessors().iter().chain(self.methods.iter()) {
+            result.push_str(&generate_method_impl(&self.name, meth));
+            result.push_str("\n\n");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::typeref::QString;
+
+    use super::*;
+
+    #[test]
+    fn generate_simple_class() {
+        let mut obj = QObjectConfig::new("Dummy");
+        let obj_clone = obj.clone();
+        let obj = obj
+            .inherit(TypeRef::qt_core_object("QObject"))
+            .property(
+                QObjectProp::new_with_type(TypeRef::qstring(), "dummy")
+                    .read("dummy")
+                    .notify("dummyChanged"),
+            )
+            .method(
+                QObjectMethod::new("dummy")
+                    .ret::<QString>()
+                    .attach(&obj_clone),
+            )
+            .signal(QObjectSignal::new("dummyChanged"));
+        let (code, _) = generate("dummy.moc", &[&obj]).unwrap();
+
+        println!("{}", code);
+
+        assert!(code.contains("#include <QObject>"));
+        assert!(code.contains("#include <QString>"));
+        assert!(code.contains("Q_OBJECT"));
+        assert!(code.contains("Q_PROPERTY(QString dummy READ dummy NOTIFY dummyChanged);"));
+        assert!(code.contains("class Dummy : public QObject"));
+        assert!(code.contains("QString dummy()"));
+        assert!(code.contains("#include \"dummy.moc\""));
+        assert!(code.contains("Qffi_Dummy_dummy(_d, &out__);"));
+        assert!(code.contains("void Qffi_Dummy_dummy(void* self_, QString* out__);"));
+        assert!(code.contains("void* Qffi_Dummy_private_new(Dummy* qobject);"));
+        assert!(code.contains("void Qffi_Dummy_private_delete(void* self_);"));
+    }
+
+    #[test]
+    fn test_cpp_impl() {
+        let def = generate_ffi_impl(
+            &(QObjectMethod::new("test")
+                .arg_with_type("arg0", TypeRef::qt_core_object("CppType0"))
+                .arg_with_type("arg1", TypeRef::qt_core_object("CppType1"))
+                .attach(&QObjectConfig::new("Test"))),
+        );
+        assert_eq!("Qffi_Test_test(_d, arg0, arg1);", def.trim());
+    }
+
+    #[test]
+    fn test_cpp_impl_with_return() {
+        let def = generate_ffi_impl(
+            &QObjectMethod::new("test")
+                .arg_with_type("arg0", TypeRef::qt_core_object("CppType0"))
+                .ret_type(TypeRef::qt_core_object("RetCppType"))
+                .attach(&QObjectConfig::new("Test")),
+        );
+        assert_eq!(
+            r#"
+    RetCppType out__;
+    Qffi_Test_test(_d, arg0, &out__);
+    return out__;"#
+                .trim(),
+            def.trim()
+        );
+    }
+
+    #[test]
+    fn test_rust_impl_trait() {
+        let mut obj = QObjectConfig::new("Dummy");
+        let obj = obj
+            .method(
+                QObjectMethod::new("rowCount")
+                    .const_()
+                    .arg::<i32>("parent")
+                    .ret::<i32>(),
+            )
+            .method(QObjectMethod::new("objectName").proxy("QObject"))
+            .slot(QObjectMethod::new("setName").arg::<&QString>("name"));
+        let (_, rust) = generate("dummy.moc", &[&obj]).unwrap();
+
+        println!("{}", rust);
+
+        assert!(rust.contains(
+            "pub trait DummyImpl {\n    \
+             fn new(qobject: *mut Dummy) -> Self;\n    \
+             fn row_count(&self, parent: i32) -> i32;\n    \
+             fn set_name(&mut self, name: &qt5qml::core::QString);\n}"
+        ));
+        assert!(rust.contains(
+            "<DummyPrivate as DummyImpl>::row_count(&*(self_ as *const DummyPrivate), parent)"
+        ));
+        assert!(rust.contains(
+            "<DummyPrivate as DummyImpl>::set_name(&mut *(self_ as *mut DummyPrivate), name)"
+        ));
+        assert!(rust.contains("Box::new(<DummyPrivate as DummyImpl>::new(qobject))"));
+        assert!(!rust.contains("    fn object_name("));
+    }
+
+    #[test]
+    fn test_rust_impl_trait_getter() {
+        let mut obj = QObjectConfig::new("Dummy");
+        obj.method(QObjectMethod::new("count").ret::<i32>())
+            .property(QObjectProp::new::<i32>("count").read("count"));
+        let (code, rust) = generate("dummy.moc", &[&obj]).unwrap();
+
+        assert!(code.contains("qint32 count() const;"));
+        assert!(rust.contains("    fn count(&self) -> i32;\n"));
+    }
+
+    #[test]
+    fn test_by_value_args() {
+        let mut obj = QObjectConfig::new("Dummy");
+        let obj = obj
+            .inherit(TypeRef::qobject())
+            .method(
+                QObjectMethod::new("setObjectName")
+                    .proxy("QObject")
+                    .arg::<QString>("name"),
+            )
+            .slot(
+                QObjectMethod::new("setName")
+                    .arg::<QString>("name")
+                    .arg::<i32>("index"),
+            )
+            .signal(QObjectSignal::new("nameChanged").arg::<QString>("name"));
+        let (code, rust) = generate("dummy.moc", &[&obj]).unwrap();
+
+        println!("{}", code);
+        println!("{}", rust);
+
+        assert!(code.contains("void setName(QString name, qint32 index);"));
+        assert!(code.contains(
+            "extern \"C\" void Qffi_Dummy_setName(void* self_, const QString& name, qint32 index);"
+        ));
+        assert!(code.contains("Qffi_Dummy_setName(_d, name, index);"));
+        assert!(code.contains("void Qffi_Dummy_setObjectName(Dummy& self_, const QString& name)"));
+        assert!(code.contains("void Qffi_Dummy_nameChanged(Dummy* self_, const QString& name)"));
+
+        assert!(rust.contains("fn set_name(&mut self, name: qt5qml::core::QString, index: i32);"));
+        assert!(rust.contains(
+            "fn Qffi_Dummy_setName(self_: *mut std::ffi::c_void, name: &qt5qml::core::QString, index: i32)"
+        ));
+        assert!(rust.contains("(&mut *(self_ as *mut DummyPrivate), name.clone(), index)"));
+        assert!(rust.contains(
+            "pub(crate) fn set_object_name(&mut self, name: qt5qml::core::QString) -> () {\n        \
+             unsafe { Qffi_Dummy_setObjectName(self, &name) }"
+        ));
+        assert!(rust.contains(
+            "pub(crate) fn name_changed(&mut self, name: qt5qml::core::QString) {\n        \
+             unsafe { Qffi_Dummy_nameChanged(self, &name); }"
+        ));
+    }
+
+    #[test]
+    fn test_rust_conversions() {
+        let mut obj = QObjectConfig::new("Dummy");
+        let obj = obj
+            .inherit(TypeRef::qobject())
+            .method(
+                QObjectMethod::new("objectName")
+                    .const_()
+                    .proxy("QObject")
+                    .ret::<String>(),
+            )
+            .slot(
+                QObjectMethod::new("join")
+                    .arg::<Vec<i32>>("values")
+                    .arg::<&str>("separator")
+                    .ret::<String>(),
+            )
+            .signal(QObjectSignal::new("nameChanged").arg::<Option<QString>>("name"));
+        let (code, rust) = generate("dummy.moc", &[&obj]).unwrap();
+
+        println!("{}", rust);
+
+        assert!(code.contains("QString join(QList<int> values, QString separator);"));
+        assert!(rust.contains("fn join(&mut self, values: Vec<i32>, separator: &str) -> String;"));
+        assert!(rust.contains(
+            "*out__ = qt5qml::core::QString::from(<DummyPrivate as DummyImpl>::join(\
+             &mut *(self_ as *mut DummyPrivate), values.to_vec(), &separator.to_string()))"
+        ));
+        assert!(rust.contains("pub(crate) fn object_name(&self) -> String {"));
+        assert!(rust.contains("unsafe { out__.assume_init().to_string() }"));
+        assert!(rust.contains(
+            "pub(crate) fn name_changed(&mut self, name: Option<qt5qml::core::QString>) {\n        \
+             unsafe { Qffi_Dummy_nameChanged(self, &name.unwrap_or_default()); }"
+        ));
+    }
+
+    #[test]
+    fn test_item_model_proxies() {
+        let mut obj = QObjectConfig::new("Dummy");
+        let obj = obj
+            .inherit(TypeRef::qt_core_object("QAbstractListModel"))
+            .method(QObjectMethod::new("endResetModel").proxy("QAbstractItemModel"));
+        let (code, rust) = generate("dummy.moc", &[&obj]).unwrap();
+
+        println!("{}", code);
+        println!("{}", rust);
+
+        assert!(code.contains(
+            "void beginInsertRows(const QModelIndex& parent, qint32 first, qint32 last);"
+        ));
+        assert!(code.contains("return QAbstractListModel::beginInsertRows(parent, first, last);"));
+        assert!(code.contains(
+            "bool beginMoveRows(const QModelIndex& source_parent, qint32 first, qint32 last, \
+             const QModelIndex& destination_parent, qint32 destination);"
+        ));
+        assert!(
+            code.contains("QModelIndex createIndex(qint32 row, qint32 column, quintptr id) const;")
+        );
+        assert!(code.contains("return QAbstractItemModel::endResetModel();"));
+        assert!(!code.contains("return QAbstractListModel::endResetModel();"));
+        assert!(code.contains("Q_EMIT self_->dataChanged(top_left, bottom_right, roles);"));
+        assert!(!code.contains("void dataChanged("));
+
+        assert!(rust.contains(
+            "pub(crate) fn begin_insert_rows(&mut self, parent: &qt5qml::core::QModelIndex, first: i32, last: i32)"
+        ));
+        assert!(rust.contains("pub(crate) fn create_index(&self, row: i32, column: i32, id: usize) -> qt5qml::core::QModelIndex {"));
+        assert!(rust.contains(
+            "pub(crate) fn data_changed(&mut self, top_left: &qt5qml::core::QModelIndex, \
+             bottom_right: &qt5qml::core::QModelIndex, roles: &qt5qml::core::QVector<i32>)"
+        ));
+        assert!(!rust.contains("fn begin_insert_rows(&mut self, parent: &qt5qml::core::QModelIndex, first: i32, last: i32);"));
+    }
+
+    #[test]
+    fn test_no_item_model_proxies() {
+        let obj = QObjectConfig::new("Dummy");
+        let (code, rust) = generate("dummy.moc", &[&obj]).unwrap();
+
+        assert!(!code.contains("beginInsertRows"));
+        assert!(!rust.contains("data_changed"));
+    }
+
+    #[test]
+    fn test_rust_panic_policy_abort() {
+        let mut obj = QObjectConfig::new("Dummy");
+        let obj = obj.method(QObjectMethod::new("count").ret::<i32>());
+        let (_, rust) = generate("dummy.moc", &[&obj]).unwrap();
+
+        assert!(rust.contains(
+            "qt5qml::panic::abort_on_panic(\"Qffi_Dummy_count\", || { unsafe { <DummyPrivate"
+        ));
+        assert!(rust.contains("qt5qml::panic::abort_on_panic(\"Qffi_Dummy_private_new\""));
+        assert!(rust.contains("qt5qml::panic::abort_on_panic(\"Qffi_Dummy_private_delete\""));
+    }
+
+    #[test]
+    fn test_rust_panic_policy_log_and_default() {
+        let mut obj = QObjectConfig::new("Dummy");
+        let obj = obj
+            .method(QObjectMethod::new("count").ret::<i32>())
+            .method(QObjectMethod::new("name").ret::<QString>())
+            .method(QObjectMethod::new("parent").ret_type(TypeRef::qobject_ptr()))
+            .panic_policy(PanicPolicy::LogAndDefault);
+        let (_, rust) = generate("dummy.moc", &[&obj]).unwrap();
+
+        println!("{}", rust);
+
+        assert!(rust.contains(
+            "qt5qml::panic::catch_panic(\"Qffi_Dummy_count\", qt5qml::panic::log_panic, || {"
+        ));
+        assert!(rust.contains(
+            "as DummyImpl>::count(&mut *(self_ as *mut DummyPrivate)) } }).unwrap_or_default()"
+        ));
+        assert!(rust.contains("*out__ = <DummyPrivate as DummyImpl>::name(&mut *(self_ as *mut DummyPrivate)) } }).unwrap_or_default()"));
+        assert!(rust.contains(
+            "::parent(&mut *(self_ as *mut DummyPrivate)) } }).unwrap_or_else(std::ptr::null_mut)"
+        ));
+        assert!(
+            rust.contains("as *mut std::ffi::c_void }).unwrap_or_else(|| std::process::abort())")
+        );
+    }
+
+    #[test]
+    fn test_rust_panic_policy_log_and_default_enum() {
+        let mut obj = QObjectConfig::new("Dummy");
+        let obj = obj
+            .enum_(QObjectEnum::new("Mode").value("Off").value("On"))
+            .method(QObjectMethod::new("mode").ret_type(TypeRef::generated_enum("Dummy", "Mode")))
+            .panic_policy(PanicPolicy::LogAndDefault);
+        let (_, rust) = generate("dummy.moc", &[&obj]).unwrap();
+
+        assert!(rust.contains("#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]\npub struct DummyMode(i32);"));
+        assert!(rust.contains(
+            "as DummyImpl>::mode(&mut *(self_ as *mut DummyPrivate)) } }).unwrap_or_default()"
+        ));
+    }
+
+    #[test]
+    fn test_rust_panic_policy_hook() {
+        let mut obj = QObjectConfig::new("Dummy");
+        let obj = obj
+            .method(QObjectMethod::new("count").ret::<i32>())
+            .panic_policy(PanicPolicy::Hook("crate::on_panic".into()));
+        let (_, rust) = generate("dummy.moc", &[&obj]).unwrap();
+
+        assert!(
+            rust.contains("qt5qml::panic::catch_panic(\"Qffi_Dummy_count\", crate::on_panic, || {")
+        );
+        assert!(rust.contains("qt5qml::panic::catch_panic(\"Qffi_Dummy_singletonFactory\", crate::on_panic, || { factory().into_raw() }).unwrap_or_else(|| std::process::abort())"));
+    }
+
+    #[test]
+    fn test_cpp_class_with_signal() {
+        let mut obj = QObjectConfig::new("Dummy");
+        let obj = obj
+            .inherit(TypeRef::qobject())
+            .signal(QObjectSignal::new("testSignal").arg_with_type("arg0", TypeRef::qobject_ptr()));
+        let (code, _) = generate("dummy.moc", &[&obj]).unwrap();
+
+        println!("{}", code);
+
+        assert!(code.contains("void Qffi_Dummy_testSignal(Dummy* self_, QObject* arg0)"));
+        assert!(code.contains("Q_EMIT self_->testSignal(arg0)"));
+    }
+
+    #[test]
+    fn test_rust_typed_signal() {
+        let mut obj = QObjectConfig::new("Dummy");
+        let obj = obj
+            .inherit(TypeRef::qobject())
+            .signal(QObjectSignal::new("nameChanged").arg::<&QString>("name"))
+            .signal(QObjectSignal::new("cleared"));
+        let (_, rust) = generate("dummy.moc", &[&obj]).unwrap();
+
+        println!("{}", rust);
+
+        assert!(rust.contains(
+            "pub(crate) fn name_changed_signal() -> qt5qml::core::TypedSignal<(qt5qml::core::QString,)>"
+        ));
+        assert!(rust.contains(r#"qt5qml::typed_signal!("nameChanged(const QString&)")"#));
+        assert!(rust.contains("pub(crate) fn cleared_signal() -> qt5qml::core::TypedSignal<()>"));
+        assert!(rust.contains(r#"qt5qml::typed_signal!("cleared()")"#));
+    }
+
+    #[test]
+    fn test_cpp_class_with_slot() {
+        let mut obj = QObjectConfig::new("Dummy");
+        let obj = obj
+            .inherit(TypeRef::qobject())
+            .slot(QObjectMethod::new("testSlot").arg::<&QString>("arg0"));
+        let (code, _) = generate("dummy.moc", &[&obj]).unwrap();
+
+        println!("{}", code);
+
+        assert!(code.contains("public Q_SLOTS:"));
+        assert!(code.contains("void testSlot(const QString& arg0)"));
+    }
+
+    #[test]
+    fn test_cpp_many_classes() {
+        let mut first = QObjectConfig::new("First");
+        let mut second = QObjectConfig::new("Second");
+        second.signal(
+            QObjectSignal::new("firstChanged")
+                .arg_with_type("first", TypeRef::generated("First").with_mut_ptr()),
+        );
+        first
+            .property(
+                QObjectProp::new_with_type(TypeRef::generated("Second").with_mut_ptr(), "second")
+                    .read("second"),
+            )
+            .method(
+                QObjectMethod::new("second").ret_type(TypeRef::generated("Second").with_mut_ptr()),
+            );
+        let (code, rust) = generate("dummy.moc", &[&first, &second]).unwrap();
+
+        println!("{}", code);
+
+        assert!(code.find("class First;").unwrap() < code.find("class First :").unwrap());
+        assert!(code.find("class Second;").unwrap() < code.find("class First :").unwrap());
+        assert!(code.contains("class Second : public QObject"));
+        assert!(code.contains("Q_PROPERTY(Second* second READ second);"));
+        assert!(code.contains("void Qffi_Second_firstChanged(Second* self_, First* first)"));
+        assert_eq!(1, code.matches("#include \"dummy.moc\"").count());
+        assert!(rust.contains("pub struct First {"));
+        assert!(rust.contains("pub struct Second {"));
+    }
+
+    #[test]
+    fn test_cpp_class_with_enum() {
+        let mut obj = QObjectConfig::new("Dummy");
+        let obj = obj
+            .enum_(
+                QObjectEnum::new("Mode")
+                    .value("Off")
+                    .value("On")
+                    .value_with("Auto", 10)
+                    .value("Next"),
+            )
+            .property(
+                QObjectProp::new_with_type(TypeRef::generated_enum("Dummy", "Mode"), "mode")
+                    .read("mode"),
+            )
+            .method(QObjectMethod::new("mode").ret_type(TypeRef::generated_enum("Dummy", "Mode")));
+        let (code, rust) = generate("dummy.moc", &[&obj]).unwrap();
+
+        println!("{}", code);
+
+        assert!(code.contains(
+            "  enum Mode {\n    Off = 0,\n    On = 1,\n    Auto = 10,\n    Next = 11,\n  };"
+        ));
+        assert!(code.contains("  Q_ENUM(Mode)"));
+        assert!(code.contains("Q_PROPERTY(Dummy::Mode mode READ mode);"));
+        assert!(code.contains("extern \"C\" Dummy::Mode Qffi_Dummy_mode(void* self_);"));
+        assert!(
+            code.find("Q_ENUM(Mode)").unwrap() < code.find("Dummy::Mode mode() const;").unwrap()
+        );
+        assert!(rust.contains("#[repr(transparent)]"));
+        assert!(rust.contains("pub struct DummyMode(i32);"));
+        assert!(rust.contains("    pub const Auto: Self = Self(10);"));
+        assert!(rust.contains("fn Qffi_Dummy_mode(self_: *mut std::ffi::c_void) -> DummyMode"));
+    }
+
+    #[test]
+    fn test_cpp_class_with_flags() {
+        let mut obj = QObjectConfig::new("Dummy");
+        let obj = obj.flags(
+            "Options",
+            QObjectEnum::new("Option")
+                .value("Bold")
+                .value("Italic")
+                .value_with("Strike", 16)
+                .value("Underline"),
+        );
+        let (code, rust) = generate("dummy.moc", &[&obj]).unwrap();
+
+        println!("{}", code);
+
+        assert!(
+            code.contains("    Bold = 1,\n    Italic = 2,\n    Strike = 16,\n    Underline = 32,")
+        );
+        assert!(code.contains("  Q_DECLARE_FLAGS(Options, Option)\n  Q_FLAG(Options)"));
+        assert!(code.contains("Q_DECLARE_OPERATORS_FOR_FLAGS(Dummy::Options)"));
+        assert!(rust.contains("pub struct DummyOptions(i32);"));
+        assert!(rust.contains("pub const Underline: Self = Self(32);"));
+        assert!(rust.contains("impl std::ops::BitOr for DummyOptions {"));
+    }
+
+    #[test]
+    fn test_cpp_property_attributes() {
+        let mut obj = QObjectConfig::new("Dummy");
+        let obj = obj
+            .property(
+                QObjectProp::new::<i32>("value")
+                    .read("value")
+                    .write("setValue")
+                    .reset("resetValue")
+                    .notify("valueChanged")
+                    .revision(2)
+                    .designable(false)
+                    .scriptable(true)
+                    .stored(false)
+                    .user()
+                    .final_(),
+            )
+            .property(QObjectProp::new::<i32>("count").member("m_count"))
+            .method(QObjectMethod::new("value").ret::<i32>())
+            .method(QObjectMethod::new("setValue").arg::<i32>("value"))
+            .method(QObjectMethod::new("resetValue"))
+            .signal(QObjectSignal::new("valueChanged"));
+        let (code, _) = generate("dummy.moc", &[&obj]).unwrap();
+
+        println!("{}", code);
+
+        assert!(code.contains(
+            "Q_PROPERTY(qint32 value READ value WRITE setValue RESET resetValue \
+             NOTIFY valueChanged REVISION 2 DESIGNABLE false SCRIPTABLE true STORED false \
+             USER true FINAL);"
+        ));
+        assert!(code.contains("Q_PROPERTY(qint32 count MEMBER m_count);"));
+        assert!(code.contains("  qint32 m_count{};"));
+    }
+
+    #[test]
+    fn test_cpp_auto_property() {
+        let mut obj = QObjectConfig::new("Dummy");
+        let obj = obj
+            .property(
+                QObjectProp::new::<i32>("count")
+                    .auto()
+                    .notify("countChanged"),
+            )
+            .property(
+                QObjectProp::new::<QString>("name")
+                    .auto()
+                    .notify("nameChanged"),
+            )
+            .signal(QObjectSignal::new("countChanged").arg::<i32>("count"))
+            .signal(QObjectSignal::new("nameChanged"));
+        let (code, rust) = generate("dummy.moc", &[&obj]).unwrap();
+
+        println!("{}", code);
+
+        assert!(code
+            .contains("Q_PROPERTY(qint32 count READ count WRITE setCount NOTIFY countChanged);"));
+        assert!(code.contains("  qint32 count() const;\n  void setCount(qint32 value);"));
+        assert!(code.contains("  void setName(const QString& value);"));
+        assert!(code.contains("  qint32 m_count{};"));
+        assert!(code.contains(
+            "void Dummy::setCount(qint32 value) {\n    if (m_count == value)\n        return;\n    \
+             m_count = value;\n    Q_EMIT countChanged(m_count);\n}"
+        ));
+        assert!(code.contains("    m_name = value;\n    Q_EMIT nameChanged();\n}"));
+        assert!(code.contains("void Qffi_Dummy_name(const Dummy& self_, QString* out__)"));
+        assert!(rust.contains("pub(crate) fn set_count(&mut self, value: i32)"));
+        assert!(rust.contains("pub(crate) fn set_name(&mut self, value: &qt5qml::core::QString)"));
+        assert!(rust.contains("pub(crate) fn count(&self) -> i32"));
+    }
+
+    #[test]
+    fn test_cpp_gadget() {
+        let mut gadget = QGadgetConfig::new("Point");
+        gadget
+            .property(QObjectProp::new::<i32>("x"))
+            .property(QObjectProp::new::<QString>("name").const_())
+            .method(QObjectMethod::new("length").ret::<f64>().const_());
+        let (code, rust) = generate_all("dummy.moc", &[&gadget], &[]).unwrap();
+
+        println!("{}", code);
+
+        assert!(code.contains("class Point {\n  Q_GADGET\n"));
+        assert!(code.contains("Q_PROPERTY(qint32 x READ x WRITE setX);"));
+        assert!(code.contains("Q_PROPERTY(QString name READ name CONSTANT);"));
+        assert!(!code.contains("setName"));
+        assert!(code.contains("Point(const Point& other);"));
+        assert!(code.contains("Q_INVOKABLE double length() const;"));
+        assert!(code.contains("Q_DECLARE_METATYPE(Point)"));
+        assert!(code.contains("_d(Qffi_Point_private_clone(other._d))"));
+        assert!(code.contains("return qRegisterMetaType<Point>(\"Point\");"));
+        assert!(rust.contains("pub struct Point {\n    _d: *mut std::ffi::c_void,\n}"));
+        assert!(rust.contains("impl Clone for Point {"));
+        assert!(rust.contains("pub fn to_variant(&self) -> qt5qml::core::QVariant"));
+        assert!(rust.contains(
+            "pub trait PointImpl: Default + Clone {\n    \
+             fn x(&self) -> i32;\n    \
+             fn set_x(&mut self, value: i32);\n    \
+             fn name(&self) -> qt5qml::core::QString;\n    \
+             fn length(&self) -> f64;\n}"
+        ));
+    }
+
+    #[test]
+    fn test_cpp_gadget_in_class() {
+        let gadget = QGadgetConfig::new("Point");
+        let mut obj = QObjectConfig::new("Dummy");
+        obj.property(QObjectProp::new_with_type(gadget.type_ref(), "point").auto());
+        let (code, _) = generate_all("dummy.moc", &[&gadget], &[&obj]).unwrap();
+
+        println!("{}", code);
+
+        assert!(
+            code.find("Q_DECLARE_METATYPE(Point)").unwrap() < code.find("class Dummy :").unwrap()
+        );
+        assert!(code.contains("Q_PROPERTY(Point point READ point WRITE setPoint);"));
+        assert!(code.contains("void setPoint(const Point& value);"));
+    }
+
+    #[test]
+    fn test_cpp_qml_registration() {
+        let mut obj = QObjectConfig::new("Dummy");
+        let (code, rust) = generate("dummy.moc", &[&obj.qml(true)]).unwrap();
+
+        println!("{}", code);
+
+        assert!(code.contains("#include <QtQml>"));
+        assert!(code.contains("using Qffi_Dummy_SingletonFactory = Dummy* (*)(void* data);"));
+        assert!(code.contains("return qmlRegisterType<Dummy>("));
+        assert!(code.contains("return qmlRegisterSingletonType<Dummy>("));
+        assert!(code.contains(
+            "[factory, data](QQmlEngine*, QJSEngine*) -> QObject* { return factory(data); });"
+        ));
+        assert!(!code.contains("static Qffi_Dummy_SingletonFactory"));
+        assert!(code.contains("return qmlRegisterUncreatableType<Dummy>("));
+        assert!(code.contains("QString::fromUtf8(reason)"));
+        assert!(rust.contains("pub(crate) fn register_type("));
+        assert!(rust.contains("factory: fn() -> qt5qml::QBox<Dummy>) -> i32"));
+        assert!(rust.contains("reason: &std::ffi::CStr) -> i32"));
+    }
+
+    #[test]
+    fn test_cpp_no_qml_registration() {
+        let mut obj = QObjectConfig::new("Dummy");
+        let (code, rust) = generate("dummy.moc", &[&obj.qml(false)]).unwrap();
+
+        assert!(!code.contains("qmlRegister"));
+        assert!(!code.contains("SingletonFactory"));
+        assert!(!rust.contains("fn register_"));
+    }
+
+    fn validation_errors(obj: &QObjectConfig) -> Vec<String> {
+        match generate("dummy.moc", &[obj]) {
+            Err(QObjectBuildError::Generate(errors)) => errors,
+            Err(err) => panic!("unexpected error: {}", err),
+            Ok(_) => panic!("invalid class was generated"),
+        }
+    }
+
+    #[test]
+    fn test_validate_missing_notify_signal() {
+        let mut obj = QObjectConfig::new("Dummy");
+        obj.property(QObjectProp::new::<i32>("foo").auto().notify("fooChanged"));
+
+        assert_eq!(
+            vec!["class `Dummy`: notify signal `fooChanged` of property `foo` is not a signal of the class"],
+            validation_errors(&obj)
+        );
+    }
+
+    #[test]
+    fn test_validate_missing_accessors() {
+        let mut obj = QObjectConfig::new("Dummy");
+        obj.property(
+            QObjectProp::new::<i32>("x")
+                .read("x")
+                .write("setX")
+                .reset("resetX"),
+        )
+        .slot(QObjectMethod::new("resetX"));
+
+        assert_eq!(
+            vec![
+                "class `Dummy`: getter `x` of property `x` is not a method of the class",
+                "class `Dummy`: setter `setX` of property `x` is not a method of the class",
+            ],
+            validation_errors(&obj)
+        );
+    }
+
+    #[test]
+    fn test_validate_property_without_getter() {
+        let mut obj = QObjectConfig::new("Dummy");
+        obj.property(QObjectProp::new::<i32>("x"));
+
+        assert_eq!(
+            vec!["class `Dummy`: property `x` has neither a getter nor a member"],
+            validation_errors(&obj)
+        );
+    }
+
+    #[test]
+    fn test_validate_duplicate_names() {
+        let mut obj = QObjectConfig::new("Dummy");
+        obj.property(QObjectProp::new::<i32>("count").auto())
+            .property(QObjectProp::new::<i32>("count").member("m_count"))
+            .method(QObjectMethod::new("clear"))
+            .slot(QObjectMethod::new("clear"))
+            .method(QObjectMethod::new("setCount").arg::<i32>("value"))
+            .enum_(QObjectEnum::new("Mode").value("Off"))
+            .enum_(QObjectEnum::new("Mode").value("On"));
+
+        assert_eq!(
+            vec![
+                "class `Dummy`: slot `clear` is defined more than once",
+                "class `Dummy`: auto property accessor `setCount` is defined more than once",
+                "class `Dummy`: property `count` is defined more than once",
+                "class `Dummy`: enum `Mode` is defined more than once",
+            ],
+            validation_errors(&obj)
+        );
+    }
+
+    #[test]
+    fn test_validate_enum_overflow() {
+        let mut obj = QObjectConfig::new("Dummy");
+        obj.flags(
+            "Options",
+            QObjectEnum::new("Option")
+                .value_with("Bit30", 0x4000_0000)
+                .value("Bit31")
+                .value("Bit32"),
+        )
+        .enum_(
+            QObjectEnum::new("Mode")
+                .value_with("Last", i32::MAX)
+                .value("AfterLast"),
+        );
+
+        assert_eq!(
+            vec![
+                "class `Dummy`: value of enumerator `Bit32` of enum `Options` does not fit into 32 bits",
+                "class `Dummy`: value of enumerator `AfterLast` of enum `Mode` does not fit into 32 bits",
+            ],
+            validation_errors(&obj)
+        );
+        assert_eq!(i32::MIN, obj.enums[0].resolved_values()[1].1);
+    }
+
+    #[test]
+    fn test_validate_invalid_identifiers() {
+        let mut obj = QObjectConfig::new("my-class");
+        obj.property(QObjectProp::new::<i32>("2d").member("m_2d"))
+            .signal(QObjectSignal::new("value changed"));
+
+        assert_eq!(
+            vec![
+                "class `my-class`: class name is not a valid identifier",
+                "class `my-class`: signal name `value changed` is not a valid identifier",
+                "class `my-class`: property name `2d` is not a valid identifier",
+            ],
+            validation_errors(&obj)
+        );
+    }
+
+    #[test]
+    fn test_validate_reference_return_with_log_and_default() {
+        let mut obj = QObjectConfig::new("Dummy");
+        obj.method(QObjectMethod::new("name").ret_type(TypeRef::qstring().with_mut_ref()))
+            .panic_policy(PanicPolicy::LogAndDefault);
+
+        assert_eq!(
+            vec!["class `Dummy`: method `name` returns reference `&mut qt5qml::core::QString`, which has no default value for the panic policy"],
+            validation_errors(&obj)
+        );
+    }
+
+    #[test]
+    fn test_validate_borrowed_return_type() {
+        let mut obj = QObjectConfig::new("Dummy");
+        obj.method(QObjectMethod::new("name").ret::<&str>())
+            .slot(QObjectMethod::new("greet").arg::<&str>("name"));
+
+        assert_eq!(
+            vec!["class `Dummy`: method `name` can not return `&str`, which is only supported for arguments"],
+            validation_errors(&obj)
+        );
+    }
+
+    #[test]
+    fn test_validate_gadget() {
+        let mut gadget = QGadgetConfig::new("Point");
+        gadget
+            .property(QObjectProp::new::<i32>("x"))
+            .property(QObjectProp::new::<i32>("x").read("getX").write("putX"))
+            .method(QObjectMethod::new("setX").arg::<i32>("value"))
+            .method(QObjectMethod::new("label").ret::<&str>());
+
+        match generate_all("dummy.moc", &[&gadget], &[]) {
+            Err(QObjectBuildError::Generate(errors)) => assert_eq!(
+                vec![
+                    "gadget `Point`: property `x` is defined more than once",
+                    "gadget `Point`: method `setX` is defined more than once",
+                    "gadget `Point`: method `label` can not return `&str`, which is only supported for arguments",
+                ],
+                errors
+            ),
+            _ => panic!("expected validation errors"),
+        }
+    }
+
+    #[test]
+    fn test_validate_reports_all_classes() {
+        let mut first = QObjectConfig::new("First");
+        first.property(QObjectProp::new::<i32>("x"));
+        let mut second = QObjectConfig::new("Second");
+        second.property(QObjectProp::new::<i32>("y"));
+
+        match generate("dummy.moc", &[&first, &second]) {
+            Err(QObjectBuildError::Generate(errors)) => assert_eq!(2, errors.len()),
+            _ => panic!("expected validation errors"),
+        }
     }
 }
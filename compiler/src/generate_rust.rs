@@ -1,10 +1,16 @@
 use std::fmt::Write;
 
 use crate::ffi::FfiBridge;
-use crate::qobject::QObjectConfig;
+use crate::qgadget::QGadgetConfig;
+use crate::qobject::{QObjectConfig, QObjectEnum, QObjectMethod};
+use crate::typeref::TypeRef;
 use crate::utils::to_snake_case;
 
-pub(crate) fn generate_rust(objects: &[&QObjectConfig], ffi: &FfiBridge) -> String {
+pub(crate) fn generate_rust(
+    gadgets: &[&QGadgetConfig],
+    objects: &[&QObjectConfig],
+    ffi: &FfiBridge,
+) -> String {
     let mut result = String::with_capacity(4 * 1024);
 
     // C++ extern
@@ -22,8 +28,43 @@ pub(crate) fn generate_rust(objects: &[&QObjectConfig], ffi: &FfiBridge) -> Stri
         result.push('\n');
     }
 
+    // Gadgets
+    for gadget in gadgets {
+        let methods: Vec<QObjectMethod> = gadget
+            .accessors()
+            .into_iter()
+            .chain(gadget.methods.iter().cloned())
+            .collect();
+        result.push_str(&generate_rust_impl_trait(
+            &gadget.name,
+            ": Default + Clone",
+            None,
+            &methods,
+        ));
+        result.push_str(&generate_rust_gadget(gadget));
+    }
+
     // Objects
     for obj in objects {
+        let methods: Vec<QObjectMethod> = obj
+            .methods
+            .iter()
+            .chain(obj.slots.iter())
+            .filter(|meth| meth.proxy_class.is_none())
+            .cloned()
+            .collect();
+        let new = format!("fn new(qobject: *mut {}) -> Self;", obj.name);
+        result.push_str(&generate_rust_impl_trait(
+            &obj.name,
+            "",
+            Some(&new),
+            &methods,
+        ));
+
+        for enum_ in &obj.enums {
+            result.push_str(&generate_rust_enum(&obj.name, enum_));
+        }
+
         let _ = writeln!(
             result,
             r#"
@@ -61,7 +102,7 @@ impl {0} {{
         );
 
         result.push('\n');
-        for meth in &obj.methods {
+        for meth in &obj.all_methods() {
             if meth.proxy_class.is_none() {
                 continue;
             }
@@ -69,49 +110,54 @@ impl {0} {{
             let mut args: Vec<String> = meth
                 .args
                 .iter()
-                .map(|arg| format!("{}: {}", arg.0, arg.1.rust_type()))
+                .map(|arg| format!("{}: {}", arg.0, arg.1.rust_api_type()))
                 .collect();
             if meth.const_ {
                 args.insert(0, "&self".into());
             } else {
                 args.insert(0, "&mut self".into());
             }
-            let mut params: Vec<&str> = meth.args.iter().map(|arg| &arg.0 as &str).collect();
-            params.insert(0, "self");
+            let mut params: Vec<String> = meth.args.iter().map(gen_ffi_param).collect();
+            params.insert(0, "self".into());
 
             match &meth.rtype {
                 Some(ref rty) if !rty.return_safe() => {
                     let _ = writeln!(
                         result,
                         r#"
-    pub(crate) fn {1}({2}) -> {4} {{
+    pub(crate) fn {1}({2}) -> {5} {{
         let mut out__ = std::mem::MaybeUninit::<{4}>::uninit();
         unsafe {{ {0}({3}, out__.as_mut_ptr()); }}
-        unsafe {{ out__.assume_init() }}
+        unsafe {{ {6} }}
     }}
 "#,
                         meth.get_ffi_name(),
                         to_snake_case(&meth.name),
                         args.join(", "),
                         params.join(", "),
-                        rty.rust_type()
+                        rty.rust_type(),
+                        rty.rust_api_type(),
+                        rty.rust_from_ffi("out__.assume_init()"),
                     );
                 }
                 _ => {
+                    let call = format!("{}({})", meth.get_ffi_name(), params.join(", "));
                     let _ = writeln!(
                         result,
                         r#"
-    pub(crate) fn {1}({2}) -> {4} {{
-        unsafe {{ {0}({3}) }}
+    pub(crate) fn {1}({2}) -> {3} {{
+        unsafe {{ {0} }}
     }}
 "#,
-                        meth.get_ffi_name(),
+                        match &meth.rtype {
+                            Some(rty) => rty.rust_from_ffi(&call),
+                            None => call,
+                        },
                         to_snake_case(&meth.name),
                         args.join(", "),
-                        params.join(", "),
                         meth.rtype
                             .as_ref()
-                            .map(|rty| rty.rust_type())
+                            .map(|rty| rty.rust_api_type())
                             .unwrap_or("()")
                     );
                 }
@@ -119,15 +165,15 @@ impl {0} {{
         }
 
         result.push('\n');
-        for signal in &obj.signals {
+        for signal in &obj.emitted_signals() {
             let mut args: Vec<String> = signal
                 .args
                 .iter()
-                .map(|arg| format!("{}: {}", arg.0, arg.1.rust_type()))
+                .map(|arg| format!("{}: {}", arg.0, arg.1.rust_api_type()))
                 .collect();
             args.insert(0, "&mut self".into());
-            let mut params: Vec<&str> = signal.args.iter().map(|arg| &arg.0 as &str).collect();
-            params.insert(0, "self");
+            let mut params: Vec<String> = signal.args.iter().map(gen_ffi_param).collect();
+            params.insert(0, "self".into());
             let _ = writeln!(
                 result,
                 r#"
@@ -141,6 +187,73 @@ impl {0} {{
                 args.join(", "),
                 params.join(", ")
             );
+
+            let types: Vec<&str> = signal.args.iter().map(|arg| arg.1.cpp_type()).collect();
+            let value_types: Vec<String> = signal
+                .args
+                .iter()
+                .map(|arg| format!("{},", arg.1.rust_value_type()))
+                .collect();
+            let _ = writeln!(
+                result,
+                r#"
+    pub(crate) fn {0}_signal() -> qt5qml::core::TypedSignal<({1})> {{
+        unsafe {{ qt5qml::typed_signal!("{2}({3})") }}
+    }}
+"#,
+                to_snake_case(&signal.name),
+                value_types.join(" "),
+                &signal.name,
+                types.join(","),
+            );
+        }
+
+        result.push('\n');
+        for prop in obj.properties.iter().filter(|prop| prop.auto) {
+            let getter = prop.getter.as_ref().unwrap();
+            let setter = prop.setter.as_ref().unwrap();
+            if prop.type_ref.return_safe() {
+                let _ = writeln!(
+                    result,
+                    r#"
+    pub(crate) fn {2}(&self) -> {3} {{
+        unsafe {{ Qffi_{0}_{1}(self) }}
+    }}
+"#,
+                    obj.name,
+                    getter,
+                    to_snake_case(getter),
+                    prop.type_ref.rust_type(),
+                );
+            } else {
+                let _ = writeln!(
+                    result,
+                    r#"
+    pub(crate) fn {2}(&self) -> {3} {{
+        let mut out__ = std::mem::MaybeUninit::<{3}>::uninit();
+        unsafe {{ Qffi_{0}_{1}(self, out__.as_mut_ptr()); }}
+        unsafe {{ out__.assume_init() }}
+    }}
+"#,
+                    obj.name,
+                    getter,
+                    to_snake_case(getter),
+                    prop.type_ref.rust_type(),
+                );
+            }
+
+            let _ = writeln!(
+                result,
+                r#"
+    pub(crate) fn {2}(&mut self, value: {3}) {{
+        unsafe {{ Qffi_{0}_{1}(self, value); }}
+    }}
+"#,
+                obj.name,
+                setter,
+                to_snake_case(setter),
+                prop.auto_value_type().rust_type(),
+            );
         }
 
         result.push('\n');
@@ -151,8 +264,25 @@ impl {0} {{
     pub(crate) fn register_type(uri: &std::ffi::CStr, version_major: i32, version_minor: i32, qml_name: &std::ffi::CStr) -> i32 {{
         unsafe {{ Qffi_{0}_registerType(uri.as_ptr(), version_major, version_minor, qml_name.as_ptr()) }}
     }}
+
+    pub(crate) fn register_singleton(uri: &std::ffi::CStr, version_major: i32, version_minor: i32, qml_name: &std::ffi::CStr, factory: fn() -> qt5qml::QBox<{0}>) -> i32 {{
+        extern "C" fn call_factory(data: *mut std::ffi::c_void) -> *mut {0} {{
+            let factory = unsafe {{ std::mem::transmute::<*mut std::ffi::c_void, fn() -> qt5qml::QBox<{0}>>(data) }};
+            {1}
+        }}
+
+        unsafe {{ Qffi_{0}_registerSingletonType(uri.as_ptr(), version_major, version_minor, qml_name.as_ptr(), call_factory, factory as *mut std::ffi::c_void) }}
+    }}
+
+    pub(crate) fn register_uncreatable(uri: &std::ffi::CStr, version_major: i32, version_minor: i32, qml_name: &std::ffi::CStr, reason: &std::ffi::CStr) -> i32 {{
+        unsafe {{ Qffi_{0}_registerUncreatableType(uri.as_ptr(), version_major, version_minor, qml_name.as_ptr(), reason.as_ptr()) }}
+    }}
 "#,
                 obj.name,
+                obj.panic_policy.catch_unwind_constructor(
+                    &format!("Qffi_{}_singletonFactory", obj.name),
+                    "factory().into_raw()"
+                ),
             );
         }
 
@@ -161,3 +291,238 @@ impl {0} {{
 
     result
 }
+
+/// Parameter passing the argument `arg` of the Rust API type to a C++ FFI function.
+///
+/// Arguments which are not return safe are passed by reference.
+fn gen_ffi_param(arg: &(String, TypeRef)) -> String {
+    if arg.1.return_safe() {
+        arg.1.rust_into_ffi(&arg.0)
+    } else {
+        format!("&{}", arg.1.rust_into_ffi(&arg.0))
+    }
+}
+
+/// Generate trait `<cls>Impl`, which `<cls>Private` must implement.
+fn generate_rust_impl_trait(
+    cls: &str,
+    supertraits: &str,
+    constructor: Option<&str>,
+    methods: &[QObjectMethod],
+) -> String {
+    let mut result = String::new();
+    let _ = writeln!(
+        result,
+        "\n/// Methods of `{0}Private` called by the C++ class `{0}`.\npub trait {0}Impl{1} {{",
+        cls, supertraits
+    );
+    if let Some(constructor) = constructor {
+        let _ = writeln!(result, "    {}", constructor);
+    }
+    for meth in methods {
+        let mut args: Vec<String> = meth
+            .args
+            .iter()
+            .map(|arg| format!("{}: {}", arg.0, arg.1.rust_api_type()))
+            .collect();
+        if meth.const_ {
+            args.insert(0, "&self".into());
+        } else {
+            args.insert(0, "&mut self".into());
+        }
+        let _ = write!(
+            result,
+            "    fn {}({})",
+            to_snake_case(&meth.name),
+            args.join(", ")
+        );
+        if let Some(rtype) = &meth.rtype {
+            let _ = write!(result, " -> {}", rtype.rust_api_type());
+        }
+        result.push_str(";\n");
+    }
+    result.push_str("}\n");
+    result
+}
+
+fn generate_rust_gadget(gadget: &QGadgetConfig) -> String {
+    format!(
+        r#"
+#[repr(C)]
+pub struct {0} {{
+    _d: *mut std::ffi::c_void,
+}}
+
+impl {0} {{
+    #[allow(unused)]
+    pub fn new(value: {0}Private) -> Self {{
+        Self {{
+            _d: Box::into_raw(Box::new(value)) as *mut std::ffi::c_void,
+        }}
+    }}
+
+    #[allow(unused)]
+    pub fn static_meta_object() -> &'static qt5qml::core::QMetaObject {{
+        unsafe {{ &*Qffi_{0}_staticMetaObject() }}
+    }}
+
+    #[allow(unused)]
+    pub fn register_meta_type() -> i32 {{
+        unsafe {{ Qffi_{0}_registerMetaType() }}
+    }}
+
+    #[allow(unused)]
+    pub fn to_variant(&self) -> qt5qml::core::QVariant {{
+        let mut out__ = std::mem::MaybeUninit::<qt5qml::core::QVariant>::uninit();
+        unsafe {{ Qffi_{0}_toVariant(self, out__.as_mut_ptr()); }}
+        unsafe {{ out__.assume_init() }}
+    }}
+
+    #[allow(unused)]
+    pub fn from_variant(value: &qt5qml::core::QVariant) -> Option<Self> {{
+        let mut out = Self::default();
+        if unsafe {{ Qffi_{0}_fromVariant(value, &mut out) }} {{
+            Some(out)
+        }} else {{
+            None
+        }}
+    }}
+}}
+
+impl Default for {0} {{
+    fn default() -> Self {{
+        Self::new({0}Private::default())
+    }}
+}}
+
+impl Clone for {0} {{
+    fn clone(&self) -> Self {{
+        Self::new((**self).clone())
+    }}
+}}
+
+impl Drop for {0} {{
+    fn drop(&mut self) {{
+        unsafe {{ drop(Box::from_raw(self._d as *mut {0}Private)) }};
+    }}
+}}
+
+impl std::ops::Deref for {0} {{
+    type Target = {0}Private;
+
+    fn deref(&self) -> &{0}Private {{
+        unsafe {{ &*(self._d as *const {0}Private) }}
+    }}
+}}
+
+impl std::ops::DerefMut for {0} {{
+    fn deref_mut(&mut self) -> &mut {0}Private {{
+        unsafe {{ &mut *(self._d as *mut {0}Private) }}
+    }}
+}}
+"#,
+        gadget.name
+    )
+}
+
+fn generate_rust_enum(cls: &str, enum_: &QObjectEnum) -> String {
+    let mut result = String::new();
+    let values = enum_.resolved_values();
+    if enum_.flags.is_none() {
+        let _ = write!(
+            result,
+            r#"
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct {0}{1}(i32);
+
+#[allow(non_upper_case_globals)]
+impl {0}{1} {{
+"#,
+            cls, enum_.name
+        );
+        for (name, value) in values {
+            let _ = writeln!(result, "    pub const {}: Self = Self({});", name, value);
+        }
+        result.push_str(
+            r#"
+    #[allow(unused)]
+    pub const fn from_value(value: i32) -> Self {
+        Self(value)
+    }
+
+    #[allow(unused)]
+    pub const fn value(self) -> i32 {
+        self.0
+    }
+}
+"#,
+        );
+    } else {
+        let _ = write!(
+            result,
+            r#"
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct {0}{1}(i32);
+
+#[allow(non_upper_case_globals)]
+impl {0}{1} {{
+"#,
+            cls,
+            enum_.type_name()
+        );
+        for (name, value) in values {
+            let _ = writeln!(result, "    pub const {}: Self = Self({});", name, value);
+        }
+        let _ = write!(
+            result,
+            r#"
+    #[allow(unused)]
+    pub const fn empty() -> Self {{
+        Self(0)
+    }}
+
+    #[allow(unused)]
+    pub const fn from_bits(bits: i32) -> Self {{
+        Self(bits)
+    }}
+
+    #[allow(unused)]
+    pub const fn bits(self) -> i32 {{
+        self.0
+    }}
+
+    #[allow(unused)]
+    pub const fn contains(self, other: Self) -> bool {{
+        self.0 & other.0 == other.0
+    }}
+}}
+
+impl std::ops::BitOr for {0} {{
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {{
+        Self(self.0 | other.0)
+    }}
+}}
+
+impl std::ops::BitOrAssign for {0} {{
+    fn bitor_assign(&mut self, other: Self) {{
+        self.0 |= other.0;
+    }}
+}}
+
+impl std::ops::BitAnd for {0} {{
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {{
+        Self(self.0 & other.0)
+    }}
+}}
+"#,
+            format!("{}{}", cls, enum_.type_name())
+        );
+    }
+    result
+}
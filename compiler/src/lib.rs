@@ -1,29 +1,62 @@
-use std::env;
 use std::path::PathBuf;
 use std::process::exit;
+use std::{env, io};
 
 pub use cc::Build as CcBuild;
 
-use crate::build::build;
+use crate::build::{build, build_all, build_qml_module};
+pub use crate::error::{Diagnostic, DiagnosticKind, QObjectBuildError};
 use crate::moc::MocConfig;
+use crate::qgadget::QGadgetConfig;
+pub use crate::qmltypes::QmlModule;
 use crate::qobject::QObjectConfig;
-pub use crate::qobject::{QObjectMethod, QObjectProp, QObjectSignal};
+pub use crate::qobject::{PanicPolicy, QObjectEnum, QObjectMethod, QObjectProp, QObjectSignal};
+pub use crate::qt::QtInstallation;
 pub use crate::typeref::{Include, TypeRef, TypeRefTrait};
 
 pub(crate) mod dependent;
+pub mod error;
 pub(crate) mod ffi;
 pub(crate) mod generate;
 pub(crate) mod generate_rust;
 pub mod moc;
+pub mod qgadget;
+pub(crate) mod qmltypes;
 pub mod qobject;
+pub mod qt;
 pub mod typeref;
 mod utils;
 
 pub mod build;
 
+/// Path of the generated C++ file `qffi_<name>.cpp` in `OUT_DIR`.
+fn out_path(name: &str) -> Result<PathBuf, QObjectBuildError> {
+    let out_dir = env::var_os("OUT_DIR").ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            "OUT_DIR environment variable not set",
+        )
+    })?;
+    Ok(PathBuf::from(out_dir).join(format!("qffi_{}.cpp", name)))
+}
+
+/// Report build error to cargo and exit.
+fn exit_with_error(err: QObjectBuildError) -> ! {
+    for line in err.cargo_warnings() {
+        println!("{}", line);
+    }
+    eprintln!("{}", err);
+    exit(1);
+}
+
 /// Builder for a C++ Qt class.
+///
+/// The C++ class holds a Rust struct named `<Name>Private`, which must implement the generated
+/// trait `<Name>Impl`. The trait has a `new` function and a method for every method and slot
+/// that is not a proxy. Const methods take `&self`, all others `&mut self`.
 pub struct QObjectBuild {
     obj: QObjectConfig,
+    qml_module: Option<QmlModule>,
 }
 
 impl QObjectBuild {
@@ -31,6 +64,7 @@ impl QObjectBuild {
     pub fn new(name: &str) -> Self {
         Self {
             obj: QObjectConfig::new(name),
+            qml_module: None,
         }
     }
 
@@ -66,23 +100,213 @@ impl QObjectBuild {
         self
     }
 
-    /// Set that QML register function is generated.
+    /// Add an enum registered with `Q_ENUM`.
+    ///
+    /// The Rust type is a wrapper of `i32` with a constant for each enumerator, because C++ and
+    /// QML can pass any integer value.
+    pub fn enum_(&mut self, enum_: QObjectEnum) -> &mut Self {
+        self.obj.enum_(enum_);
+        self
+    }
+
+    /// Add a flags type named `name` registered with `Q_FLAG`.
+    ///
+    /// The enumerators of `enum_` are the single flags.
+    pub fn flags(&mut self, name: &str, enum_: QObjectEnum) -> &mut Self {
+        self.obj.flags(name, enum_);
+        self
+    }
+
+    /// Type reference to an enum or flags type of the generated class.
+    pub fn enum_type_ref(&self, name: &str) -> TypeRef {
+        TypeRef::generated_enum(&self.obj.name, name)
+    }
+
+    /// Set that QML register functions are generated.
+    ///
+    /// The generated class gets the functions `register_type`, `register_singleton` and
+    /// `register_uncreatable`. The object created by the factory of `register_singleton` is
+    /// owned by the QML engine. Every call of `register_singleton` keeps its own factory, which
+    /// requires Qt 5.14 or newer.
     pub fn qml(&mut self, value: bool) -> &mut Self {
         self.obj.qml(value);
         self
     }
 
+    /// Set handling of panics in the Rust implementation.
+    ///
+    /// Default is `PanicPolicy::Abort`.
+    pub fn panic_policy(&mut self, policy: PanicPolicy) -> &mut Self {
+        self.obj.panic_policy(policy);
+        self
+    }
+
+    /// Write `qmldir` and `plugins.qmltypes` files describing the class for QML tooling.
+    pub fn qml_module(&mut self, module: QmlModule) -> &mut Self {
+        self.qml_module = Some(module);
+        self
+    }
+
+    /// Type reference to the generated class.
+    ///
+    /// Use `.with_mut_ptr()` to pass objects of this class to other classes of the same
+    /// `QObjectBuildSet`.
+    pub fn type_ref(&self) -> TypeRef {
+        TypeRef::generated(self.obj.name.clone())
+    }
+
     /// Build C++ source file.
     ///
     /// This function generates the C++ source file, generates the moc file and compiles the
     /// sources. The compiled object file is added to the cargo build process.
+    ///
+    /// Exits the build script on errors. Use `try_build` to handle errors yourself.
     pub fn build(&self, cc: &CcBuild, moc: &MocConfig) {
-        // TODO: move logic out of here
-        let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR environment variable not set");
-        let path = PathBuf::from(out_dir).join(format!("qffi_{}.cpp", self.obj.name));
-        if let Err(err) = build(cc, moc, &path, &self.obj) {
-            eprintln!("Failed to compile C++ class: {:?}", err);
-            exit(1);
+        if let Err(err) = self.try_build(cc, moc) {
+            exit_with_error(err);
+        }
+    }
+
+    /// Build C++ source file and return errors.
+    pub fn try_build(&self, cc: &CcBuild, moc: &MocConfig) -> Result<(), QObjectBuildError> {
+        let path = out_path(&self.obj.name)?;
+        build(cc, moc, &path, &self.obj)?;
+        if let Some(module) = &self.qml_module {
+            build_qml_module(module, path.parent().unwrap(), &[], &[&self.obj])?;
+        }
+        Ok(())
+    }
+}
+
+/// Builder for a C++ value type with `Q_GADGET`.
+///
+/// The generated C++ class only holds a pointer to a Rust struct named `<Name>Private`, which
+/// must implement `Default`, `Clone` and the generated trait `<Name>Impl`. Copying the C++
+/// value clones the Rust struct. The generated Rust type `<Name>` owns the Rust struct, has the
+/// same layout as the C++ class and dereferences to the Rust struct. The type is declared with
+/// `Q_DECLARE_METATYPE`, so it can be stored in a `QVariant`.
+///
+/// The getter of a property is a `&self` method and the setter a `&mut self` method of the
+/// trait.
+pub struct QGadgetBuild {
+    gadget: QGadgetConfig,
+}
+
+impl QGadgetBuild {
+    /// Construct builder for a value type named `name`.
+    pub fn new(name: &str) -> Self {
+        Self {
+            gadget: QGadgetConfig::new(name),
+        }
+    }
+
+    /// Add a property.
+    ///
+    /// Getter and setter are named `<name>` and `set<Name>` if not set explicitly.
+    pub fn property(&mut self, prop: QObjectProp) -> &mut Self {
+        self.gadget.property(prop);
+        self
+    }
+
+    /// Add a `Q_INVOKABLE` method.
+    pub fn method(&mut self, meth: QObjectMethod) -> &mut Self {
+        self.gadget.method(meth);
+        self
+    }
+
+    /// Type reference to the generated value type.
+    ///
+    /// Use it to pass values to classes of the same `QObjectBuildSet`.
+    pub fn type_ref(&self) -> TypeRef {
+        self.gadget.type_ref()
+    }
+
+    /// Build C++ source file.
+    ///
+    /// This function generates the C++ source file, generates the moc file and compiles the
+    /// sources. The compiled object file is added to the cargo build process.
+    ///
+    /// Exits the build script on errors. Use `try_build` to handle errors yourself.
+    pub fn build(&self, cc: &CcBuild, moc: &MocConfig) {
+        if let Err(err) = self.try_build(cc, moc) {
+            exit_with_error(err);
+        }
+    }
+
+    /// Build C++ source file and return errors.
+    pub fn try_build(&self, cc: &CcBuild, moc: &MocConfig) -> Result<(), QObjectBuildError> {
+        let path = out_path(&self.gadget.name)?;
+        build_all(cc, moc, &path, &[&self.gadget], &[])
+    }
+}
+
+/// Builder for many C++ Qt classes in one translation unit.
+///
+/// All classes are generated into one C++ source file `qffi_<name>.cpp`, processed with one
+/// moc run and compiled into one static library. The Rust code of all classes is written to
+/// `qffi_<name>.rs`.
+pub struct QObjectBuildSet {
+    name: String,
+    gadgets: Vec<QGadgetConfig>,
+    objs: Vec<QObjectConfig>,
+    qml_module: Option<QmlModule>,
+}
+
+impl QObjectBuildSet {
+    /// Construct builder for a set of classes written to files named after `name`.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            gadgets: vec![],
+            objs: vec![],
+            qml_module: None,
+        }
+    }
+
+    /// Add a class.
+    ///
+    /// Classes are generated in the order they are added, so a base class must be added before
+    /// its sub classes.
+    pub fn add(&mut self, obj: &QObjectBuild) -> &mut Self {
+        self.objs.push(obj.obj.clone());
+        self
+    }
+
+    /// Add a value type.
+    ///
+    /// Value types are generated before all classes.
+    pub fn add_gadget(&mut self, gadget: &QGadgetBuild) -> &mut Self {
+        self.gadgets.push(gadget.gadget.clone());
+        self
+    }
+
+    /// Write `qmldir` and `plugins.qmltypes` files describing all classes for QML tooling.
+    pub fn qml_module(&mut self, module: QmlModule) -> &mut Self {
+        self.qml_module = Some(module);
+        self
+    }
+
+    /// Build C++ source file.
+    ///
+    /// This function generates the C++ source file, generates the moc file and compiles the
+    /// sources. The compiled object file is added to the cargo build process.
+    ///
+    /// Exits the build script on errors. Use `try_build` to handle errors yourself.
+    pub fn build(&self, cc: &CcBuild, moc: &MocConfig) {
+        if let Err(err) = self.try_build(cc, moc) {
+            exit_with_error(err);
+        }
+    }
+
+    /// Build C++ source file and return errors.
+    pub fn try_build(&self, cc: &CcBuild, moc: &MocConfig) -> Result<(), QObjectBuildError> {
+        let path = out_path(&self.name)?;
+        let gadgets: Vec<&QGadgetConfig> = self.gadgets.iter().collect();
+        let objs: Vec<&QObjectConfig> = self.objs.iter().collect();
+        build_all(cc, moc, &path, &gadgets, &objs)?;
+        if let Some(module) = &self.qml_module {
+            build_qml_module(module, path.parent().unwrap(), &gadgets, &objs)?;
         }
+        Ok(())
     }
 }
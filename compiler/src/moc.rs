@@ -1,16 +1,18 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+use std::env;
 use std::path::{Path, PathBuf};
 use std::process::Command;
-use std::{env, io};
+
+use crate::error::{parse_diagnostics, QObjectBuildError};
 
 #[derive(Clone)]
 pub struct MocConfig {
     moc: Option<PathBuf>,
     include_paths: Vec<PathBuf>,
     frameworks: Vec<String>,
-    macros: HashMap<String, Option<String>>,
+    macros: BTreeMap<String, Option<String>>,
     undefs: Vec<String>,
-    metadata: HashMap<String, String>,
+    metadata: BTreeMap<String, String>,
     compiler_flavor: Option<String>,
     path_prefix: Option<PathBuf>,
     override_include: Option<PathBuf>,
@@ -35,11 +37,77 @@ impl MocConfig {
         }
     }
 
+    /// Set path to moc executable.
+    ///
+    /// Default is the `MOC` environment variable or `moc`.
+    pub fn moc<T: Into<PathBuf>>(&mut self, path: T) -> &mut Self {
+        self.moc = Some(path.into());
+        self
+    }
+
+    /// Add include path (`-I`).
     pub fn include_path(&mut self, path: &Path) -> &mut Self {
         self.include_paths.push(path.into());
         self
     }
 
+    /// Add macOS framework include path (`-F`).
+    pub fn framework(&mut self, framework: &str) -> &mut Self {
+        self.frameworks.push(framework.into());
+        self
+    }
+
+    /// Define macro with optional value (`-D`).
+    pub fn define(&mut self, name: &str, value: Option<&str>) -> &mut Self {
+        self.macros
+            .insert(name.into(), value.map(|value| value.into()));
+        self
+    }
+
+    /// Undefine macro (`-U`).
+    pub fn undefine(&mut self, name: &str) -> &mut Self {
+        self.undefs.push(name.into());
+        self
+    }
+
+    /// Add plugin meta data (`-M`).
+    pub fn metadata(&mut self, key: &str, value: &str) -> &mut Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Set compiler flavor (`--compiler-flavor`).
+    ///
+    /// Either `unix` or `msvc`.
+    pub fn compiler_flavor(&mut self, flavor: &str) -> &mut Self {
+        self.compiler_flavor = Some(flavor.into());
+        self
+    }
+
+    /// Set path prefix for the included header file (`-p`).
+    pub fn path_prefix(&mut self, path: &Path) -> &mut Self {
+        self.path_prefix = Some(path.into());
+        self
+    }
+
+    /// Force `#include` of `path` in the generated source instead of the input file (`-f`).
+    pub fn override_include(&mut self, path: &Path) -> &mut Self {
+        self.override_include = Some(path.into());
+        self
+    }
+
+    /// Prepend `#include <path>` to the generated source (`-b`).
+    pub fn extra_include(&mut self, path: &Path) -> &mut Self {
+        self.extra_includes.push(path.into());
+        self
+    }
+
+    /// Parse `path` as an `#include` before the input file (`--include`).
+    pub fn include(&mut self, path: &Path) -> &mut Self {
+        self.includes.push(path.into());
+        self
+    }
+
     fn get_moc(&self) -> PathBuf {
         match &self.moc {
             Some(moc) => moc.clone(),
@@ -50,22 +118,61 @@ impl MocConfig {
         }
     }
 
-    pub fn build(&self, input: &Path, output: &Path) -> io::Result<()> {
+    pub(crate) fn command(&self, input: &Path, output: &Path) -> Command {
         let mut command = Command::new(self.get_moc());
         command.arg("-o").arg(output).arg(input);
         for include in &self.include_paths {
             command.arg("-I").arg(include);
         }
+        for framework in &self.frameworks {
+            command.arg(format!("-F{}", framework));
+        }
+        for (name, value) in &self.macros {
+            match value {
+                Some(value) => command.arg(format!("-D{}={}", name, value)),
+                None => command.arg(format!("-D{}", name)),
+            };
+        }
+        for name in &self.undefs {
+            command.arg(format!("-U{}", name));
+        }
+        for (key, value) in &self.metadata {
+            command.arg(format!("-M{}={}", key, value));
+        }
+        if let Some(flavor) = &self.compiler_flavor {
+            command.arg("--compiler-flavor").arg(flavor);
+        }
+        if let Some(prefix) = &self.path_prefix {
+            command.arg("-p").arg(prefix);
+        }
+        if let Some(include) = &self.override_include {
+            let mut arg = std::ffi::OsString::from("-f");
+            arg.push(include);
+            command.arg(arg);
+        }
+        for include in &self.extra_includes {
+            command.arg("-b").arg(include);
+        }
+        for include in &self.includes {
+            command.arg("--include").arg(include);
+        }
+        command
+    }
+
+    /// Run moc for `input` and write result to `output`.
+    pub fn build(&self, input: &Path, output: &Path) -> Result<(), QObjectBuildError> {
+        let mut command = self.command(input, output);
 
         println!("exec: {:?}", command);
         let result = command.output()?;
         if result.status.success() {
             Ok(())
         } else {
-            Err(io::Error::new(
-                io::ErrorKind::Other,
-                String::from_utf8_lossy(&result.stderr),
-            ))
+            let output = String::from_utf8_lossy(&result.stderr).into_owned();
+            Err(QObjectBuildError::Moc {
+                diagnostics: parse_diagnostics(&output),
+                output,
+            })
         }
     }
 }
@@ -75,3 +182,99 @@ impl Default for MocConfig {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(moc: &MocConfig) -> Vec<String> {
+        moc.command(Path::new("in.cpp"), Path::new("out.moc"))
+            .get_args()
+            .map(|arg| arg.to_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_default_command() {
+        let moc = MocConfig::new();
+        assert_eq!(vec!["-o", "out.moc", "in.cpp"], args(&moc));
+    }
+
+    #[test]
+    fn test_moc_path() {
+        let mut moc = MocConfig::new();
+        moc.moc("/opt/qt/bin/moc");
+        let command = moc.command(Path::new("in.cpp"), Path::new("out.moc"));
+        assert_eq!("/opt/qt/bin/moc", command.get_program());
+    }
+
+    #[test]
+    fn test_include_path() {
+        let mut moc = MocConfig::new();
+        moc.include_path(Path::new("/usr/include/qt"));
+        assert_eq!(&["-I", "/usr/include/qt"], &args(&moc)[3..]);
+    }
+
+    #[test]
+    fn test_framework() {
+        let mut moc = MocConfig::new();
+        moc.framework("/Library/Frameworks");
+        assert_eq!(&["-F/Library/Frameworks"], &args(&moc)[3..]);
+    }
+
+    #[test]
+    fn test_define() {
+        let mut moc = MocConfig::new();
+        moc.define("FOO", None).define("BAR", Some("1"));
+        assert_eq!(&["-DBAR=1", "-DFOO"], &args(&moc)[3..]);
+    }
+
+    #[test]
+    fn test_undefine() {
+        let mut moc = MocConfig::new();
+        moc.undefine("FOO");
+        assert_eq!(&["-UFOO"], &args(&moc)[3..]);
+    }
+
+    #[test]
+    fn test_metadata() {
+        let mut moc = MocConfig::new();
+        moc.metadata("key", "value");
+        assert_eq!(&["-Mkey=value"], &args(&moc)[3..]);
+    }
+
+    #[test]
+    fn test_compiler_flavor() {
+        let mut moc = MocConfig::new();
+        moc.compiler_flavor("msvc");
+        assert_eq!(&["--compiler-flavor", "msvc"], &args(&moc)[3..]);
+    }
+
+    #[test]
+    fn test_path_prefix() {
+        let mut moc = MocConfig::new();
+        moc.path_prefix(Path::new("src"));
+        assert_eq!(&["-p", "src"], &args(&moc)[3..]);
+    }
+
+    #[test]
+    fn test_override_include() {
+        let mut moc = MocConfig::new();
+        moc.override_include(Path::new("header.h"));
+        assert_eq!(&["-fheader.h"], &args(&moc)[3..]);
+    }
+
+    #[test]
+    fn test_extra_include() {
+        let mut moc = MocConfig::new();
+        moc.extra_include(Path::new("pch.h"));
+        assert_eq!(&["-b", "pch.h"], &args(&moc)[3..]);
+    }
+
+    #[test]
+    fn test_include() {
+        let mut moc = MocConfig::new();
+        moc.include(Path::new("macros.h"));
+        assert_eq!(&["--include", "macros.h"], &args(&moc)[3..]);
+    }
+}
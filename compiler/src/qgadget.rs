@@ -0,0 +1,131 @@
+use std::collections::HashSet;
+
+use crate::qobject::{QObjectMethod, QObjectProp};
+use crate::typeref::TypeRef;
+use crate::utils::{is_identifier, upper_first};
+
+#[derive(Clone, Debug)]
+pub struct QGadgetConfig {
+    pub(crate) name: String,
+    pub(crate) properties: Vec<QObjectProp>,
+    pub(crate) methods: Vec<QObjectMethod>,
+}
+
+impl QGadgetConfig {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            properties: vec![],
+            methods: vec![],
+        }
+    }
+
+    /// Add a property.
+    ///
+    /// The getter is named like the property and the setter `set<Name>` when not set
+    /// explicitly with `read` or `write`. Constant properties have no setter.
+    pub fn property(&mut self, mut prop: QObjectProp) -> &mut Self {
+        if prop.getter.is_none() {
+            prop.getter = Some(prop.name.clone());
+        }
+        if prop.setter.is_none() && !prop.const_ {
+            prop.setter = Some(format!("set{}", upper_first(&prop.name)));
+        }
+        self.properties.push(prop);
+        self
+    }
+
+    /// Add a `Q_INVOKABLE` method.
+    pub fn method(&mut self, meth: QObjectMethod) -> &mut Self {
+        self.methods.push(meth.invokable().attach_to(&self.name));
+        self
+    }
+
+    /// Getter and setter methods of the properties.
+    pub(crate) fn accessors(&self) -> Vec<QObjectMethod> {
+        let mut result = vec![];
+        for prop in &self.properties {
+            if let Some(getter) = &prop.getter {
+                result.push(
+                    QObjectMethod::new(getter)
+                        .ret_type(prop.type_ref.clone())
+                        .const_()
+                        .attach_to(&self.name),
+                );
+            }
+            if let Some(setter) = &prop.setter {
+                result.push(
+                    QObjectMethod::new(setter)
+                        .arg_with_type("value", prop.auto_value_type())
+                        .attach_to(&self.name),
+                );
+            }
+        }
+        result
+    }
+
+    /// Check the configuration for inconsistencies like `QObjectConfig::validate`.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = vec![];
+        let mut error =
+            |message: String| errors.push(format!("gadget `{}`: {}", self.name, message));
+
+        if !is_identifier(&self.name) {
+            error("gadget name is not a valid identifier".to_string());
+        }
+
+        let mut seen = HashSet::new();
+        for prop in &self.properties {
+            if !is_identifier(&prop.name) {
+                error(format!(
+                    "property name `{}` is not a valid identifier",
+                    prop.name
+                ));
+            } else if !seen.insert(&prop.name) {
+                error(format!(
+                    "property `{}` is defined more than once",
+                    prop.name
+                ));
+            }
+        }
+
+        let accessors = self.accessors();
+        let functions = accessors
+            .iter()
+            .map(|meth| ("property accessor", meth))
+            .chain(self.methods.iter().map(|meth| ("method", meth)));
+        let mut seen = HashSet::new();
+        for (kind, meth) in functions {
+            if !is_identifier(&meth.name) {
+                error(format!(
+                    "{} name `{}` is not a valid identifier",
+                    kind, meth.name
+                ));
+            } else if !seen.insert(&meth.name) {
+                error(format!(
+                    "{} `{}` is defined more than once",
+                    kind, meth.name
+                ));
+            }
+            if let Some(rtype) = &meth.rtype {
+                if rtype.is_borrowed_conversion() {
+                    error(format!(
+                        "method `{}` can not return `{}`, which is only supported for arguments",
+                        meth.name,
+                        rtype.rust_api_type()
+                    ));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    pub(crate) fn type_ref(&self) -> TypeRef {
+        TypeRef::generated(self.name.clone())
+    }
+}
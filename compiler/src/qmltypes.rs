@@ -0,0 +1,355 @@
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use crate::qgadget::QGadgetConfig;
+use crate::qobject::{QObjectConfig, QObjectEnum, QObjectMethod, QObjectProp, QObjectSignal};
+use crate::typeref::TypeRef;
+
+/// QML module described by the generated `qmldir` and `plugins.qmltypes` files.
+///
+/// The files are only read by tools like QtCreator and qmllint. The types still have to be
+/// registered at runtime with the generated `register_*` functions using the same URI,
+/// version and names.
+#[derive(Clone, Debug)]
+pub struct QmlModule {
+    pub(crate) uri: String,
+    pub(crate) version_major: i32,
+    pub(crate) version_minor: i32,
+    pub(crate) dir: Option<PathBuf>,
+    pub(crate) singletons: Vec<String>,
+    pub(crate) uncreatables: Vec<String>,
+}
+
+impl QmlModule {
+    pub fn new(uri: &str, version_major: i32, version_minor: i32) -> Self {
+        Self {
+            uri: uri.to_string(),
+            version_major,
+            version_minor,
+            dir: None,
+            singletons: vec![],
+            uncreatables: vec![],
+        }
+    }
+
+    /// Set output directory.
+    ///
+    /// Default is `OUT_DIR`.
+    pub fn dir<T: Into<PathBuf>>(mut self, dir: T) -> Self {
+        self.dir = Some(dir.into());
+        self
+    }
+
+    /// Mark class as registered with `register_singleton`.
+    pub fn singleton(mut self, class_name: &str) -> Self {
+        self.singletons.push(class_name.to_string());
+        self
+    }
+
+    /// Mark class as registered with `register_uncreatable`.
+    pub fn uncreatable(mut self, class_name: &str) -> Self {
+        self.uncreatables.push(class_name.to_string());
+        self
+    }
+}
+
+/// Name of a type in QML type descriptions and whether it is a pointer.
+fn qml_type(type_ref: &TypeRef) -> (String, bool) {
+    let mut name = type_ref.cpp_type().trim();
+    if let Some(stripped) = name.strip_prefix("const ") {
+        name = stripped.trim();
+    }
+    if let Some(stripped) = name.strip_suffix('&') {
+        name = stripped.trim();
+    }
+    let (name, is_pointer) = match name.strip_suffix('*') {
+        Some(stripped) => (stripped.trim(), true),
+        None => (name, false),
+    };
+    let name = match name {
+        "qint8" | "qint16" | "qint32" => "int",
+        "quint8" | "quint16" | "quint32" => "uint",
+        "qint64" => "qlonglong",
+        "quint64" => "qulonglong",
+        name => name,
+    };
+    (name.to_string(), is_pointer)
+}
+
+fn write_type(result: &mut String, type_ref: &TypeRef) {
+    let (name, is_pointer) = qml_type(type_ref);
+    let _ = write!(result, "; type: \"{}\"", name);
+    if is_pointer {
+        result.push_str("; isPointer: true");
+    }
+}
+
+fn write_enum(result: &mut String, enum_: &QObjectEnum) {
+    result.push_str("        Enum {\n");
+    let _ = writeln!(result, "            name: \"{}\"", enum_.type_name());
+    if enum_.flags.is_some() {
+        result.push_str("            isFlag: true\n");
+    }
+    result.push_str("            values: {\n");
+    let values: Vec<String> = enum_
+        .resolved_values()
+        .iter()
+        .map(|(name, value)| format!("                \"{}\": {}", name, value))
+        .collect();
+    result.push_str(&values.join(",\n"));
+    result.push_str("\n            }\n");
+    result.push_str("        }\n");
+}
+
+fn write_property(result: &mut String, prop: &QObjectProp) {
+    let _ = write!(result, "        Property {{ name: \"{}\"", prop.name);
+    write_type(result, &prop.type_ref);
+    if let Some(revision) = prop.revision {
+        let _ = write!(result, "; revision: {}", revision);
+    }
+    if prop.setter.is_none() && prop.member.is_none() {
+        result.push_str("; isReadonly: true");
+    }
+    result.push_str(" }\n");
+}
+
+fn write_parameters(result: &mut String, args: &[(String, TypeRef)]) {
+    for (name, type_ref) in args {
+        let _ = write!(result, "            Parameter {{ name: \"{}\"", name);
+        write_type(result, type_ref);
+        result.push_str(" }\n");
+    }
+}
+
+fn write_signal(result: &mut String, signal: &QObjectSignal) {
+    result.push_str("        Signal {\n");
+    let _ = writeln!(result, "            name: \"{}\"", signal.name);
+    write_parameters(result, &signal.args);
+    result.push_str("        }\n");
+}
+
+fn write_method(result: &mut String, meth: &QObjectMethod) {
+    result.push_str("        Method {\n");
+    let _ = writeln!(result, "            name: \"{}\"", meth.name);
+    if let Some(rtype) = &meth.rtype {
+        let (name, is_pointer) = qml_type(rtype);
+        let _ = writeln!(result, "            type: \"{}\"", name);
+        if is_pointer {
+            result.push_str("            isPointer: true\n");
+        }
+    }
+    write_parameters(result, &meth.args);
+    result.push_str("        }\n");
+}
+
+fn write_object(result: &mut String, module: &QmlModule, obj: &QObjectConfig) {
+    result.push_str("    Component {\n");
+    let _ = writeln!(result, "        name: \"{}\"", obj.name);
+    let _ = writeln!(
+        result,
+        "        prototype: \"{}\"",
+        qml_type(&obj.base_class).0
+    );
+    if obj.qml {
+        let _ = writeln!(
+            result,
+            "        exports: [\"{}/{} {}.{}\"]",
+            module.uri, obj.name, module.version_major, module.version_minor
+        );
+        result.push_str("        exportMetaObjectRevisions: [0]\n");
+        if module.singletons.contains(&obj.name) {
+            result.push_str("        isCreatable: false\n");
+            result.push_str("        isSingleton: true\n");
+        } else if module.uncreatables.contains(&obj.name) {
+            result.push_str("        isCreatable: false\n");
+        }
+    }
+    for enum_ in &obj.enums {
+        write_enum(result, enum_);
+    }
+    for prop in &obj.properties {
+        write_property(result, prop);
+    }
+    for signal in &obj.signals {
+        write_signal(result, signal);
+    }
+    let methods = obj
+        .slots
+        .iter()
+        .chain(obj.methods.iter().filter(|m| m.invokable || m.scriptable));
+    for meth in methods {
+        write_method(result, meth);
+    }
+    result.push_str("    }\n");
+}
+
+fn write_gadget(result: &mut String, gadget: &QGadgetConfig) {
+    result.push_str("    Component {\n");
+    let _ = writeln!(result, "        name: \"{}\"", gadget.name);
+    for prop in &gadget.properties {
+        write_property(result, prop);
+    }
+    for meth in &gadget.methods {
+        write_method(result, meth);
+    }
+    result.push_str("    }\n");
+}
+
+/// Generate content of `plugins.qmltypes`.
+pub(crate) fn generate_qmltypes(
+    module: &QmlModule,
+    gadgets: &[&QGadgetConfig],
+    objects: &[&QObjectConfig],
+) -> String {
+    let mut result = String::with_capacity(4 * 1024);
+    result.push_str("import QtQuick.tooling 1.2\n");
+    result.push('\n');
+    result.push_str("// This file describes the types generated by qobject compiler.\n");
+    result.push_str("// It is used for QML tooling purposes only.\n");
+    result.push('\n');
+    result.push_str("Module {\n");
+    result.push_str("    dependencies: [\"QtQuick 2.0\"]\n");
+    for gadget in gadgets {
+        write_gadget(&mut result, gadget);
+    }
+    for obj in objects {
+        write_object(&mut result, module, obj);
+    }
+    result.push_str("}\n");
+    result
+}
+
+/// Generate content of `qmldir`.
+pub(crate) fn generate_qmldir(module: &QmlModule) -> String {
+    format!("module {}\ntypeinfo plugins.qmltypes\n", module.uri)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::typeref::QString;
+
+    #[test]
+    fn test_qmldir() {
+        let module = QmlModule::new("org.example", 1, 0);
+        assert_eq!(
+            "module org.example\ntypeinfo plugins.qmltypes\n",
+            generate_qmldir(&module)
+        );
+    }
+
+    #[test]
+    fn test_qml_type() {
+        assert_eq!(
+            ("int".to_string(), false),
+            qml_type(&TypeRef::from_type::<i32>())
+        );
+        assert_eq!(
+            ("QString".to_string(), false),
+            qml_type(&TypeRef::from_type::<&QString>())
+        );
+        assert_eq!(
+            ("QObject".to_string(), true),
+            qml_type(&TypeRef::qobject_ptr())
+        );
+    }
+
+    #[test]
+    fn test_qmltypes_class() {
+        let mut obj = QObjectConfig::new("Dummy");
+        obj.enum_(QObjectEnum::new("Mode").value("Off").value("On"))
+            .property(
+                QObjectProp::new::<i32>("count")
+                    .read("count")
+                    .write("setCount")
+                    .notify("countChanged"),
+            )
+            .property(QObjectProp::new::<QString>("name").read("name"))
+            .signal(QObjectSignal::new("countChanged").arg::<i32>("count"))
+            .slot(QObjectMethod::new("clear"))
+            .method(QObjectMethod::new("hidden"))
+            .method(
+                QObjectMethod::new("find")
+                    .invokable()
+                    .arg::<&QString>("name")
+                    .ret_type(TypeRef::qobject_ptr()),
+            );
+        let module = QmlModule::new("org.example", 1, 2);
+        let code = generate_qmltypes(&module, &[], &[&obj]);
+
+        println!("{}", code);
+
+        assert!(code.starts_with("import QtQuick.tooling 1.2\n"));
+        assert!(code.contains("        name: \"Dummy\"\n        prototype: \"QObject\"\n"));
+        assert!(code.contains("exports: [\"org.example/Dummy 1.2\"]"));
+        assert!(code.contains("            name: \"Mode\"\n"));
+        assert!(code.contains("                \"Off\": 0,\n                \"On\": 1\n"));
+        assert!(code.contains("Property { name: \"count\"; type: \"int\" }"));
+        assert!(code.contains("Property { name: \"name\"; type: \"QString\"; isReadonly: true }"));
+        assert!(code.contains(
+            "            name: \"countChanged\"\n            Parameter { name: \"count\"; type: \"int\" }\n"
+        ));
+        assert!(code.contains("            name: \"clear\"\n"));
+        assert!(!code.contains("hidden"));
+        assert!(code.contains(
+            "            name: \"find\"\n            type: \"QObject\"\n            isPointer: true\n"
+        ));
+        assert!(!code.contains("isSingleton"));
+        assert!(!code.contains("isFlag"));
+    }
+
+    #[test]
+    fn test_qmltypes_flags() {
+        let mut obj = QObjectConfig::new("Dummy");
+        obj.flags(
+            "Options",
+            QObjectEnum::new("Option").value("Bold").value("Italic"),
+        );
+        let module = QmlModule::new("org.example", 1, 0);
+        let code = generate_qmltypes(&module, &[], &[&obj]);
+
+        assert!(code.contains("            name: \"Options\"\n            isFlag: true\n"));
+        assert!(code.contains("                \"Bold\": 1,\n                \"Italic\": 2\n"));
+    }
+
+    #[test]
+    fn test_qmltypes_singleton() {
+        let obj = QObjectConfig::new("Service");
+        let module = QmlModule::new("org.example", 1, 0).singleton("Service");
+        let code = generate_qmltypes(&module, &[], &[&obj]);
+
+        assert!(code.contains("isCreatable: false\n        isSingleton: true\n"));
+    }
+
+    #[test]
+    fn test_qmltypes_uncreatable() {
+        let obj = QObjectConfig::new("Item");
+        let module = QmlModule::new("org.example", 1, 0).uncreatable("Item");
+        let code = generate_qmltypes(&module, &[], &[&obj]);
+
+        assert!(code.contains("isCreatable: false\n"));
+        assert!(!code.contains("isSingleton"));
+    }
+
+    #[test]
+    fn test_qmltypes_no_qml() {
+        let mut obj = QObjectConfig::new("Internal");
+        obj.qml(false);
+        let module = QmlModule::new("org.example", 1, 0);
+        let code = generate_qmltypes(&module, &[], &[&obj]);
+
+        assert!(code.contains("name: \"Internal\""));
+        assert!(!code.contains("exports"));
+    }
+
+    #[test]
+    fn test_qmltypes_gadget() {
+        let mut gadget = QGadgetConfig::new("Point");
+        gadget.property(QObjectProp::new::<i32>("x"));
+        let module = QmlModule::new("org.example", 1, 0);
+        let code = generate_qmltypes(&module, &[&gadget], &[]);
+
+        assert!(code.contains("        name: \"Point\"\n"));
+        assert!(code.contains("Property { name: \"x\"; type: \"int\" }"));
+    }
+}
@@ -1,4 +1,7 @@
-use crate::typeref::{TypeRef, TypeRefTrait};
+use std::collections::HashSet;
+
+use crate::typeref::{Include, QModelIndex, QVector, TypeRef, TypeRefTrait};
+use crate::utils::{is_identifier, upper_first};
 
 #[derive(Clone, Debug)]
 pub struct QObjectProp {
@@ -7,7 +10,16 @@ pub struct QObjectProp {
     pub(crate) getter: Option<String>,
     pub(crate) setter: Option<String>,
     pub(crate) signal: Option<String>,
+    pub(crate) reset: Option<String>,
+    pub(crate) member: Option<String>,
+    pub(crate) revision: Option<i32>,
+    pub(crate) designable: Option<bool>,
+    pub(crate) scriptable: Option<bool>,
+    pub(crate) stored: Option<bool>,
+    pub(crate) user: bool,
     pub(crate) const_: bool,
+    pub(crate) final_: bool,
+    pub(crate) auto: bool,
 }
 
 impl QObjectProp {
@@ -18,7 +30,16 @@ impl QObjectProp {
             getter: None,
             setter: None,
             signal: None,
+            reset: None,
+            member: None,
+            revision: None,
+            designable: None,
+            scriptable: None,
+            stored: None,
+            user: false,
             const_: false,
+            final_: false,
+            auto: false,
         }
     }
 
@@ -29,7 +50,16 @@ impl QObjectProp {
             getter: None,
             setter: None,
             signal: None,
+            reset: None,
+            member: None,
+            revision: None,
+            designable: None,
+            scriptable: None,
+            stored: None,
+            user: false,
             const_: false,
+            final_: false,
+            auto: false,
         }
     }
 
@@ -48,10 +78,80 @@ impl QObjectProp {
         self
     }
 
+    /// Set method that resets the property to its default value.
+    pub fn reset<T: Into<String>>(mut self, reset: T) -> Self {
+        self.reset = Some(reset.into());
+        self
+    }
+
+    /// Store the property in a C++ member variable of the generated class.
+    ///
+    /// The member is readable and writable without READ and WRITE methods.
+    pub fn member<T: Into<String>>(mut self, member: T) -> Self {
+        self.member = Some(member.into());
+        self
+    }
+
+    pub fn revision(mut self, revision: i32) -> Self {
+        self.revision = Some(revision);
+        self
+    }
+
+    pub fn designable(mut self, value: bool) -> Self {
+        self.designable = Some(value);
+        self
+    }
+
+    pub fn scriptable(mut self, value: bool) -> Self {
+        self.scriptable = Some(value);
+        self
+    }
+
+    pub fn stored(mut self, value: bool) -> Self {
+        self.stored = Some(value);
+        self
+    }
+
+    /// Mark as user-facing or user-editable property of the class.
+    pub fn user(mut self) -> Self {
+        self.user = true;
+        self
+    }
+
     pub fn const_(mut self) -> Self {
         self.const_ = true;
         self
     }
+
+    /// Mark that the property will not be overridden by a derived class.
+    pub fn final_(mut self) -> Self {
+        self.final_ = true;
+        self
+    }
+
+    /// Generate storage, getter and setter for the property.
+    ///
+    /// The value is stored in the C++ class. The getter is named like the property and the
+    /// setter `set<Name>` when not set explicitly with `read` or `write`. The setter emits the
+    /// notify signal only when the value changed. The notify signal must be added to the class
+    /// as `QObjectSignal` and can have the new value as its only argument.
+    pub fn auto(mut self) -> Self {
+        self.auto = true;
+        self
+    }
+
+    pub(crate) fn auto_member(&self) -> String {
+        format!("m_{}", self.name)
+    }
+
+    /// Type of the setter argument of an auto property.
+    pub(crate) fn auto_value_type(&self) -> TypeRef {
+        if self.type_ref.return_safe() {
+            self.type_ref.clone()
+        } else {
+            self.type_ref.clone().with_const_ref()
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -153,8 +253,12 @@ impl QObjectMethod {
         self
     }
 
-    pub(crate) fn attach(mut self, cls: &QObjectConfig) -> Self {
-        self.ffi_name = Some(format!("Qffi_{}_{}", cls.name, self.name));
+    pub(crate) fn attach(self, cls: &QObjectConfig) -> Self {
+        self.attach_to(&cls.name)
+    }
+
+    pub(crate) fn attach_to(mut self, cls: &str) -> Self {
+        self.ffi_name = Some(format!("Qffi_{}_{}", cls, self.name));
         self
     }
 
@@ -165,6 +269,188 @@ impl QObjectMethod {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct QObjectEnum {
+    pub(crate) name: String,
+    pub(crate) values: Vec<(String, Option<i32>)>,
+    pub(crate) flags: Option<String>,
+}
+
+impl QObjectEnum {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            values: vec![],
+            flags: None,
+        }
+    }
+
+    /// Add enumerator with next value.
+    ///
+    /// For enums the next value is the previous value plus one, for flags the next unused bit.
+    pub fn value(mut self, name: &str) -> Self {
+        self.values.push((name.into(), None));
+        self
+    }
+
+    /// Add enumerator with explicit value.
+    pub fn value_with(mut self, name: &str, value: i32) -> Self {
+        self.values.push((name.into(), Some(value)));
+        self
+    }
+
+    /// Enumerators with their resolved values.
+    ///
+    /// The value is `None`, when the next value does not fit into 32 bits.
+    fn try_resolved_values(&self) -> Vec<(&str, Option<i32>)> {
+        let mut next = Some(if self.flags.is_some() { 1 } else { 0 });
+        self.values
+            .iter()
+            .map(|(name, value)| {
+                let value = value.or(next);
+                next = value.and_then(|value| {
+                    if self.flags.is_some() {
+                        (value as u32)
+                            .checked_add(1)
+                            .and_then(u32::checked_next_power_of_two)
+                            .map(|next| next as i32)
+                    } else {
+                        value.checked_add(1)
+                    }
+                });
+                (name as &str, value)
+            })
+            .collect()
+    }
+
+    /// Enumerators with their resolved values.
+    ///
+    /// Values which do not fit into 32 bits are reported by `QObjectConfig::validate`.
+    pub(crate) fn resolved_values(&self) -> Vec<(&str, i32)> {
+        self.try_resolved_values()
+            .into_iter()
+            .map(|(name, value)| (name, value.unwrap_or_default()))
+            .collect()
+    }
+
+    /// Name of the declared type: the enum name or the flags name.
+    pub(crate) fn type_name(&self) -> &str {
+        self.flags.as_ref().unwrap_or(&self.name)
+    }
+}
+
+/// Handling of Rust panics in functions called by the generated C++ code.
+///
+/// Panics must not unwind into C++ code. A panic in the constructor of the private struct
+/// always aborts the process, because no object can be created.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum PanicPolicy {
+    /// Print the panic message and abort the process.
+    #[default]
+    Abort,
+    /// Print the panic message and return the default value of the return type.
+    ///
+    /// Return types must implement `Default`, raw pointers are returned as null pointers.
+    /// References can not be returned.
+    LogAndDefault,
+    /// Call the function at the path with the name of the C++ entry point and the panic
+    /// payload and return the default value of the return type like `LogAndDefault`.
+    ///
+    /// The function must have the type `qt5qml::panic::PanicHook`.
+    Hook(String),
+}
+
+impl PanicPolicy {
+    /// Rust code evaluating `body` of the FFI function `function` and handling panics.
+    ///
+    /// `rtype` is the type returned by the FFI function.
+    pub(crate) fn catch_unwind(
+        &self,
+        function: &str,
+        body: &str,
+        rtype: Option<&TypeRef>,
+    ) -> String {
+        let fallback = match rtype.map(|rtype| rtype.rust_type()) {
+            Some(rtype) if rtype.starts_with("*const ") => "unwrap_or_else(std::ptr::null)",
+            Some(rtype) if rtype.starts_with("*mut ") => "unwrap_or_else(std::ptr::null_mut)",
+            _ => "unwrap_or_default()",
+        };
+        self.generate_catch_unwind(function, body, fallback)
+    }
+
+    /// Rust code evaluating `body` of the FFI constructor `function` and handling panics.
+    pub(crate) fn catch_unwind_constructor(&self, function: &str, body: &str) -> String {
+        self.generate_catch_unwind(function, body, "unwrap_or_else(|| std::process::abort())")
+    }
+
+    fn generate_catch_unwind(&self, function: &str, body: &str, fallback: &str) -> String {
+        let hook = match self {
+            PanicPolicy::Abort => {
+                return format!(
+                    "qt5qml::panic::abort_on_panic(\"{}\", || {{ {} }})",
+                    function, body
+                )
+            }
+            PanicPolicy::LogAndDefault => "qt5qml::panic::log_panic",
+            PanicPolicy::Hook(path) => path,
+        };
+        format!(
+            "qt5qml::panic::catch_panic(\"{}\", {}, || {{ {} }}).{}",
+            function, hook, body, fallback
+        )
+    }
+}
+
+/// Proxies for the protected API of `QAbstractItemModel` to mutate the model.
+fn item_model_proxies(base: &str) -> Vec<QObjectMethod> {
+    let range = |name: &str, parent: &str| {
+        QObjectMethod::new(name)
+            .proxy(base)
+            .arg::<&QModelIndex>(parent)
+            .arg::<i32>("first")
+            .arg::<i32>("last")
+    };
+    let move_range = |name: &str| {
+        range(name, "source_parent")
+            .arg::<&QModelIndex>("destination_parent")
+            .arg::<i32>("destination")
+            .ret::<bool>()
+    };
+    let end = |name: &str| QObjectMethod::new(name).proxy(base);
+
+    vec![
+        range("beginInsertRows", "parent"),
+        end("endInsertRows"),
+        range("beginRemoveRows", "parent"),
+        end("endRemoveRows"),
+        move_range("beginMoveRows"),
+        end("endMoveRows"),
+        range("beginInsertColumns", "parent"),
+        end("endInsertColumns"),
+        range("beginRemoveColumns", "parent"),
+        end("endRemoveColumns"),
+        move_range("beginMoveColumns"),
+        end("endMoveColumns"),
+        end("beginResetModel"),
+        end("endResetModel"),
+        QObjectMethod::new("createIndex")
+            .proxy(base)
+            .const_()
+            .arg::<i32>("row")
+            .arg::<i32>("column")
+            .arg_with_type(
+                "id",
+                TypeRef::new(
+                    "quintptr",
+                    "usize",
+                    true,
+                    Some(Include::System("QtGlobal".into())),
+                ),
+            )
+            .ret::<QModelIndex>(),
+    ]
+}
+
 #[derive(Clone, Debug)]
 pub struct QObjectConfig {
     pub(crate) name: String,
@@ -173,7 +459,9 @@ pub struct QObjectConfig {
     pub(crate) methods: Vec<QObjectMethod>,
     pub(crate) slots: Vec<QObjectMethod>,
     pub(crate) signals: Vec<QObjectSignal>,
+    pub(crate) enums: Vec<QObjectEnum>,
     pub(crate) qml: bool,
+    pub(crate) panic_policy: PanicPolicy,
 }
 
 impl QObjectConfig {
@@ -185,7 +473,9 @@ impl QObjectConfig {
             methods: vec![],
             signals: vec![],
             slots: vec![],
+            enums: vec![],
             qml: true,
+            panic_policy: PanicPolicy::default(),
         }
     }
 
@@ -194,13 +484,27 @@ impl QObjectConfig {
         self
     }
 
-    pub fn property(&mut self, prop: QObjectProp) -> &mut Self {
+    /// Add a property.
+    ///
+    /// Getters of properties are const like Qt expects, so the method of the class used as
+    /// getter is made const, even when it was not declared const.
+    pub fn property(&mut self, mut prop: QObjectProp) -> &mut Self {
+        if prop.auto {
+            if prop.getter.is_none() {
+                prop.getter = Some(prop.name.clone());
+            }
+            if prop.setter.is_none() {
+                prop.setter = Some(format!("set{}", upper_first(&prop.name)));
+            }
+        }
         self.properties.push(prop);
+        self.make_getters_const();
         self
     }
 
     pub fn method(&mut self, meth: QObjectMethod) -> &mut Self {
         self.methods.push(meth.attach(self));
+        self.make_getters_const();
         self
     }
 
@@ -211,6 +515,33 @@ impl QObjectConfig {
 
     pub fn slot(&mut self, slot: QObjectMethod) -> &mut Self {
         self.slots.push(slot.attach(self));
+        self.make_getters_const();
+        self
+    }
+
+    fn make_getters_const(&mut self) {
+        let getters: Vec<&String> = self
+            .properties
+            .iter()
+            .filter_map(|prop| prop.getter.as_ref())
+            .collect();
+        for meth in self.methods.iter_mut().chain(self.slots.iter_mut()) {
+            if getters.contains(&&meth.name) {
+                meth.const_ = true;
+            }
+        }
+    }
+
+    /// Add a `Q_ENUM` enum.
+    pub fn enum_(&mut self, enum_: QObjectEnum) -> &mut Self {
+        self.enums.push(enum_);
+        self
+    }
+
+    /// Add a `Q_FLAG` flags type named `name` for the enum `enum_`.
+    pub fn flags(&mut self, name: &str, mut enum_: QObjectEnum) -> &mut Self {
+        enum_.flags = Some(name.to_string());
+        self.enums.push(enum_);
         self
     }
 
@@ -219,4 +550,223 @@ impl QObjectConfig {
         self.qml = value;
         self
     }
+
+    /// Whether the base class is one of the abstract Qt item models.
+    pub(crate) fn is_item_model(&self) -> bool {
+        matches!(
+            self.base_class.cpp_type(),
+            "QAbstractItemModel" | "QAbstractListModel" | "QAbstractTableModel"
+        )
+    }
+
+    /// Methods of the class including the proxies generated for the base class.
+    ///
+    /// For item models proxies for the protected model API like `beginInsertRows` and
+    /// `createIndex` are added, unless a method with the same name is declared explicitly.
+    pub(crate) fn all_methods(&self) -> Vec<QObjectMethod> {
+        let mut methods = self.methods.clone();
+        if self.is_item_model() {
+            for meth in item_model_proxies(self.base_class.cpp_type()) {
+                if !methods
+                    .iter()
+                    .chain(self.slots.iter())
+                    .any(|m| m.name == meth.name)
+                {
+                    methods.push(meth.attach(self));
+                }
+            }
+        }
+        methods
+    }
+
+    /// Signals which can be emitted from Rust including inherited signals of the base class.
+    ///
+    /// For item models `dataChanged` is added.
+    pub(crate) fn emitted_signals(&self) -> Vec<QObjectSignal> {
+        let mut signals = self.signals.clone();
+        if self.is_item_model() && !signals.iter().any(|s| s.name == "dataChanged") {
+            signals.push(
+                QObjectSignal::new("dataChanged")
+                    .arg::<&QModelIndex>("top_left")
+                    .arg::<&QModelIndex>("bottom_right")
+                    .arg::<&QVector<i32>>("roles"),
+            );
+        }
+        signals
+    }
+
+    /// Check the configuration for inconsistencies.
+    ///
+    /// Returns a message for every error found, so all of them can be reported at once
+    /// instead of surfacing as moc or C++ compiler errors.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = vec![];
+        let mut error =
+            |message: String| errors.push(format!("class `{}`: {}", self.name, message));
+
+        if !is_identifier(&self.name) {
+            error("class name is not a valid identifier".to_string());
+        }
+
+        // names of member functions generated for auto properties
+        let auto_accessors: Vec<&String> = self
+            .properties
+            .iter()
+            .filter(|prop| prop.auto)
+            .flat_map(|prop| prop.getter.iter().chain(prop.setter.iter()))
+            .collect();
+        let functions: Vec<(&str, &String)> = self
+            .methods
+            .iter()
+            .map(|meth| ("method", &meth.name))
+            .chain(self.slots.iter().map(|slot| ("slot", &slot.name)))
+            .chain(self.signals.iter().map(|signal| ("signal", &signal.name)))
+            .chain(
+                auto_accessors
+                    .iter()
+                    .map(|name| ("auto property accessor", *name)),
+            )
+            .collect();
+        let is_function = |name: &String| {
+            self.methods
+                .iter()
+                .chain(self.slots.iter())
+                .any(|meth| &meth.name == name)
+        };
+
+        let mut seen = HashSet::new();
+        for (kind, name) in &functions {
+            if !is_identifier(name) {
+                error(format!(
+                    "{} name `{}` is not a valid identifier",
+                    kind, name
+                ));
+            } else if !seen.insert(*name) {
+                error(format!("{} `{}` is defined more than once", kind, name));
+            }
+        }
+
+        let mut seen = HashSet::new();
+        for prop in &self.properties {
+            if !is_identifier(&prop.name) {
+                error(format!(
+                    "property name `{}` is not a valid identifier",
+                    prop.name
+                ));
+            } else if !seen.insert(&prop.name) {
+                error(format!(
+                    "property `{}` is defined more than once",
+                    prop.name
+                ));
+            }
+
+            if prop.getter.is_none() && prop.member.is_none() {
+                error(format!(
+                    "property `{}` has neither a getter nor a member",
+                    prop.name
+                ));
+            }
+            if !prop.auto {
+                let accessors = [
+                    ("getter", &prop.getter),
+                    ("setter", &prop.setter),
+                    ("reset function", &prop.reset),
+                ];
+                for (kind, accessor) in accessors.iter() {
+                    if let Some(name) = accessor {
+                        if !is_function(name) {
+                            error(format!(
+                                "{} `{}` of property `{}` is not a method of the class",
+                                kind, name, prop.name
+                            ));
+                        }
+                    }
+                }
+            }
+            if let Some(signal) = &prop.signal {
+                if !self.signals.iter().any(|s| &s.name == signal) {
+                    error(format!(
+                        "notify signal `{}` of property `{}` is not a signal of the class",
+                        signal, prop.name
+                    ));
+                }
+            }
+        }
+
+        let mut seen = HashSet::new();
+        for enum_ in &self.enums {
+            if !seen.insert(enum_.type_name()) {
+                error(format!(
+                    "enum `{}` is defined more than once",
+                    enum_.type_name()
+                ));
+            }
+            for (name, value) in enum_.try_resolved_values() {
+                if value.is_none() {
+                    error(format!(
+                        "value of enumerator `{}` of enum `{}` does not fit into 32 bits",
+                        name,
+                        enum_.type_name()
+                    ));
+                }
+            }
+        }
+
+        for meth in self.methods.iter().chain(self.slots.iter()) {
+            if let Some(rtype) = &meth.rtype {
+                if rtype.is_borrowed_conversion() {
+                    error(format!(
+                        "method `{}` can not return `{}`, which is only supported for arguments",
+                        meth.name,
+                        rtype.rust_api_type()
+                    ));
+                }
+            }
+        }
+
+        if self.panic_policy != PanicPolicy::Abort {
+            let rust_methods = self
+                .methods
+                .iter()
+                .chain(self.slots.iter())
+                .filter(|meth| meth.proxy_class.is_none());
+            for meth in rust_methods {
+                if let Some(rtype) = &meth.rtype {
+                    if rtype.rust_type().starts_with('&') {
+                        error(format!(
+                            "method `{}` returns reference `{}`, which has no default value \
+                             for the panic policy",
+                            meth.name,
+                            rtype.rust_type()
+                        ));
+                    }
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Set handling of panics in the Rust implementation.
+    pub fn panic_policy(&mut self, policy: PanicPolicy) -> &mut Self {
+        self.panic_policy = policy;
+        self
+    }
+
+    /// Type of the factory function for QML singletons.
+    pub(crate) fn singleton_factory_type(&self) -> TypeRef {
+        TypeRef::new(
+            format!("Qffi_{}_SingletonFactory", self.name),
+            format!(
+                "extern \"C\" fn(*mut std::ffi::c_void) -> *mut {}",
+                self.name
+            ),
+            true,
+            None,
+        )
+    }
 }
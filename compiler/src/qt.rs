@@ -0,0 +1,362 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::{env, io};
+
+use crate::moc::MocConfig;
+use crate::CcBuild;
+
+/// Qt installation used to compile and link generated classes.
+///
+/// Only the selected modules are added to the include paths and link libraries. Modules are
+/// named without prefix, for example `Core`, `Gui`, `Qml` or `Quick`.
+#[derive(Clone, Debug)]
+pub struct QtInstallation {
+    version: Option<String>,
+    moc: PathBuf,
+    include_paths: Vec<PathBuf>,
+    link_paths: Vec<PathBuf>,
+    libs: Vec<String>,
+    framework_paths: Vec<PathBuf>,
+    frameworks: Vec<String>,
+}
+
+/// Environment variables, which influence the installation found by `QtInstallation::find`.
+const FIND_ENV_VARS: &[&str] = &[
+    "QMAKE",
+    "QT_DIR",
+    "PKG_CONFIG",
+    "PKG_CONFIG_PATH",
+    "PKG_CONFIG_LIBDIR",
+    "PKG_CONFIG_SYSROOT_DIR",
+];
+
+fn not_found(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::NotFound, message)
+}
+
+fn push_unique<T: PartialEq>(vec: &mut Vec<T>, value: T) {
+    if !vec.contains(&value) {
+        vec.push(value);
+    }
+}
+
+/// Parse output of `qmake -query`.
+fn parse_qmake_query(output: &str) -> HashMap<String, String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(2, ':');
+            let key = parts.next()?.trim();
+            let value = parts.next()?.trim();
+            Some((key.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+impl QtInstallation {
+    /// Find Qt installation with modules.
+    ///
+    /// The installation is searched in this order:
+    ///
+    /// 1. qmake executable in the `QMAKE` environment variable
+    /// 2. installation prefix in the `QT_DIR` environment variable
+    /// 3. pkg-config
+    /// 4. `qmake` in `PATH`
+    ///
+    /// Cargo is told to rerun the build script, when one of the environment variables changes.
+    /// `PATH` is only watched, when `qmake` is searched in it.
+    pub fn find(modules: &[&str]) -> io::Result<Self> {
+        for var in FIND_ENV_VARS {
+            println!("cargo:rerun-if-env-changed={}", var);
+        }
+
+        if let Some(qmake) = env::var_os("QMAKE") {
+            return Self::from_qmake(Path::new(&qmake), modules);
+        }
+        if let Some(dir) = env::var_os("QT_DIR") {
+            return Self::from_dir(Path::new(&dir), modules);
+        }
+        Self::from_pkg_config(modules).or_else(|_| {
+            println!("cargo:rerun-if-env-changed=PATH");
+            Self::from_qmake(Path::new("qmake"), modules)
+        })
+    }
+
+    /// Use the installation reported by `qmake -query`.
+    pub fn from_qmake(qmake: &Path, modules: &[&str]) -> io::Result<Self> {
+        let output = Command::new(qmake).arg("-query").output()?;
+        if !output.status.success() {
+            return Err(not_found(format!(
+                "{} -query failed: {}",
+                qmake.display(),
+                String::from_utf8_lossy(&output.stderr)
+            )));
+        }
+        let query = parse_qmake_query(&String::from_utf8_lossy(&output.stdout));
+        Self::from_query(&query, modules)
+    }
+
+    /// Use the installation with prefix `dir`.
+    ///
+    /// Headers are expected in `include`, libraries in `lib` and executables in `bin`.
+    pub fn from_dir(dir: &Path, modules: &[&str]) -> io::Result<Self> {
+        let mut query = HashMap::new();
+        query.insert(
+            "QT_INSTALL_HEADERS".to_string(),
+            dir.join("include").to_string_lossy().into_owned(),
+        );
+        query.insert(
+            "QT_INSTALL_LIBS".to_string(),
+            dir.join("lib").to_string_lossy().into_owned(),
+        );
+        query.insert(
+            "QT_INSTALL_BINS".to_string(),
+            dir.join("bin").to_string_lossy().into_owned(),
+        );
+        Self::from_query(&query, modules)
+    }
+
+    /// Use the installation found by pkg-config.
+    pub fn from_pkg_config(modules: &[&str]) -> io::Result<Self> {
+        let mut result = Self {
+            version: None,
+            moc: "moc".into(),
+            include_paths: vec![],
+            link_paths: vec![],
+            libs: vec![],
+            framework_paths: vec![],
+            frameworks: vec![],
+        };
+
+        for module in modules {
+            let lib = pkg_config::Config::new()
+                .cargo_metadata(false)
+                .probe(&format!("Qt5{}", module))
+                .map_err(|err| not_found(err.to_string()))?;
+            if result.version.is_none() {
+                result.version = Some(lib.version.clone());
+            }
+            lib.include_paths
+                .into_iter()
+                .for_each(|path| push_unique(&mut result.include_paths, path));
+            lib.link_paths
+                .into_iter()
+                .for_each(|path| push_unique(&mut result.link_paths, path));
+            lib.libs
+                .into_iter()
+                .for_each(|lib| push_unique(&mut result.libs, lib));
+            lib.framework_paths
+                .into_iter()
+                .for_each(|path| push_unique(&mut result.framework_paths, path));
+            lib.frameworks
+                .into_iter()
+                .for_each(|framework| push_unique(&mut result.frameworks, framework));
+        }
+
+        if let Ok(bins) = pkg_config::get_variable("Qt5Core", "host_bins") {
+            result.moc = Path::new(&bins).join(format!("moc{}", env::consts::EXE_SUFFIX));
+        }
+
+        Ok(result)
+    }
+
+    fn from_query(query: &HashMap<String, String>, modules: &[&str]) -> io::Result<Self> {
+        let get = |key: &str| {
+            query
+                .get(key)
+                .filter(|value| !value.is_empty())
+                .map(PathBuf::from)
+                .ok_or_else(|| not_found(format!("{} is not known", key)))
+        };
+        let headers = get("QT_INSTALL_HEADERS")?;
+        let libs = get("QT_INSTALL_LIBS")?;
+        let bins = get("QT_HOST_BINS").or_else(|_| get("QT_INSTALL_BINS"))?;
+        let version = query.get("QT_VERSION").cloned();
+        let major = version
+            .as_ref()
+            .and_then(|version| version.split('.').next())
+            .unwrap_or("5")
+            .to_string();
+
+        let mut result = Self {
+            version,
+            moc: bins.join(format!("moc{}", env::consts::EXE_SUFFIX)),
+            include_paths: vec![headers.clone()],
+            link_paths: vec![],
+            libs: vec![],
+            framework_paths: vec![],
+            frameworks: vec![],
+        };
+        for module in modules {
+            let framework = libs.join(format!("Qt{}.framework", module));
+            if framework.exists() {
+                push_unique(&mut result.framework_paths, libs.clone());
+                result.frameworks.push(format!("Qt{}", module));
+                result.include_paths.push(framework.join("Headers"));
+            } else {
+                push_unique(&mut result.link_paths, libs.clone());
+                result.libs.push(format!("Qt{}{}", major, module));
+                result
+                    .include_paths
+                    .push(headers.join(format!("Qt{}", module)));
+            }
+        }
+        Ok(result)
+    }
+
+    /// Qt version if known.
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    /// Path to moc executable.
+    pub fn moc(&self) -> &Path {
+        &self.moc
+    }
+
+    pub fn include_paths(&self) -> &[PathBuf] {
+        &self.include_paths
+    }
+
+    /// C++ build with include paths of the selected modules.
+    pub fn cc_build(&self) -> CcBuild {
+        let mut build = CcBuild::new();
+        build.cpp(true);
+        for include in &self.include_paths {
+            build.include(include);
+        }
+        for path in &self.framework_paths {
+            build.flag(format!("-F{}", path.display()).as_str());
+        }
+        build
+    }
+
+    /// Moc configuration with moc executable and include paths of the selected modules.
+    pub fn moc_config(&self) -> MocConfig {
+        let mut moc = MocConfig::new();
+        moc.moc(&self.moc);
+        for include in &self.include_paths {
+            moc.include_path(include);
+        }
+        for path in &self.framework_paths {
+            moc.framework(&path.to_string_lossy());
+        }
+        moc
+    }
+
+    /// Cargo instructions to link the libraries of the selected modules.
+    pub fn link_lines(&self) -> Vec<String> {
+        let mut result = vec![];
+        for path in &self.link_paths {
+            result.push(format!("cargo:rustc-link-search=native={}", path.display()));
+        }
+        for path in &self.framework_paths {
+            result.push(format!(
+                "cargo:rustc-link-search=framework={}",
+                path.display()
+            ));
+        }
+        for lib in &self.libs {
+            result.push(format!("cargo:rustc-link-lib={}", lib));
+        }
+        for framework in &self.frameworks {
+            result.push(format!("cargo:rustc-link-lib=framework={}", framework));
+        }
+        result
+    }
+
+    /// Print cargo instructions to link the libraries of the selected modules.
+    pub fn link(&self) {
+        for line in self.link_lines() {
+            println!("{}", line);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const QUERY: &str = "QT_SYSROOT:\n\
+                         QT_INSTALL_PREFIX:/usr\n\
+                         QT_INSTALL_HEADERS:/usr/include/qt5\n\
+                         QT_INSTALL_LIBS:/usr/lib/qt5\n\
+                         QT_INSTALL_BINS:/usr/lib/qt5/bin\n\
+                         QT_HOST_BINS:/usr/lib/qt5/host-bin\n\
+                         QT_VERSION:5.15.2\n";
+
+    #[test]
+    fn test_parse_qmake_query() {
+        let query = parse_qmake_query(QUERY);
+        assert_eq!("/usr/include/qt5", query["QT_INSTALL_HEADERS"]);
+        assert_eq!("", query["QT_SYSROOT"]);
+        assert_eq!("5.15.2", query["QT_VERSION"]);
+    }
+
+    #[test]
+    fn test_from_query() {
+        let qt = QtInstallation::from_query(&parse_qmake_query(QUERY), &["Core", "Qml"]).unwrap();
+
+        assert_eq!(Some("5.15.2"), qt.version());
+        assert_eq!(
+            Path::new(&format!(
+                "/usr/lib/qt5/host-bin/moc{}",
+                env::consts::EXE_SUFFIX
+            )),
+            qt.moc()
+        );
+        assert_eq!(
+            &[
+                PathBuf::from("/usr/include/qt5"),
+                PathBuf::from("/usr/include/qt5/QtCore"),
+                PathBuf::from("/usr/include/qt5/QtQml"),
+            ],
+            qt.include_paths()
+        );
+        assert_eq!(
+            vec![
+                "cargo:rustc-link-search=native=/usr/lib/qt5",
+                "cargo:rustc-link-lib=Qt5Core",
+                "cargo:rustc-link-lib=Qt5Qml",
+            ],
+            qt.link_lines()
+        );
+    }
+
+    #[test]
+    fn test_from_query_missing_headers() {
+        let query = parse_qmake_query("QT_INSTALL_LIBS:/usr/lib\nQT_INSTALL_BINS:/usr/bin\n");
+        assert!(QtInstallation::from_query(&query, &["Core"]).is_err());
+    }
+
+    #[test]
+    fn test_from_dir() {
+        let qt = QtInstallation::from_dir(Path::new("/opt/qt"), &["Gui"]).unwrap();
+
+        assert_eq!(None, qt.version());
+        assert_eq!(
+            Path::new(&format!("/opt/qt/bin/moc{}", env::consts::EXE_SUFFIX)),
+            qt.moc()
+        );
+        assert!(qt
+            .include_paths()
+            .contains(&PathBuf::from("/opt/qt/include/QtGui")));
+        assert!(qt
+            .link_lines()
+            .contains(&"cargo:rustc-link-lib=Qt5Gui".to_string()));
+    }
+
+    #[test]
+    fn test_moc_config() {
+        let qt = QtInstallation::from_dir(Path::new("/opt/qt"), &["Core"]).unwrap();
+        let command = qt
+            .moc_config()
+            .command(Path::new("in.cpp"), Path::new("out.moc"));
+
+        assert_eq!(qt.moc(), command.get_program());
+        assert!(command
+            .get_args()
+            .any(|arg| arg == Path::new("/opt/qt/include/QtCore")));
+    }
+}
@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 use std::ffi::CStr;
+use std::marker::PhantomData;
 
 #[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
 pub enum Include {
@@ -13,6 +14,20 @@ pub struct TypeRef {
     rust: Cow<'static, str>,
     return_safe: bool,
     include: Option<Include>,
+    /// Template arguments like the element type of containers.
+    args: Vec<TypeRef>,
+    conversion: Option<RustConversion>,
+}
+
+/// Conversion between the Rust type of generated APIs and the Rust type of the FFI.
+///
+/// The expressions contain the placeholder `$value` for the value to convert.
+#[derive(Clone, Debug)]
+struct RustConversion {
+    rust: Cow<'static, str>,
+    into_ffi: Cow<'static, str>,
+    from_ffi: Cow<'static, str>,
+    from_ref: bool,
 }
 
 impl TypeRef {
@@ -27,6 +42,8 @@ impl TypeRef {
             rust: rust_name.into(),
             return_safe,
             include,
+            args: vec![],
+            conversion: None,
         }
     }
 
@@ -36,6 +53,8 @@ impl TypeRef {
             include: None,
             rust: "*mut std::ffi::c_void".into(),
             return_safe: true,
+            args: vec![],
+            conversion: None,
         }
     }
 
@@ -45,6 +64,8 @@ impl TypeRef {
             rust: "qt5qml::core::QObject".into(),
             include: Some(Include::System("QObject".into())),
             return_safe: false,
+            args: vec![],
+            conversion: None,
         }
     }
 
@@ -55,6 +76,20 @@ impl TypeRef {
             rust: name,
             include: None,
             return_safe: false,
+            args: vec![],
+            conversion: None,
+        }
+    }
+
+    /// Enum or flags type declared in the generated class `class_name`.
+    pub fn generated_enum(class_name: &str, name: &str) -> Self {
+        Self {
+            cpp: format!("{}::{}", class_name, name).into(),
+            rust: format!("{}{}", class_name, name).into(),
+            include: None,
+            return_safe: true,
+            args: vec![],
+            conversion: None,
         }
     }
 
@@ -64,6 +99,8 @@ impl TypeRef {
             rust: "*mut qt5qml::core::QObject".into(),
             include: Some(Include::System("QObject".into())),
             return_safe: true,
+            args: vec![],
+            conversion: None,
         }
     }
 
@@ -74,9 +111,56 @@ impl TypeRef {
             include: Some(Include::System(class_name.to_string())),
             cpp: class_name,
             return_safe: false,
+            args: vec![],
+            conversion: None,
+        }
+    }
+
+    fn qt_container(name: &'static str, args: Vec<TypeRef>) -> Self {
+        // use the names of the metatypes Qt registers for containers
+        let cpp_args: Vec<&str> = args
+            .iter()
+            .map(|arg| match arg.cpp_type() {
+                "qint32" => "int",
+                "quint32" => "uint",
+                "qint64" => "qlonglong",
+                "quint64" => "qulonglong",
+                name => name,
+            })
+            .collect();
+        let rust_args: Vec<&str> = args.iter().map(|arg| arg.rust_type()).collect();
+        Self {
+            cpp: format!("{}<{}>", name, cpp_args.join(", ")).into(),
+            rust: format!("qt5qml::core::{}<{}>", name, rust_args.join(", ")).into(),
+            include: Some(Include::System(name.into())),
+            return_safe: false,
+            args,
+            conversion: None,
         }
     }
 
+    /// `QList<T>` with element type `item`.
+    ///
+    /// The supported element types are listed in `bindgen.yml` of qt5qml.
+    pub fn qlist(item: TypeRef) -> Self {
+        Self::qt_container("QList", vec![item])
+    }
+
+    /// `QVector<T>` with element type `item`.
+    pub fn qvector(item: TypeRef) -> Self {
+        Self::qt_container("QVector", vec![item])
+    }
+
+    /// `QMap<K, V>` with key type `key` and value type `value`.
+    pub fn qmap(key: TypeRef, value: TypeRef) -> Self {
+        Self::qt_container("QMap", vec![key, value])
+    }
+
+    /// `QHash<K, V>` with key type `key` and value type `value`.
+    pub fn qhash(key: TypeRef, value: TypeRef) -> Self {
+        Self::qt_container("QHash", vec![key, value])
+    }
+
     pub fn from_type<T: TypeRefTrait>() -> Self {
         T::type_ref()
     }
@@ -87,6 +171,8 @@ impl TypeRef {
             include: self.include,
             rust: format!("*mut {}", self.rust).into(),
             return_safe: true,
+            args: self.args,
+            conversion: None,
         }
     }
 
@@ -96,6 +182,8 @@ impl TypeRef {
             include: self.include,
             rust: format!("*const {}", self.rust).into(),
             return_safe: true,
+            args: self.args,
+            conversion: None,
         }
     }
 
@@ -105,6 +193,8 @@ impl TypeRef {
             include: self.include,
             rust: format!("&mut {}", self.rust).into(),
             return_safe: true,
+            args: self.args,
+            conversion: None,
         }
     }
 
@@ -114,7 +204,46 @@ impl TypeRef {
             include: self.include,
             rust: format!("&{}", self.rust).into(),
             return_safe: true,
+            args: self.args,
+            conversion: None,
+        }
+    }
+
+    /// Use `rust` as type in the generated Rust APIs instead of the FFI type.
+    ///
+    /// `into_ffi` converts a value `$value` of type `rust` into an owned value of the FFI type and
+    /// `from_ffi` converts an owned value `$value` of the FFI type back. Types derived with the
+    /// `with_*` methods have no conversion.
+    pub fn with_rust_conversion(
+        self,
+        rust: impl Into<Cow<'static, str>>,
+        into_ffi: impl Into<Cow<'static, str>>,
+        from_ffi: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        Self {
+            conversion: Some(RustConversion {
+                rust: rust.into(),
+                into_ffi: into_ffi.into(),
+                from_ffi: from_ffi.into(),
+                from_ref: false,
+            }),
+            ..self
+        }
+    }
+
+    /// Like `with_rust_conversion`, but `from_ffi` only needs a reference `$value` to the value
+    /// of the FFI type.
+    pub fn with_rust_ref_conversion(
+        self,
+        rust: impl Into<Cow<'static, str>>,
+        into_ffi: impl Into<Cow<'static, str>>,
+        from_ffi: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        let mut result = self.with_rust_conversion(rust, into_ffi, from_ffi);
+        if let Some(conversion) = &mut result.conversion {
+            conversion.from_ref = true;
         }
+        result
     }
 
     pub fn qstring() -> Self {
@@ -129,13 +258,86 @@ impl TypeRef {
         &self.rust
     }
 
+    /// Rust type of a value of this type.
+    ///
+    /// References are replaced with the referenced type.
+    pub fn rust_value_type(&self) -> &str {
+        self.rust
+            .strip_prefix("&mut ")
+            .or_else(|| self.rust.strip_prefix('&'))
+            .unwrap_or(&self.rust)
+    }
+
+    /// Rust type used in generated Rust APIs like the `Impl` trait, signals and proxy methods.
+    pub fn rust_api_type(&self) -> &str {
+        match &self.conversion {
+            Some(conversion) => &conversion.rust,
+            None => &self.rust,
+        }
+    }
+
+    /// Whether the Rust API type borrows the value converted from the FFI type, like `&str`.
+    ///
+    /// The converted value is a temporary, so these types are only supported for arguments.
+    pub fn is_borrowed_conversion(&self) -> bool {
+        self.conversion
+            .as_ref()
+            .is_some_and(|conversion| conversion.rust.starts_with('&'))
+    }
+
+    /// Whether the conversion from the FFI type only needs a reference to the value.
+    pub fn converts_from_ref(&self) -> bool {
+        self.conversion
+            .as_ref()
+            .is_some_and(|conversion| conversion.from_ref)
+    }
+
+    /// Rust expression converting `value` of the Rust API type into the FFI type.
+    pub fn rust_into_ffi(&self, value: &str) -> String {
+        match &self.conversion {
+            Some(conversion) => conversion.into_ffi.replace("$value", value),
+            None => value.to_string(),
+        }
+    }
+
+    /// Rust expression converting the owned `value` of the FFI type into the Rust API type.
+    ///
+    /// `value` may be a reference, when `converts_from_ref` is true.
+    pub fn rust_from_ffi(&self, value: &str) -> String {
+        match &self.conversion {
+            Some(conversion) => conversion.from_ffi.replace("$value", value),
+            None => value.to_string(),
+        }
+    }
+
     pub fn include(&self) -> &Option<Include> {
         &self.include
     }
 
+    /// Includes required for this type and its template arguments.
+    pub fn includes(&self) -> Vec<Include> {
+        let mut result: Vec<Include> = self.include.iter().cloned().collect();
+        for arg in &self.args {
+            result.extend(arg.includes());
+        }
+        result
+    }
+
     pub fn return_safe(&self) -> bool {
         self.return_safe
     }
+
+    /// Type used to pass an argument of this type through the FFI.
+    ///
+    /// Values, which are not return safe, are passed by const reference, because they are not
+    /// trivially copyable.
+    pub fn ffi_arg_type(&self) -> TypeRef {
+        if self.return_safe {
+            self.clone()
+        } else {
+            self.clone().with_const_ref()
+        }
+    }
 }
 
 pub trait TypeRefTrait {
@@ -178,6 +380,75 @@ impl TypeRefTrait for &mut CStr {
     }
 }
 
+/// `&str` is passed as `QString`. Only supported for arguments.
+impl TypeRefTrait for &str {
+    fn type_ref() -> TypeRef {
+        TypeRef::qstring().with_rust_ref_conversion(
+            "&str",
+            "qt5qml::core::QString::from($value)",
+            "&$value.to_string()",
+        )
+    }
+}
+
+/// `String` is passed as `QString`.
+impl TypeRefTrait for String {
+    fn type_ref() -> TypeRef {
+        TypeRef::qstring().with_rust_ref_conversion(
+            "String",
+            "qt5qml::core::QString::from($value)",
+            "$value.to_string()",
+        )
+    }
+}
+
+/// `Option<QString>` is passed as `QString`, which is null for `None`.
+impl TypeRefTrait for Option<QString> {
+    fn type_ref() -> TypeRef {
+        TypeRef::qstring().with_rust_conversion(
+            "Option<qt5qml::core::QString>",
+            "$value.unwrap_or_default()",
+            "Some($value).filter(|value| !value.is_null())",
+        )
+    }
+}
+
+/// `Vec<T>` is passed as `QList<T>`. The items are converted, when `T` has a Rust conversion
+/// like `String`. Borrowed item types like `&str` are not supported.
+impl<T: TypeRefTrait> TypeRefTrait for Vec<T> {
+    fn type_ref() -> TypeRef {
+        let item = T::type_ref();
+        assert!(
+            !item.is_borrowed_conversion(),
+            "`Vec<{}>` is not supported",
+            item.rust_api_type()
+        );
+
+        let rust = format!("Vec<{}>", item.rust_api_type());
+        if item.conversion.is_none() {
+            return TypeRef::qlist(item).with_rust_ref_conversion(
+                rust,
+                "qt5qml::core::QList::from($value)",
+                "$value.to_vec()",
+            );
+        }
+
+        let into_ffi = format!(
+            "$value.into_iter().map(|item| {}).collect::<qt5qml::core::QList<_>>()",
+            item.rust_into_ffi("item")
+        );
+        let from_ffi = format!(
+            "$value.iter().map(|item| {}).collect::<Vec<_>>()",
+            if item.converts_from_ref() {
+                item.rust_from_ffi("item")
+            } else {
+                item.rust_from_ffi("item.clone()")
+            }
+        );
+        TypeRef::qlist(item).with_rust_ref_conversion(rust, into_ffi, from_ffi)
+    }
+}
+
 macro_rules! impl_type_ref_trait {
     ($rust:ty => $cpp:expr, $return_safe:expr, $include:expr) => {
         impl TypeRefTrait for $rust {
@@ -218,6 +489,36 @@ pub struct QString;
 pub struct QByteArray;
 pub struct QVariant;
 pub struct QHashIntQByteArray;
+pub struct Orientation;
+pub struct ItemFlags;
+pub struct QList<T>(PhantomData<T>);
+pub struct QVector<T>(PhantomData<T>);
+pub struct QMap<K, V>(PhantomData<(K, V)>);
+pub struct QHash<K, V>(PhantomData<(K, V)>);
+
+impl<T: TypeRefTrait> TypeRefTrait for QList<T> {
+    fn type_ref() -> TypeRef {
+        TypeRef::qlist(T::type_ref())
+    }
+}
+
+impl<T: TypeRefTrait> TypeRefTrait for QVector<T> {
+    fn type_ref() -> TypeRef {
+        TypeRef::qvector(T::type_ref())
+    }
+}
+
+impl<K: TypeRefTrait, V: TypeRefTrait> TypeRefTrait for QMap<K, V> {
+    fn type_ref() -> TypeRef {
+        TypeRef::qmap(K::type_ref(), V::type_ref())
+    }
+}
+
+impl<K: TypeRefTrait, V: TypeRefTrait> TypeRefTrait for QHash<K, V> {
+    fn type_ref() -> TypeRef {
+        TypeRef::qhash(K::type_ref(), V::type_ref())
+    }
+}
 
 impl_type_ref_trait!(i8 => "qint8", true, "QtGlobal");
 impl_type_ref_trait!(u8 => "quint8", true, "QtGlobal");
@@ -237,3 +538,159 @@ impl_type_ref_trait!(qt5qml::core::QString : QString => "QString", false, "QStri
 impl_type_ref_trait!(qt5qml::core::QByteArray : QByteArray => "QByteArray", false, "QByteArray");
 impl_type_ref_trait!(qt5qml::core::QVariant : QVariant => "QVariant", false, "QVariant");
 impl_type_ref_trait!(qt5qml::core::QHashIntQByteArray : QHashIntQByteArray => "QHash<int, QByteArray>", false, "QHash");
+impl_type_ref_trait!(qt5qml::core::Orientation : Orientation => "Qt::Orientation", true, "qnamespace.h");
+// QFlags is a class, so it is not passed by value although it has the layout of an int
+impl_type_ref_trait!(qt5qml::core::ItemFlags : ItemFlags => "Qt::ItemFlags", false, "qnamespace.h");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qlist() {
+        let type_ref = TypeRef::from_type::<QList<i32>>();
+        assert_eq!("QList<int>", type_ref.cpp_type());
+        assert_eq!("qt5qml::core::QList<i32>", type_ref.rust_type());
+        assert!(!type_ref.return_safe());
+    }
+
+    #[test]
+    fn test_qvector() {
+        let type_ref = TypeRef::qvector(TypeRef::qstring());
+        assert_eq!("QVector<QString>", type_ref.cpp_type());
+        assert_eq!(
+            "qt5qml::core::QVector<qt5qml::core::QString>",
+            type_ref.rust_type()
+        );
+    }
+
+    #[test]
+    fn test_qmap() {
+        let type_ref = TypeRef::from_type::<QMap<QString, QVariant>>();
+        assert_eq!("QMap<QString, QVariant>", type_ref.cpp_type());
+        assert_eq!(
+            "qt5qml::core::QMap<qt5qml::core::QString, qt5qml::core::QVariant>",
+            type_ref.rust_type()
+        );
+    }
+
+    #[test]
+    fn test_qhash() {
+        let type_ref = TypeRef::from_type::<QHash<i64, f64>>();
+        assert_eq!("QHash<qlonglong, double>", type_ref.cpp_type());
+        assert_eq!("qt5qml::core::QHash<i64, f64>", type_ref.rust_type());
+    }
+
+    #[test]
+    fn test_container_includes() {
+        let type_ref = TypeRef::from_type::<&QHash<QString, QList<QVariant>>>();
+        assert_eq!(
+            vec![
+                Include::System("QHash".into()),
+                Include::System("QString".into()),
+                Include::System("QList".into()),
+                Include::System("QVariant".into()),
+            ],
+            type_ref.includes()
+        );
+        assert_eq!(
+            "const QHash<QString, QList<QVariant>>&",
+            type_ref.cpp_type()
+        );
+    }
+
+    #[test]
+    fn test_string() {
+        let type_ref = TypeRef::from_type::<String>();
+        assert_eq!("QString", type_ref.cpp_type());
+        assert_eq!("qt5qml::core::QString", type_ref.rust_type());
+        assert_eq!("String", type_ref.rust_api_type());
+        assert_eq!(
+            "qt5qml::core::QString::from(x)",
+            type_ref.rust_into_ffi("x")
+        );
+        assert_eq!("x.to_string()", type_ref.rust_from_ffi("x"));
+        assert!(!type_ref.is_borrowed_conversion());
+    }
+
+    #[test]
+    fn test_str() {
+        let type_ref = TypeRef::from_type::<&str>();
+        assert_eq!("QString", type_ref.cpp_type());
+        assert_eq!("&str", type_ref.rust_api_type());
+        assert_eq!("&x.to_string()", type_ref.rust_from_ffi("x"));
+        assert!(type_ref.is_borrowed_conversion());
+    }
+
+    #[test]
+    fn test_option_qstring() {
+        let type_ref = TypeRef::from_type::<Option<QString>>();
+        assert_eq!("QString", type_ref.cpp_type());
+        assert_eq!("Option<qt5qml::core::QString>", type_ref.rust_api_type());
+        assert_eq!("x.unwrap_or_default()", type_ref.rust_into_ffi("x"));
+    }
+
+    #[test]
+    fn test_vec() {
+        let type_ref = TypeRef::from_type::<Vec<i32>>();
+        assert_eq!("QList<int>", type_ref.cpp_type());
+        assert_eq!("qt5qml::core::QList<i32>", type_ref.rust_type());
+        assert_eq!("Vec<i32>", type_ref.rust_api_type());
+        assert_eq!("x.to_vec()", type_ref.rust_from_ffi("x"));
+        assert!(type_ref.converts_from_ref());
+    }
+
+    #[test]
+    fn test_vec_string() {
+        let type_ref = TypeRef::from_type::<Vec<String>>();
+        assert_eq!("QList<QString>", type_ref.cpp_type());
+        assert_eq!(
+            "qt5qml::core::QList<qt5qml::core::QString>",
+            type_ref.rust_type()
+        );
+        assert_eq!("Vec<String>", type_ref.rust_api_type());
+        assert_eq!(
+            "x.into_iter().map(|item| qt5qml::core::QString::from(item))\
+             .collect::<qt5qml::core::QList<_>>()",
+            type_ref.rust_into_ffi("x")
+        );
+        assert_eq!(
+            "x.iter().map(|item| item.to_string()).collect::<Vec<_>>()",
+            type_ref.rust_from_ffi("x")
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vec_str() {
+        TypeRef::from_type::<Vec<&str>>();
+    }
+
+    #[test]
+    fn test_no_conversion() {
+        let type_ref = TypeRef::from_type::<QString>();
+        assert_eq!("qt5qml::core::QString", type_ref.rust_api_type());
+        assert_eq!("x", type_ref.rust_into_ffi("x"));
+        assert_eq!("x", type_ref.rust_from_ffi("x"));
+
+        let type_ref = TypeRef::from_type::<String>().with_const_ref();
+        assert_eq!("&qt5qml::core::QString", type_ref.rust_api_type());
+    }
+
+    #[test]
+    fn test_qt_namespace_types() {
+        let type_ref = TypeRef::from_type::<Orientation>();
+        assert_eq!("Qt::Orientation", type_ref.cpp_type());
+        assert_eq!("qt5qml::core::Orientation", type_ref.rust_type());
+        assert!(type_ref.return_safe());
+
+        let type_ref = TypeRef::from_type::<ItemFlags>();
+        assert_eq!("Qt::ItemFlags", type_ref.cpp_type());
+        assert_eq!("qt5qml::core::ItemFlags", type_ref.rust_type());
+        assert!(!type_ref.return_safe());
+        assert_eq!(
+            vec![Include::System("qnamespace.h".into())],
+            type_ref.includes()
+        );
+    }
+}
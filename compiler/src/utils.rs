@@ -80,6 +80,25 @@ pub fn to_snake_case(input: &str) -> String {
     parser.finish()
 }
 
+pub fn upper_first(input: &str) -> String {
+    let mut chars = input.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Whether `input` is a valid C++ identifier.
+pub fn is_identifier(input: &str) -> bool {
+    let mut chars = input.chars();
+    match chars.next() {
+        Some(first) if first == '_' || first.is_ascii_alphabetic() => {
+            chars.all(|c| c == '_' || c.is_ascii_alphanumeric())
+        }
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -138,4 +157,24 @@ mod tests {
     fn test_to_snake_case_mixed_case_1() {
         assert_eq!(to_snake_case("QOBJECT_Ref"), "qobject_ref");
     }
+
+    #[test]
+    fn test_upper_first() {
+        assert_eq!(upper_first("rowCount"), "RowCount");
+    }
+
+    #[test]
+    fn test_upper_first_empty() {
+        assert_eq!(upper_first(""), "");
+    }
+
+    #[test]
+    fn test_is_identifier() {
+        assert!(is_identifier("rowCount"));
+        assert!(is_identifier("_row_count2"));
+        assert!(!is_identifier(""));
+        assert!(!is_identifier("2rows"));
+        assert!(!is_identifier("row-count"));
+        assert!(!is_identifier("Foo::Bar"));
+    }
 }
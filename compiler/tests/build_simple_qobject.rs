@@ -1,7 +1,6 @@
 use qobject_compiler::build::build;
-use qobject_compiler::moc::MocConfig;
 use qobject_compiler::qobject::QObjectConfig;
-use qobject_compiler::CcBuild;
+use qobject_compiler::QtInstallation;
 use std::fs;
 use std::path::PathBuf;
 
@@ -11,19 +10,14 @@ fn main() {
     let dir = temp_file.path();
     let path: PathBuf = "input.cpp".into();
 
-    let config = pkg_config::probe_library("Qt5Core").unwrap();
+    let qt = QtInstallation::find(&["Core"]).unwrap();
 
-    let mut moc = MocConfig::new();
-    let mut cpp = CcBuild::new();
-    cpp.cpp(true);
+    let moc = qt.moc_config();
+    let mut cpp = qt.cc_build();
     cpp.out_dir(&dir);
     cpp.host("x86_64-linux-gnu");
     cpp.target("x86_64-linux-gnu");
     cpp.opt_level(0);
-    for include in &config.include_paths {
-        cpp.include(include);
-        moc.include_path(include);
-    }
 
     let mut obj = QObjectConfig::new("MyQObject");
     obj.qml(false);
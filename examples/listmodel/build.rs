@@ -1,22 +1,12 @@
-use qobject_compiler::moc::MocConfig;
 use qobject_compiler::typeref::TypeRef;
 use qobject_compiler::typeref::{QHashIntQByteArray, QModelIndex, QVariant};
-use qobject_compiler::{CcBuild, QObjectBuild, QObjectMethod};
+use qobject_compiler::{QObjectBuild, QObjectMethod, QtInstallation};
 
 fn main() {
-    let core = pkg_config::probe_library("Qt5Core").unwrap();
-    let qml = pkg_config::probe_library("Qt5Qml").unwrap();
-
-    let mut moc = MocConfig::new();
-    let mut cpp = CcBuild::new();
-    for include in &core.include_paths {
-        cpp.include(include);
-        moc.include_path(include);
-    }
-    for include in &qml.include_paths {
-        cpp.include(include);
-        moc.include_path(include);
-    }
+    let qt = QtInstallation::find(&["Core", "Gui", "Qml"]).unwrap();
+    qt.link();
+    let moc = qt.moc_config();
+    let cpp = qt.cc_build();
 
     QObjectBuild::new("TestObject")
         .inherit(TypeRef::qt_core_object("QAbstractListModel"))
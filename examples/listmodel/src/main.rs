@@ -8,17 +8,17 @@ use std::process::exit;
 include!(concat!(env!("OUT_DIR"), "/qffi_TestObject.rs"));
 
 pub struct TestObjectPrivate {
-    _qobject: *mut TestObject,
+    qobject: *mut TestObject,
     items: Vec<(String, String)>,
 }
 
 const NAME_ROLE: i32 = QT_USER_ROLE;
 const DESCRIPTION_ROLE: i32 = NAME_ROLE + 1;
 
-impl TestObjectPrivate {
-    pub fn new(qobject: *mut TestObject) -> Self {
+impl TestObjectImpl for TestObjectPrivate {
+    fn new(qobject: *mut TestObject) -> Self {
         Self {
-            _qobject: qobject,
+            qobject,
             items: vec![
                 ("Item 1".into(), "a description".into()),
                 ("Item 2".into(), "a second description".into()),
@@ -26,7 +26,7 @@ impl TestObjectPrivate {
         }
     }
 
-    pub fn row_count(&self, parent: &QModelIndex) -> i32 {
+    fn row_count(&self, parent: &QModelIndex) -> i32 {
         if parent.is_valid() {
             0
         } else {
@@ -34,7 +34,7 @@ impl TestObjectPrivate {
         }
     }
 
-    pub fn data(&self, index: &QModelIndex, role: i32) -> QVariant {
+    fn data(&self, index: &QModelIndex, role: i32) -> QVariant {
         if !index.is_valid() {
             return QVariant::new();
         }
@@ -50,15 +50,21 @@ impl TestObjectPrivate {
         }
     }
 
-    pub fn role_names(&self) -> QHashIntQByteArray {
+    fn role_names(&self) -> QHashIntQByteArray {
         let mut result = QHashIntQByteArray::new();
         result.insert(&NAME_ROLE, &"name".into());
         result.insert(&DESCRIPTION_ROLE, &"description".into());
         result
     }
+}
 
+impl TestObjectPrivate {
     pub fn add_item(&mut self, name: &str, description: &str) {
-        self.items.push((name.into(), description.into()))
+        let row = self.items.len() as i32;
+        let qobject = unsafe { &mut *self.qobject };
+        qobject.begin_insert_rows(&QModelIndex::new(), row, row);
+        self.items.push((name.into(), description.into()));
+        qobject.end_insert_rows();
     }
 }
 
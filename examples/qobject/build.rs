@@ -1,19 +1,14 @@
-use qobject_compiler::moc::MocConfig;
 use qobject_compiler::typeref::QString;
-use qobject_compiler::{CcBuild, QObjectBuild, QObjectProp};
+use qobject_compiler::{QObjectBuild, QObjectProp, QtInstallation};
 
 fn main() {
-    let config = pkg_config::probe_library("Qt5Core").unwrap();
-
-    let mut moc = MocConfig::new();
-    let mut cpp = CcBuild::new();
-    for include in &config.include_paths {
-        cpp.include(include);
-        moc.include_path(include);
-    }
+    let qt = QtInstallation::find(&["Core"]).unwrap();
+    qt.link();
+    let moc = qt.moc_config();
+    let cpp = qt.cc_build();
 
     QObjectBuild::new("MyQObject")
-        .property(QObjectProp::new::<QString>("my_name"))
+        .property(QObjectProp::new::<QString>("my_name").auto())
         .qml(false)
         .build(&cpp, &moc);
 }
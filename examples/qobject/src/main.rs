@@ -8,8 +8,8 @@ pub struct MyQObjectPrivate {
     _qobject: *mut MyQObject,
 }
 
-impl MyQObjectPrivate {
-    pub fn new(qobject: *mut MyQObject) -> Self {
+impl MyQObjectImpl for MyQObjectPrivate {
+    fn new(qobject: *mut MyQObject) -> Self {
         Self { _qobject: qobject }
     }
 }
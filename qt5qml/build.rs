@@ -1,13 +1,10 @@
-fn main() {
-    let qt5core = pkg_config::probe_library("Qt5Core").unwrap();
-    let qt5gui = pkg_config::probe_library("Qt5Gui").unwrap();
-    let qt5qml = pkg_config::probe_library("Qt5Qml").unwrap();
+use qobject_compiler::QtInstallation;
 
-    cc::Build::new()
-        .cpp(true)
-        .includes(qt5core.include_paths)
-        .includes(qt5gui.include_paths)
-        .includes(qt5qml.include_paths)
+fn main() {
+    let qt = QtInstallation::find(&["Core", "Gui", "Qml"]).unwrap();
+    qt.link();
+    qt.cc_build()
+        .include("src/ffi")
         .file("src/ffi/qffi.cpp")
         .compile("qffi");
 }
@@ -1,10 +1,130 @@
 use std::ffi::c_void;
+use std::ops::{BitAnd, BitOr, BitOrAssign};
 use std::ptr;
 
-opaque_struct!(QAbstractItemModel);
+use crate::core::{QHashIntQByteArray, QObject, QObjectRef, QVariant, QVector, TypedSignal};
+use crate::ffi::{init_ffi_struct, QffiWrapper};
 
 #[repr(C)]
-#[derive(Eq, PartialEq, Ord, PartialOrd)]
+pub struct QAbstractItemModel(pub(crate) crate::ffi::QAbstractItemModel);
+impl_ffi_trait!(QAbstractItemModel);
+impl_qobject_ref!(QAbstractItemModel);
+
+impl QAbstractItemModel {
+    /// Cast `object` to an item model, if it inherits `QAbstractItemModel`.
+    pub fn cast(object: &QObject) -> Option<&QAbstractItemModel> {
+        if object.inherits(cstr!("QAbstractItemModel")) {
+            Some(unsafe { &*(object as *const QObject as *const QAbstractItemModel) })
+        } else {
+            None
+        }
+    }
+
+    /// Cast `object` to an item model, if it inherits `QAbstractItemModel`.
+    pub fn cast_mut(object: &mut QObject) -> Option<&mut QAbstractItemModel> {
+        if object.inherits(cstr!("QAbstractItemModel")) {
+            Some(unsafe { &mut *(object as *mut QObject as *mut QAbstractItemModel) })
+        } else {
+            None
+        }
+    }
+
+    pub fn row_count(&self, parent: &QModelIndex) -> i32 {
+        unsafe { crate::ffi::qffi_QAbstractItemModel_rowCount(self.to_inner(), parent) }
+    }
+
+    pub fn column_count(&self, parent: &QModelIndex) -> i32 {
+        unsafe { crate::ffi::qffi_QAbstractItemModel_columnCount(self.to_inner(), parent) }
+    }
+
+    pub fn index(&self, row: i32, column: i32, parent: &QModelIndex) -> QModelIndex {
+        init_ffi_struct(|result| unsafe {
+            crate::ffi::qffi_QAbstractItemModel_index(self.to_inner(), row, column, parent, result)
+        })
+    }
+
+    pub fn parent(&self, index: &QModelIndex) -> QModelIndex {
+        init_ffi_struct(|result| unsafe {
+            crate::ffi::qffi_QAbstractItemModel_parent(self.to_inner(), index, result)
+        })
+    }
+
+    pub fn sibling(&self, row: i32, column: i32, index: &QModelIndex) -> QModelIndex {
+        init_ffi_struct(|result| unsafe {
+            crate::ffi::qffi_QAbstractItemModel_sibling(self.to_inner(), row, column, index, result)
+        })
+    }
+
+    pub fn data(&self, index: &QModelIndex, role: i32) -> QVariant {
+        unsafe {
+            QVariant::create(|result| {
+                crate::ffi::qffi_QAbstractItemModel_data(self.to_inner(), index, role, result)
+            })
+        }
+    }
+
+    pub fn set_data(&mut self, index: &QModelIndex, value: &QVariant, role: i32) -> bool {
+        unsafe {
+            crate::ffi::qffi_QAbstractItemModel_setData(
+                self.to_inner_mut(),
+                index,
+                value.to_inner(),
+                role,
+            )
+        }
+    }
+
+    pub fn flags(&self, index: &QModelIndex) -> ItemFlags {
+        ItemFlags::from_bits(unsafe {
+            crate::ffi::qffi_QAbstractItemModel_flags(self.to_inner(), index)
+        })
+    }
+
+    pub fn header_data(&self, section: i32, orientation: Orientation, role: i32) -> QVariant {
+        unsafe {
+            QVariant::create(|result| {
+                crate::ffi::qffi_QAbstractItemModel_headerData(
+                    self.to_inner(),
+                    section,
+                    orientation.value(),
+                    role,
+                    result,
+                )
+            })
+        }
+    }
+
+    pub fn role_names(&self) -> QHashIntQByteArray {
+        unsafe {
+            QHashIntQByteArray::create(|result| {
+                crate::ffi::qffi_QAbstractItemModel_roleNames(self.to_inner(), result)
+            })
+        }
+    }
+
+    pub fn rows_inserted_signal() -> TypedSignal<(QModelIndex, i32, i32)> {
+        unsafe { typed_signal!("rowsInserted(QModelIndex,int,int)") }
+    }
+
+    pub fn rows_removed_signal() -> TypedSignal<(QModelIndex, i32, i32)> {
+        unsafe { typed_signal!("rowsRemoved(QModelIndex,int,int)") }
+    }
+
+    pub fn rows_moved_signal() -> TypedSignal<(QModelIndex, i32, i32, QModelIndex, i32)> {
+        unsafe { typed_signal!("rowsMoved(QModelIndex,int,int,QModelIndex,int)") }
+    }
+
+    pub fn data_changed_signal() -> TypedSignal<(QModelIndex, QModelIndex, QVector<i32>)> {
+        unsafe { typed_signal!("dataChanged(QModelIndex,QModelIndex,QVector<int>)") }
+    }
+
+    pub fn model_reset_signal() -> TypedSignal<()> {
+        unsafe { typed_signal!("modelReset()") }
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub struct QModelIndex {
     r: i32,
     c: i32,
@@ -22,7 +142,7 @@ impl QModelIndex {
         }
     }
 
-    fn new_(r: i32, c: i32, i: *mut c_void, m: *const QAbstractItemModel) -> Self {
+    pub(crate) fn new_(r: i32, c: i32, i: *mut c_void, m: *const QAbstractItemModel) -> Self {
         Self { r, c, i, m }
     }
 
@@ -50,49 +170,75 @@ impl QModelIndex {
         self.r >= 0 && self.c >= 0 && !self.m.is_null()
     }
 
-    /*    pub fn parent(&self) -> QModelIndex {
-        if self.m != ptr::null() {
-            unsafe { (&*m).parent(self) }
-        } else {
-            QModelIndex::new()
+    /// Model of the index, if the index is valid.
+    ///
+    /// # Safety
+    ///
+    /// The model of the index must not be destroyed yet.
+    pub unsafe fn model_ref(&self) -> Option<&QAbstractItemModel> {
+        self.m.as_ref()
+    }
+
+    /// Parent index in the model of the index.
+    ///
+    /// # Safety
+    ///
+    /// The model of the index must not be destroyed yet.
+    pub unsafe fn parent(&self) -> QModelIndex {
+        match self.model_ref() {
+            Some(model) => model.parent(self),
+            None => QModelIndex::new(),
         }
     }
 
-    pub fn sibling(&self, arow: i32, acolumn: i32) -> QModelIndex {
-        if self.m != ptr::null() {
-            if self.r == arow && self.c == acolumn {
-                self.clone()
-            } else {
-                unsafe { (&*m).sibling(arow, acolumn, self) }
-            }
-        } else {
-            QModelIndex::new()
+    /// Index at `row` and `column` with the same parent in the model of the index.
+    ///
+    /// # Safety
+    ///
+    /// The model of the index must not be destroyed yet.
+    pub unsafe fn sibling(&self, row: i32, column: i32) -> QModelIndex {
+        match self.model_ref() {
+            Some(_) if self.r == row && self.c == column => *self,
+            Some(model) => model.sibling(row, column, self),
+            None => QModelIndex::new(),
         }
     }
 
-    pub fn child(&self, arow: i32, acolumn: i32) -> QModelIndex {
-        if self.m != ptr::null() {
-            unsafe { (&*m).index(arow, acolumn, self) }
-        } else {
-            QModelIndex::new()
+    /// Child index at `row` and `column` in the model of the index.
+    ///
+    /// # Safety
+    ///
+    /// The model of the index must not be destroyed yet.
+    pub unsafe fn child(&self, row: i32, column: i32) -> QModelIndex {
+        match self.model_ref() {
+            Some(model) => model.index(row, column, self),
+            None => QModelIndex::new(),
         }
     }
 
-    pub fn data(&self, arole: i32) -> QModelIndex {
-        if self.m != ptr::null() {
-            unsafe { (&*m).data(self, arole) }
-        } else {
-            QModelIndex::new()
+    /// Data for `role` from the model of the index.
+    ///
+    /// # Safety
+    ///
+    /// The model of the index must not be destroyed yet.
+    pub unsafe fn data(&self, role: i32) -> QVariant {
+        match self.model_ref() {
+            Some(model) => model.data(self, role),
+            None => QVariant::new(),
         }
     }
 
-    pub fn flags(&self) -> ItemFlags {
-        if self.m != ptr::null() {
-            unsafe { (&*m).flags(self) }
-        } else {
-            ItemFlags::default()
+    /// Flags from the model of the index.
+    ///
+    /// # Safety
+    ///
+    /// The model of the index must not be destroyed yet.
+    pub unsafe fn flags(&self) -> ItemFlags {
+        match self.model_ref() {
+            Some(model) => model.flags(self),
+            None => ItemFlags::default(),
         }
-    }*/
+    }
 }
 
 impl Default for QModelIndex {
@@ -102,8 +248,103 @@ impl Default for QModelIndex {
     }
 }
 
+pub const QT_DISPLAY_ROLE: i32 = 0;
+pub const QT_EDIT_ROLE: i32 = 2;
 pub const QT_USER_ROLE: i32 = 0x0100;
 
+/// See enum Qt::Orientation
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Orientation(i32);
+
+impl Orientation {
+    pub const HORIZONTAL: Orientation = Orientation(1);
+    pub const VERTICAL: Orientation = Orientation(2);
+
+    #[inline]
+    pub fn from_value(value: i32) -> Self {
+        Self(value)
+    }
+
+    #[inline]
+    pub fn value(self) -> i32 {
+        self.0
+    }
+}
+
+impl Default for Orientation {
+    #[inline]
+    fn default() -> Self {
+        Orientation::HORIZONTAL
+    }
+}
+
+/// See enum Qt::SortOrder
+#[repr(i32)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SortOrder {
+    Ascending = 0,
+    Descending = 1,
+}
+
+/// See enum Qt::ItemFlag
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct ItemFlags(i32);
+
+impl ItemFlags {
+    pub const NO_ITEM_FLAGS: ItemFlags = ItemFlags(0);
+    pub const ITEM_IS_SELECTABLE: ItemFlags = ItemFlags(1);
+    pub const ITEM_IS_EDITABLE: ItemFlags = ItemFlags(2);
+    pub const ITEM_IS_DRAG_ENABLED: ItemFlags = ItemFlags(4);
+    pub const ITEM_IS_DROP_ENABLED: ItemFlags = ItemFlags(8);
+    pub const ITEM_IS_USER_CHECKABLE: ItemFlags = ItemFlags(16);
+    pub const ITEM_IS_ENABLED: ItemFlags = ItemFlags(32);
+    pub const ITEM_IS_AUTO_TRISTATE: ItemFlags = ItemFlags(64);
+    pub const ITEM_NEVER_HAS_CHILDREN: ItemFlags = ItemFlags(128);
+    pub const ITEM_IS_USER_TRISTATE: ItemFlags = ItemFlags(256);
+
+    #[inline]
+    pub fn from_bits(bits: i32) -> Self {
+        Self(bits)
+    }
+
+    #[inline]
+    pub fn bits(self) -> i32 {
+        self.0
+    }
+
+    #[inline]
+    pub fn contains(self, other: ItemFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for ItemFlags {
+    type Output = ItemFlags;
+
+    #[inline]
+    fn bitor(self, rhs: ItemFlags) -> ItemFlags {
+        ItemFlags(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for ItemFlags {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: ItemFlags) {
+        self.0 |= rhs.0
+    }
+}
+
+impl BitAnd for ItemFlags {
+    type Output = ItemFlags;
+
+    #[inline]
+    fn bitand(self, rhs: ItemFlags) -> ItemFlags {
+        ItemFlags(self.0 & rhs.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -126,6 +367,29 @@ mod tests {
         )
     }
 
+    #[test]
+    fn invalid_navigation() {
+        let index = QModelIndex::new();
+        unsafe {
+            assert!(!index.parent().is_valid());
+            assert!(!index.sibling(0, 0).is_valid());
+            assert!(!index.child(0, 0).is_valid());
+            assert_eq!(ItemFlags::NO_ITEM_FLAGS, index.flags());
+        }
+    }
+
+    #[test]
+    fn item_flags() {
+        let flags = ItemFlags::ITEM_IS_SELECTABLE | ItemFlags::ITEM_IS_ENABLED;
+        assert_eq!(33, flags.bits());
+        assert!(flags.contains(ItemFlags::ITEM_IS_ENABLED));
+        assert!(!flags.contains(ItemFlags::ITEM_IS_EDITABLE));
+        assert_eq!(
+            ItemFlags::ITEM_IS_SELECTABLE,
+            flags & ItemFlags::ITEM_IS_SELECTABLE
+        );
+    }
+
     #[test]
     fn column() {
         assert_eq!(
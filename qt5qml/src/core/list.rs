@@ -1,15 +1,65 @@
-use std::mem::transmute;
+use std::fmt;
 use std::iter::FromIterator;
 use std::os::raw::c_int;
 
 use crate::ffi::QffiWrapper;
 
-#[repr(C)]
-#[derive(Clone, Default, Eq, PartialEq)]
-pub struct QObjectList(crate::ffi::QObjectList);
-impl_ffi_trait!(QObjectList);
+/// Element type of [`QList`].
+///
+/// Implemented for the element types listed in `bindgen.yml`.
+///
+/// # Safety
+///
+/// The FFI functions must operate on the C++ container with the element type `Self`.
+pub unsafe trait QListItem: Sized {
+    #[doc(hidden)]
+    type QffiList: Default + Clone + PartialEq;
+
+    #[doc(hidden)]
+    unsafe fn qffi_size(list: &Self::QffiList) -> c_int;
+    #[doc(hidden)]
+    unsafe fn qffi_at(list: &Self::QffiList, index: c_int) -> *const Self;
+    #[doc(hidden)]
+    unsafe fn qffi_append(list: &mut Self::QffiList, item: &Self);
+    #[doc(hidden)]
+    unsafe fn qffi_append_list(list: &mut Self::QffiList, other: &Self::QffiList);
+    #[doc(hidden)]
+    unsafe fn qffi_append_slice(list: &mut Self::QffiList, items: &[Self]);
+    #[doc(hidden)]
+    unsafe fn qffi_reserve_additional(list: &mut Self::QffiList, additional: c_int);
+}
+
+/// Element type, which `QList` stores in-place.
+///
+/// # Safety
+///
+/// `QList` must store the elements contiguously.
+pub unsafe trait QListSliceItem: QListItem {
+    #[doc(hidden)]
+    unsafe fn qffi_as_slice(list: &Self::QffiList, size: &mut c_int) -> *const Self;
+}
+
+/// `QList<T>`
+#[repr(transparent)]
+pub struct QList<T: QListItem>(T::QffiList);
 
-impl QObjectList {
+impl<T: QListItem> QffiWrapper for QList<T> {
+    type QffiObject = T::QffiList;
+
+    fn create_from_ffi_object(value: T::QffiList) -> Self {
+        Self(value)
+    }
+
+    fn to_inner(&self) -> &Self::QffiObject {
+        &self.0
+    }
+
+    fn to_inner_mut(&mut self) -> &mut Self::QffiObject {
+        &mut self.0
+    }
+}
+
+impl<T: QListItem> QList<T> {
     #[inline]
     pub fn new() -> Self {
         Self::default()
@@ -17,7 +67,7 @@ impl QObjectList {
 
     #[inline]
     pub fn len(&self) -> i32 {
-        unsafe { crate::ffi::qffi_QObjectList_size(self.to_inner()) }
+        unsafe { T::qffi_size(self.to_inner()) }
     }
 
     #[inline]
@@ -25,10 +75,51 @@ impl QObjectList {
         self.len() == 0
     }
 
-    pub fn as_slice(&self) -> &[*mut crate::core::QObject] {
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len() as usize {
+            Some(unsafe { &*T::qffi_at(self.to_inner(), index as c_int) })
+        } else {
+            None
+        }
+    }
+
+    pub fn push(&mut self, item: &T) {
+        unsafe { T::qffi_append(self.to_inner_mut(), item) }
+    }
+
+    pub fn append(&mut self, value: &QList<T>) {
+        unsafe { T::qffi_append_list(self.to_inner_mut(), value.to_inner()) }
+    }
+
+    pub fn extend_from_slice(&mut self, slice: &[T]) {
+        unsafe { T::qffi_append_slice(self.to_inner_mut(), slice) }
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        unsafe { T::qffi_reserve_additional(self.to_inner_mut(), additional as c_int) }
+    }
+
+    pub fn iter(&self) -> QListIter<'_, T> {
+        QListIter {
+            list: self,
+            index: 0,
+            end: self.len(),
+        }
+    }
+
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: QListSliceItem> QList<T> {
+    pub fn as_slice(&self) -> &[T] {
         unsafe {
             let mut size: c_int = 0;
-            let ptr = transmute(crate::ffi::qffi_QObjectList_asSlice(self.to_inner(), &mut size));
+            let ptr = T::qffi_as_slice(self.to_inner(), &mut size);
             if size != 0 {
                 std::slice::from_raw_parts(ptr, size as usize)
             } else {
@@ -36,147 +127,424 @@ impl QObjectList {
             }
         }
     }
+}
 
-    pub fn push(&mut self, item: &*mut crate::core::QObject) {
-        unsafe { crate::ffi::qffi_QObjectList_append(self.to_inner_mut(), transmute(item)) }
+impl<T: QListItem> Default for QList<T> {
+    #[inline]
+    fn default() -> Self {
+        Self(Default::default())
     }
+}
 
-    pub fn append(&mut self, value: &QObjectList) {
-        unsafe { crate::ffi::qffi_QObjectList_appendList(self.to_inner_mut(), transmute(value)) }
+impl<T: QListItem> Clone for QList<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
     }
+}
 
-    pub fn extend_from_slice(&mut self, slice: &[*mut crate::core::QObject]) {
-        unsafe { crate::ffi::qffi_QObjectList_appendSlice(self.to_inner_mut(), transmute(slice.as_ptr()), slice.len() as c_int) }
+impl<T: QListItem> PartialEq for QList<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
     }
+}
 
-    pub fn reserve(&mut self, additional: usize) {
-        unsafe { crate::ffi::qffi_QObjectList_reserveAdditional(self.to_inner_mut(), additional as i32) }
-    }
+impl<T: QListItem + Eq> Eq for QList<T> {}
 
-    pub fn iter(&self) -> impl Iterator<Item = &*mut crate::core::QObject> {
-        self.as_slice().iter()
+impl<T: QListItem + fmt::Debug> fmt::Debug for QList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
     }
 }
 
-impl Extend<*mut crate::core::QObject> for QObjectList {
-    fn extend<T: IntoIterator<Item = *mut crate::core::QObject>>(&mut self, iter: T) {
+impl<T: QListItem> Extend<T> for QList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
         for item in iter {
             self.push(&item);
         }
     }
 }
 
-impl<'a> Extend<&'a *mut crate::core::QObject> for QObjectList {
-    fn extend<T: IntoIterator<Item = &'a *mut crate::core::QObject>>(&mut self, iter: T) {
+impl<'a, T: QListItem + 'a> Extend<&'a T> for QList<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
         for item in iter {
             self.push(item);
         }
     }
 }
 
-impl FromIterator<*mut crate::core::QObject> for QObjectList {
-    fn from_iter<T: IntoIterator<Item = *mut crate::core::QObject>>(iter: T) -> Self {
+impl<T: QListItem> FromIterator<T> for QList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut res = Self::default();
         res.extend(iter);
         res
     }
 }
 
-impl<'a> IntoIterator for &'a QObjectList {
-    type Item = &'a *mut crate::core::QObject;
-    type IntoIter = std::slice::Iter<'a, *mut crate::core::QObject>;
+impl<'a, T: QListItem> IntoIterator for &'a QList<T> {
+    type Item = &'a T;
+    type IntoIter = QListIter<'a, T>;
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
-        self.as_slice().iter()
+        self.iter()
+    }
+}
+
+impl<T: QListItem> From<&[T]> for QList<T> {
+    fn from(value: &[T]) -> Self {
+        let mut res = Self::default();
+        res.extend_from_slice(value);
+        res
+    }
+}
+
+impl<T: QListItem> From<Vec<T>> for QList<T> {
+    fn from(value: Vec<T>) -> Self {
+        Self::from(value.as_slice())
+    }
+}
+
+impl<T: QListItem + Clone> From<&QList<T>> for Vec<T> {
+    fn from(value: &QList<T>) -> Self {
+        value.to_vec()
     }
 }
 
-#[repr(C)]
-#[derive(Clone, Default, Eq, PartialEq)]
-pub struct QStringList(crate::ffi::QStringList);
-impl_ffi_trait!(QStringList);
+/// Iterator over the elements of a [`QList`].
+pub struct QListIter<'a, T: QListItem> {
+    list: &'a QList<T>,
+    index: i32,
+    end: i32,
+}
+
+impl<'a, T: QListItem> Iterator for QListIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index < self.end {
+            let item = unsafe { &*T::qffi_at(self.list.to_inner(), self.index) };
+            self.index += 1;
+            Some(item)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.end - self.index) as usize;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: QListItem> DoubleEndedIterator for QListIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index < self.end {
+            self.end -= 1;
+            Some(unsafe { &*T::qffi_at(self.list.to_inner(), self.end) })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T: QListItem> ExactSizeIterator for QListIter<'a, T> {}
+
+pub type QObjectList = QList<*mut crate::core::QObject>;
+
+unsafe impl QListItem for *mut crate::core::QObject {
+    type QffiList = crate::ffi::QObjectList;
 
-impl QStringList {
     #[inline]
-    pub fn new() -> Self {
-        Self::default()
+    unsafe fn qffi_size(list: &Self::QffiList) -> c_int {
+        crate::ffi::qffi_QObjectList_size(list)
     }
 
     #[inline]
-    pub fn len(&self) -> i32 {
-        unsafe { crate::ffi::qffi_QStringList_size(self.to_inner()) }
+    unsafe fn qffi_at(list: &Self::QffiList, index: c_int) -> *const Self {
+        crate::ffi::qffi_QObjectList_at(list, index).cast()
     }
 
     #[inline]
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    unsafe fn qffi_append(list: &mut Self::QffiList, item: &Self) {
+        crate::ffi::qffi_QObjectList_append(list, (item as *const Self).cast())
     }
 
-    pub fn as_slice(&self) -> &[crate::core::QString] {
-        unsafe {
-            let mut size: c_int = 0;
-            let ptr = transmute(crate::ffi::qffi_QStringList_asSlice(self.to_inner(), &mut size));
-            if size != 0 {
-                std::slice::from_raw_parts(ptr, size as usize)
-            } else {
-                &[]
-            }
-        }
+    #[inline]
+    unsafe fn qffi_append_list(list: &mut Self::QffiList, other: &Self::QffiList) {
+        crate::ffi::qffi_QObjectList_appendList(list, (other as *const Self::QffiList).cast())
     }
 
-    pub fn push(&mut self, item: &crate::core::QString) {
-        unsafe { crate::ffi::qffi_QStringList_append(self.to_inner_mut(), transmute(item)) }
+    #[inline]
+    unsafe fn qffi_append_slice(list: &mut Self::QffiList, items: &[Self]) {
+        crate::ffi::qffi_QObjectList_appendSlice(list, items.as_ptr().cast(), items.len() as c_int)
     }
 
-    pub fn append(&mut self, value: &QStringList) {
-        unsafe { crate::ffi::qffi_QStringList_appendList(self.to_inner_mut(), transmute(value)) }
+    #[inline]
+    unsafe fn qffi_reserve_additional(list: &mut Self::QffiList, additional: c_int) {
+        crate::ffi::qffi_QObjectList_reserveAdditional(list, additional)
     }
+}
 
-    pub fn extend_from_slice(&mut self, slice: &[crate::core::QString]) {
-        unsafe { crate::ffi::qffi_QStringList_appendSlice(self.to_inner_mut(), transmute(slice.as_ptr()), slice.len() as c_int) }
+unsafe impl QListSliceItem for *mut crate::core::QObject {
+    #[inline]
+    unsafe fn qffi_as_slice(list: &Self::QffiList, size: &mut c_int) -> *const Self {
+        crate::ffi::qffi_QObjectList_asSlice(list, size).cast()
     }
+}
 
-    pub fn reserve(&mut self, additional: usize) {
-        unsafe { crate::ffi::qffi_QStringList_reserveAdditional(self.to_inner_mut(), additional as i32) }
+pub type QStringList = QList<crate::core::QString>;
+
+unsafe impl QListItem for crate::core::QString {
+    type QffiList = crate::ffi::QStringList;
+
+    #[inline]
+    unsafe fn qffi_size(list: &Self::QffiList) -> c_int {
+        crate::ffi::qffi_QStringList_size(list)
+    }
+
+    #[inline]
+    unsafe fn qffi_at(list: &Self::QffiList, index: c_int) -> *const Self {
+        crate::ffi::qffi_QStringList_at(list, index).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_append(list: &mut Self::QffiList, item: &Self) {
+        crate::ffi::qffi_QStringList_append(list, (item as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_append_list(list: &mut Self::QffiList, other: &Self::QffiList) {
+        crate::ffi::qffi_QStringList_appendList(list, (other as *const Self::QffiList).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_append_slice(list: &mut Self::QffiList, items: &[Self]) {
+        crate::ffi::qffi_QStringList_appendSlice(list, items.as_ptr().cast(), items.len() as c_int)
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = &crate::core::QString> {
-        self.as_slice().iter()
+    #[inline]
+    unsafe fn qffi_reserve_additional(list: &mut Self::QffiList, additional: c_int) {
+        crate::ffi::qffi_QStringList_reserveAdditional(list, additional)
     }
 }
 
-impl Extend<crate::core::QString> for QStringList {
-    fn extend<T: IntoIterator<Item = crate::core::QString>>(&mut self, iter: T) {
-        for item in iter {
-            self.push(&item);
-        }
+unsafe impl QListSliceItem for crate::core::QString {
+    #[inline]
+    unsafe fn qffi_as_slice(list: &Self::QffiList, size: &mut c_int) -> *const Self {
+        crate::ffi::qffi_QStringList_asSlice(list, size).cast()
     }
 }
 
-impl<'a> Extend<&'a crate::core::QString> for QStringList {
-    fn extend<T: IntoIterator<Item = &'a crate::core::QString>>(&mut self, iter: T) {
-        for item in iter {
-            self.push(item);
-        }
+unsafe impl QListItem for bool {
+    type QffiList = crate::ffi::QListBool;
+
+    #[inline]
+    unsafe fn qffi_size(list: &Self::QffiList) -> c_int {
+        crate::ffi::qffi_QListBool_size(list)
+    }
+
+    #[inline]
+    unsafe fn qffi_at(list: &Self::QffiList, index: c_int) -> *const Self {
+        crate::ffi::qffi_QListBool_at(list, index).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_append(list: &mut Self::QffiList, item: &Self) {
+        crate::ffi::qffi_QListBool_append(list, (item as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_append_list(list: &mut Self::QffiList, other: &Self::QffiList) {
+        crate::ffi::qffi_QListBool_appendList(list, (other as *const Self::QffiList).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_append_slice(list: &mut Self::QffiList, items: &[Self]) {
+        crate::ffi::qffi_QListBool_appendSlice(list, items.as_ptr().cast(), items.len() as c_int)
+    }
+
+    #[inline]
+    unsafe fn qffi_reserve_additional(list: &mut Self::QffiList, additional: c_int) {
+        crate::ffi::qffi_QListBool_reserveAdditional(list, additional)
     }
 }
 
-impl FromIterator<crate::core::QString> for QStringList {
-    fn from_iter<T: IntoIterator<Item = crate::core::QString>>(iter: T) -> Self {
-        let mut res = Self::default();
-        res.extend(iter);
-        res
+unsafe impl QListItem for i32 {
+    type QffiList = crate::ffi::QListInt;
+
+    #[inline]
+    unsafe fn qffi_size(list: &Self::QffiList) -> c_int {
+        crate::ffi::qffi_QListInt_size(list)
+    }
+
+    #[inline]
+    unsafe fn qffi_at(list: &Self::QffiList, index: c_int) -> *const Self {
+        crate::ffi::qffi_QListInt_at(list, index).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_append(list: &mut Self::QffiList, item: &Self) {
+        crate::ffi::qffi_QListInt_append(list, (item as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_append_list(list: &mut Self::QffiList, other: &Self::QffiList) {
+        crate::ffi::qffi_QListInt_appendList(list, (other as *const Self::QffiList).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_append_slice(list: &mut Self::QffiList, items: &[Self]) {
+        crate::ffi::qffi_QListInt_appendSlice(list, items.as_ptr().cast(), items.len() as c_int)
+    }
+
+    #[inline]
+    unsafe fn qffi_reserve_additional(list: &mut Self::QffiList, additional: c_int) {
+        crate::ffi::qffi_QListInt_reserveAdditional(list, additional)
     }
 }
 
-impl<'a> IntoIterator for &'a QStringList {
-    type Item = &'a crate::core::QString;
-    type IntoIter = std::slice::Iter<'a, crate::core::QString>;
+unsafe impl QListItem for i64 {
+    type QffiList = crate::ffi::QListLongLong;
 
     #[inline]
-    fn into_iter(self) -> Self::IntoIter {
-        self.as_slice().iter()
+    unsafe fn qffi_size(list: &Self::QffiList) -> c_int {
+        crate::ffi::qffi_QListLongLong_size(list)
+    }
+
+    #[inline]
+    unsafe fn qffi_at(list: &Self::QffiList, index: c_int) -> *const Self {
+        crate::ffi::qffi_QListLongLong_at(list, index).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_append(list: &mut Self::QffiList, item: &Self) {
+        crate::ffi::qffi_QListLongLong_append(list, (item as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_append_list(list: &mut Self::QffiList, other: &Self::QffiList) {
+        crate::ffi::qffi_QListLongLong_appendList(list, (other as *const Self::QffiList).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_append_slice(list: &mut Self::QffiList, items: &[Self]) {
+        crate::ffi::qffi_QListLongLong_appendSlice(list, items.as_ptr().cast(), items.len() as c_int)
+    }
+
+    #[inline]
+    unsafe fn qffi_reserve_additional(list: &mut Self::QffiList, additional: c_int) {
+        crate::ffi::qffi_QListLongLong_reserveAdditional(list, additional)
+    }
+}
+
+unsafe impl QListItem for f64 {
+    type QffiList = crate::ffi::QListDouble;
+
+    #[inline]
+    unsafe fn qffi_size(list: &Self::QffiList) -> c_int {
+        crate::ffi::qffi_QListDouble_size(list)
+    }
+
+    #[inline]
+    unsafe fn qffi_at(list: &Self::QffiList, index: c_int) -> *const Self {
+        crate::ffi::qffi_QListDouble_at(list, index).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_append(list: &mut Self::QffiList, item: &Self) {
+        crate::ffi::qffi_QListDouble_append(list, (item as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_append_list(list: &mut Self::QffiList, other: &Self::QffiList) {
+        crate::ffi::qffi_QListDouble_appendList(list, (other as *const Self::QffiList).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_append_slice(list: &mut Self::QffiList, items: &[Self]) {
+        crate::ffi::qffi_QListDouble_appendSlice(list, items.as_ptr().cast(), items.len() as c_int)
+    }
+
+    #[inline]
+    unsafe fn qffi_reserve_additional(list: &mut Self::QffiList, additional: c_int) {
+        crate::ffi::qffi_QListDouble_reserveAdditional(list, additional)
+    }
+}
+
+unsafe impl QListItem for crate::core::QByteArray {
+    type QffiList = crate::ffi::QListQByteArray;
+
+    #[inline]
+    unsafe fn qffi_size(list: &Self::QffiList) -> c_int {
+        crate::ffi::qffi_QListQByteArray_size(list)
+    }
+
+    #[inline]
+    unsafe fn qffi_at(list: &Self::QffiList, index: c_int) -> *const Self {
+        crate::ffi::qffi_QListQByteArray_at(list, index).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_append(list: &mut Self::QffiList, item: &Self) {
+        crate::ffi::qffi_QListQByteArray_append(list, (item as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_append_list(list: &mut Self::QffiList, other: &Self::QffiList) {
+        crate::ffi::qffi_QListQByteArray_appendList(list, (other as *const Self::QffiList).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_append_slice(list: &mut Self::QffiList, items: &[Self]) {
+        crate::ffi::qffi_QListQByteArray_appendSlice(list, items.as_ptr().cast(), items.len() as c_int)
+    }
+
+    #[inline]
+    unsafe fn qffi_reserve_additional(list: &mut Self::QffiList, additional: c_int) {
+        crate::ffi::qffi_QListQByteArray_reserveAdditional(list, additional)
+    }
+}
+
+unsafe impl QListSliceItem for crate::core::QByteArray {
+    #[inline]
+    unsafe fn qffi_as_slice(list: &Self::QffiList, size: &mut c_int) -> *const Self {
+        crate::ffi::qffi_QListQByteArray_asSlice(list, size).cast()
+    }
+}
+
+unsafe impl QListItem for crate::core::QVariant {
+    type QffiList = crate::ffi::QListQVariant;
+
+    #[inline]
+    unsafe fn qffi_size(list: &Self::QffiList) -> c_int {
+        crate::ffi::qffi_QListQVariant_size(list)
+    }
+
+    #[inline]
+    unsafe fn qffi_at(list: &Self::QffiList, index: c_int) -> *const Self {
+        crate::ffi::qffi_QListQVariant_at(list, index).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_append(list: &mut Self::QffiList, item: &Self) {
+        crate::ffi::qffi_QListQVariant_append(list, (item as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_append_list(list: &mut Self::QffiList, other: &Self::QffiList) {
+        crate::ffi::qffi_QListQVariant_appendList(list, (other as *const Self::QffiList).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_append_slice(list: &mut Self::QffiList, items: &[Self]) {
+        crate::ffi::qffi_QListQVariant_appendSlice(list, items.as_ptr().cast(), items.len() as c_int)
+    }
+
+    #[inline]
+    unsafe fn qffi_reserve_additional(list: &mut Self::QffiList, additional: c_int) {
+        crate::ffi::qffi_QListQVariant_reserveAdditional(list, additional)
     }
 }
 
@@ -0,0 +1,462 @@
+use std::collections::{HashMap, HashSet};
+use std::ffi::c_void;
+use std::hash::Hash;
+use std::os::raw::c_int;
+use std::ptr;
+
+use crate::core::{
+    QAbstractItemModel, QByteArray, QHashIntQByteArray, QObject, QObjectRef, QVariant, QT_USER_ROLE,
+};
+use crate::ffi::QffiWrapper;
+
+/// Item of a [`RustListModel`].
+pub trait ListItem {
+    /// Names of the roles, under which delegates access the data of the item.
+    ///
+    /// The role of the name at index `i` is `QT_USER_ROLE + i`.
+    fn role_names() -> Vec<&'static str>;
+
+    /// Data of the item for `role`.
+    fn data(&self, role: i32) -> QVariant;
+}
+
+/// List model backed by a `Vec<T>`.
+///
+/// All mutations go through the model, so that attached views are notified. The model is a
+/// `QAbstractListModel` and deleted, when it is dropped.
+pub struct RustListModel<T: ListItem> {
+    model: ptr::NonNull<crate::ffi::QffiListModel>,
+    // owned by `model`
+    items: *mut Vec<T>,
+}
+
+unsafe extern "C" fn row_count<T: ListItem>(data: *const c_void) -> c_int {
+    crate::panic::abort_on_panic("RustListModel::row_count", || {
+        (*(data as *const Vec<T>)).len() as c_int
+    })
+}
+
+unsafe extern "C" fn item_data<T: ListItem>(
+    data: *const c_void,
+    row: c_int,
+    role: c_int,
+    result: *mut c_void,
+) {
+    crate::panic::abort_on_panic("RustListModel::data", || {
+        let items = &*(data as *const Vec<T>);
+        *(result as *mut QVariant) = items[row as usize].data(role);
+    })
+}
+
+unsafe extern "C" fn drop_items<T: ListItem>(data: *mut c_void) {
+    crate::panic::abort_on_panic("RustListModel::drop", || {
+        drop(Box::from_raw(data as *mut Vec<T>))
+    })
+}
+
+impl<T: ListItem> RustListModel<T> {
+    pub fn new() -> Self {
+        Self::from(vec![])
+    }
+
+    #[inline]
+    fn model(&mut self) -> *mut crate::ffi::QffiListModel {
+        self.model.as_ptr()
+    }
+
+    #[inline]
+    pub fn as_item_model(&self) -> &QAbstractItemModel {
+        unsafe { &*(self.model.as_ptr() as *const QAbstractItemModel) }
+    }
+
+    #[inline]
+    pub fn as_item_model_mut(&mut self) -> &mut QAbstractItemModel {
+        unsafe { &mut *(self.model.as_ptr() as *mut QAbstractItemModel) }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { &*self.items }
+    }
+
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Append `item` to the end of the list.
+    pub fn push(&mut self, item: T) {
+        let row = self.len() as c_int;
+        unsafe {
+            crate::ffi::qffi_QffiListModel_beginInsertRows(self.model(), row, row);
+            (*self.items).push(item);
+            crate::ffi::qffi_QffiListModel_endInsertRows(self.model());
+        }
+    }
+
+    /// Insert `item` at position `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, item: T) {
+        assert!(index <= self.len(), "insertion index out of bounds");
+        let row = index as c_int;
+        unsafe {
+            crate::ffi::qffi_QffiListModel_beginInsertRows(self.model(), row, row);
+            (*self.items).insert(index, item);
+            crate::ffi::qffi_QffiListModel_endInsertRows(self.model());
+        }
+    }
+
+    /// Remove and return the item at position `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len(), "removal index out of bounds");
+        let row = index as c_int;
+        unsafe {
+            crate::ffi::qffi_QffiListModel_beginRemoveRows(self.model(), row, row);
+            let item = (*self.items).remove(index);
+            crate::ffi::qffi_QffiListModel_endRemoveRows(self.model());
+            item
+        }
+    }
+
+    /// Replace the item at position `index` and return the old item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, item: T) -> T {
+        assert!(index < self.len(), "index out of bounds");
+        let row = index as c_int;
+        unsafe {
+            let old = std::mem::replace(&mut (&mut *self.items)[index], item);
+            crate::ffi::qffi_QffiListModel_dataChanged(self.model(), row, row);
+            old
+        }
+    }
+
+    /// Replace all items and reset the model.
+    pub fn replace_all(&mut self, items: Vec<T>) -> Vec<T> {
+        unsafe {
+            crate::ffi::qffi_QffiListModel_beginResetModel(self.model());
+            let old = std::mem::replace(&mut *self.items, items);
+            crate::ffi::qffi_QffiListModel_endResetModel(self.model());
+            old
+        }
+    }
+
+    /// Remove all items.
+    pub fn clear(&mut self) {
+        self.replace_all(vec![]);
+    }
+
+    /// Replace all items with `items` without resetting the model.
+    ///
+    /// Items are matched by the keys returned by `key`. Rows of vanished keys are removed,
+    /// rows of new keys are inserted, rows of other keys are moved to their new position and
+    /// `dataChanged` is emitted for changed items. So views keep their state like the current
+    /// item and the scroll position. The model is reset, if the keys are not unique.
+    pub fn update_all<K, F>(&mut self, items: Vec<T>, key: F)
+    where
+        T: PartialEq,
+        K: Eq + Hash,
+        F: Fn(&T) -> K,
+    {
+        let positions: HashMap<K, usize> = items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| (key(item), i))
+            .collect();
+        let old_keys: HashSet<K> = self.iter().map(&key).collect();
+        if positions.len() != items.len() || old_keys.len() != self.len() {
+            self.replace_all(items);
+            return;
+        }
+
+        // new positions of the current rows
+        let mut targets: Vec<Option<usize>> = self
+            .iter()
+            .map(|item| positions.get(&key(item)).copied())
+            .collect();
+
+        // remove rows, last rows first
+        let mut end = targets.len();
+        while end > 0 {
+            if targets[end - 1].is_some() {
+                end -= 1;
+                continue;
+            }
+            let mut first = end - 1;
+            while first > 0 && targets[first - 1].is_none() {
+                first -= 1;
+            }
+            self.remove_rows(first, end - 1);
+            targets.drain(first..end);
+            end = first;
+        }
+        let mut targets: Vec<usize> = targets.into_iter().map(Option::unwrap).collect();
+
+        // move rows, which are not part of the longest sequence already in the new order,
+        // behind their predecessor in the new order
+        let in_order = longest_increasing_subsequence(&targets);
+        let mut sorted_targets = targets.clone();
+        sorted_targets.sort_unstable();
+        let mut moved: Vec<usize> = targets
+            .iter()
+            .zip(in_order)
+            .filter(|(_, in_order)| !in_order)
+            .map(|(&target, _)| target)
+            .collect();
+        moved.sort_unstable();
+        for target in moved {
+            let from = targets.iter().position(|&t| t == target).unwrap();
+            let to = match sorted_targets.binary_search(&target).unwrap() {
+                0 => 0,
+                i => {
+                    let predecessor = sorted_targets[i - 1];
+                    let predecessor = targets.iter().position(|&t| t == predecessor).unwrap();
+                    if from < predecessor {
+                        predecessor
+                    } else {
+                        predecessor + 1
+                    }
+                }
+            };
+            self.move_row(from, to);
+            targets.remove(from);
+            targets.insert(to, target);
+        }
+
+        // insert rows
+        let mut items: Vec<Option<T>> = items.into_iter().map(Some).collect();
+        let mut first = 0;
+        while first < items.len() {
+            let end = targets.get(first).copied().unwrap_or(items.len());
+            if end > first {
+                let new_items = items[first..end]
+                    .iter_mut()
+                    .map(|item| item.take().unwrap());
+                self.insert_rows(first, new_items.collect());
+                targets.splice(first..first, first..end);
+            }
+            first = end + 1;
+        }
+
+        // update changed rows
+        let mut changed: Option<(usize, usize)> = None;
+        for (row, item) in items.into_iter().enumerate() {
+            let item = match item {
+                Some(item) if item != self.as_slice()[row] => item,
+                _ => continue,
+            };
+            unsafe { (&mut *self.items)[row] = item };
+            changed = match changed {
+                Some((first, last)) if last + 1 == row => Some((first, row)),
+                Some((first, last)) => {
+                    self.data_changed(first, last);
+                    Some((row, row))
+                }
+                None => Some((row, row)),
+            };
+        }
+        if let Some((first, last)) = changed {
+            self.data_changed(first, last);
+        }
+    }
+
+    fn insert_rows(&mut self, first: usize, items: Vec<T>) {
+        let last = first + items.len() - 1;
+        unsafe {
+            crate::ffi::qffi_QffiListModel_beginInsertRows(
+                self.model(),
+                first as c_int,
+                last as c_int,
+            );
+            (*self.items).splice(first..first, items);
+            crate::ffi::qffi_QffiListModel_endInsertRows(self.model());
+        }
+    }
+
+    fn remove_rows(&mut self, first: usize, last: usize) {
+        unsafe {
+            crate::ffi::qffi_QffiListModel_beginRemoveRows(
+                self.model(),
+                first as c_int,
+                last as c_int,
+            );
+            (*self.items).drain(first..=last);
+            crate::ffi::qffi_QffiListModel_endRemoveRows(self.model());
+        }
+    }
+
+    /// Move the row `from`, so that it ends up at row `to`.
+    fn move_row(&mut self, from: usize, to: usize) {
+        if from == to {
+            return;
+        }
+        // the destination is the row before which the row is moved
+        let destination = if to > from { to + 1 } else { to };
+        unsafe {
+            let valid = crate::ffi::qffi_QffiListModel_beginMoveRows(
+                self.model(),
+                from as c_int,
+                from as c_int,
+                destination as c_int,
+            );
+            debug_assert!(valid);
+            let item = (*self.items).remove(from);
+            (*self.items).insert(to, item);
+            crate::ffi::qffi_QffiListModel_endMoveRows(self.model());
+        }
+    }
+
+    fn data_changed(&mut self, first: usize, last: usize) {
+        unsafe {
+            crate::ffi::qffi_QffiListModel_dataChanged(self.model(), first as c_int, last as c_int)
+        }
+    }
+}
+
+/// Mask of the values, which are part of a longest strictly increasing subsequence.
+fn longest_increasing_subsequence(values: &[usize]) -> Vec<bool> {
+    // indexes of the last values of the increasing subsequences with length i + 1
+    let mut tails: Vec<usize> = vec![];
+    let mut predecessors: Vec<Option<usize>> = Vec::with_capacity(values.len());
+    for (i, &value) in values.iter().enumerate() {
+        let length = tails
+            .binary_search_by(|&tail| values[tail].cmp(&value))
+            .unwrap_or_else(|length| length);
+        predecessors.push(if length > 0 {
+            Some(tails[length - 1])
+        } else {
+            None
+        });
+        if length == tails.len() {
+            tails.push(i);
+        } else {
+            tails[length] = i;
+        }
+    }
+
+    let mut result = vec![false; values.len()];
+    let mut next = tails.last().copied();
+    while let Some(i) = next {
+        result[i] = true;
+        next = predecessors[i];
+    }
+    result
+}
+
+impl<T: ListItem> Default for RustListModel<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ListItem> From<Vec<T>> for RustListModel<T> {
+    fn from(items: Vec<T>) -> Self {
+        let mut role_names = QHashIntQByteArray::new();
+        for (i, name) in T::role_names().into_iter().enumerate() {
+            role_names.insert(&(QT_USER_ROLE + i as i32), &QByteArray::from(name));
+        }
+
+        let items = Box::into_raw(Box::new(items));
+        let model = unsafe {
+            crate::ffi::qffi_QffiListModel_init(
+                ptr::null_mut(),
+                items as *mut c_void,
+                Some(row_count::<T>),
+                Some(item_data::<T>),
+                Some(drop_items::<T>),
+                role_names.to_inner(),
+            )
+        };
+        Self {
+            model: ptr::NonNull::new(model).unwrap(),
+            items,
+        }
+    }
+}
+
+impl<T: ListItem> QObjectRef for RustListModel<T> {
+    fn as_qobject_mut(&mut self) -> &mut QObject {
+        unsafe { &mut *(self.model.as_ptr() as *mut QObject) }
+    }
+
+    fn as_qobject(&self) -> &QObject {
+        unsafe { &*(self.model.as_ptr() as *const QObject) }
+    }
+}
+
+impl<T: ListItem> Drop for RustListModel<T> {
+    fn drop(&mut self) {
+        // also drops the items
+        unsafe { self.delete() };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lis_empty() {
+        assert!(longest_increasing_subsequence(&[]).is_empty());
+    }
+
+    #[test]
+    fn lis_sorted() {
+        assert_eq!(
+            vec![true, true, true],
+            longest_increasing_subsequence(&[0, 1, 2])
+        );
+    }
+
+    #[test]
+    fn lis_moved_to_front() {
+        assert_eq!(
+            vec![true, true, true, false],
+            longest_increasing_subsequence(&[1, 2, 3, 0])
+        );
+    }
+
+    #[test]
+    fn lis_reversed() {
+        assert_eq!(
+            1,
+            longest_increasing_subsequence(&[3, 2, 1, 0])
+                .into_iter()
+                .filter(|&in_lis| in_lis)
+                .count()
+        );
+    }
+
+    #[test]
+    fn lis_mixed() {
+        assert_eq!(
+            vec![false, true, false, true, true, false],
+            longest_increasing_subsequence(&[5, 1, 4, 2, 3, 0])
+        );
+    }
+}
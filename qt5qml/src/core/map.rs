@@ -0,0 +1,962 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use std::os::raw::{c_int, c_void};
+
+use crate::core::{QByteArray, QString, QVariant};
+use crate::ffi::{QffiMapEntryCall, QffiWrapper};
+
+pub type QVariantMap = QMap<QString, QVariant>;
+pub type QVariantHash = QHash<QString, QVariant>;
+pub type QHashIntQByteArray = QHash<i32, QByteArray>;
+
+unsafe extern "C" fn collect_entry<K, V>(data: *mut c_void, key: *const c_void, value: *const c_void) {
+    let entries = &mut *(data as *mut Vec<(*const K, *const V)>);
+    entries.push((key.cast(), value.cast()));
+}
+
+/// Key type of [`QMap`] with value type `V`.
+///
+/// Implemented for the key and value types listed in `bindgen.yml`.
+///
+/// # Safety
+///
+/// The FFI functions must operate on the C++ container with the key type `Self` and value type `V`.
+pub unsafe trait QMapKey<V>: Sized {
+    #[doc(hidden)]
+    type QffiMap: Default + Clone + PartialEq;
+
+    #[doc(hidden)]
+    unsafe fn qffi_size(map: &Self::QffiMap) -> c_int;
+    #[doc(hidden)]
+    unsafe fn qffi_contains(map: &Self::QffiMap, key: &Self) -> bool;
+    #[doc(hidden)]
+    unsafe fn qffi_value(map: &Self::QffiMap, key: &Self) -> *const V;
+    #[doc(hidden)]
+    unsafe fn qffi_insert(map: &mut Self::QffiMap, key: &Self, value: &V);
+    #[doc(hidden)]
+    unsafe fn qffi_remove(map: &mut Self::QffiMap, key: &Self) -> c_int;
+    #[doc(hidden)]
+    unsafe fn qffi_for_each(map: &Self::QffiMap, data: *mut c_void, call: QffiMapEntryCall);
+}
+
+/// `QMap<K, V>`
+#[repr(transparent)]
+pub struct QMap<K: QMapKey<V>, V>(K::QffiMap, PhantomData<V>);
+
+impl<K: QMapKey<V>, V> QffiWrapper for QMap<K, V> {
+    type QffiObject = K::QffiMap;
+
+    fn create_from_ffi_object(value: K::QffiMap) -> Self {
+        Self(value, PhantomData)
+    }
+
+    fn to_inner(&self) -> &Self::QffiObject {
+        &self.0
+    }
+
+    fn to_inner_mut(&mut self) -> &mut Self::QffiObject {
+        &mut self.0
+    }
+}
+
+impl<K: QMapKey<V>, V> QMap<K, V> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn len(&self) -> i32 {
+        unsafe { K::qffi_size(self.to_inner()) }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        unsafe { K::qffi_contains(self.to_inner(), key) }
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        unsafe { K::qffi_value(self.to_inner(), key).as_ref() }
+    }
+
+    pub fn insert(&mut self, key: &K, value: &V) {
+        unsafe { K::qffi_insert(self.to_inner_mut(), key, value) }
+    }
+
+    /// Remove `key` and return whether it was contained.
+    pub fn remove(&mut self, key: &K) -> bool {
+        unsafe { K::qffi_remove(self.to_inner_mut(), key) != 0 }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        let mut entries: Vec<(*const K, *const V)> = Vec::with_capacity(self.len() as usize);
+        unsafe {
+            K::qffi_for_each(
+                self.to_inner(),
+                &mut entries as *mut _ as *mut c_void,
+                Some(collect_entry::<K, V>),
+            );
+        }
+        entries
+            .into_iter()
+            .map(|(key, value)| unsafe { (&*key, &*value) })
+    }
+}
+
+impl<K: QMapKey<V>, V> Default for QMap<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self(Default::default(), PhantomData)
+    }
+}
+
+impl<K: QMapKey<V>, V> Clone for QMap<K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<K: QMapKey<V>, V> PartialEq for QMap<K, V> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K: QMapKey<V> + Eq, V: Eq> Eq for QMap<K, V> {}
+
+impl<K: QMapKey<V> + fmt::Debug, V: fmt::Debug> fmt::Debug for QMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: QMapKey<V>, V> Extend<(K, V)> for QMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(&key, &value);
+        }
+    }
+}
+
+impl<'a, K: QMapKey<V> + 'a, V: 'a> Extend<(&'a K, &'a V)> for QMap<K, V> {
+    fn extend<I: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: QMapKey<V>, V> FromIterator<(K, V)> for QMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut res = Self::default();
+        res.extend(iter);
+        res
+    }
+}
+
+impl<K: QMapKey<V>, V, S: BuildHasher> From<HashMap<K, V, S>> for QMap<K, V> {
+    fn from(value: HashMap<K, V, S>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<K: QMapKey<V>, V> From<BTreeMap<K, V>> for QMap<K, V> {
+    fn from(value: BTreeMap<K, V>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<K: QMapKey<V> + Ord + Clone, V: Clone> From<&QMap<K, V>> for BTreeMap<K, V> {
+    fn from(value: &QMap<K, V>) -> Self {
+        value
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+}
+
+unsafe impl QMapKey<i32> for i32 {
+    type QffiMap = crate::ffi::QMapIntInt;
+
+    #[inline]
+    unsafe fn qffi_size(map: &Self::QffiMap) -> c_int {
+        crate::ffi::qffi_QMapIntInt_size(map)
+    }
+
+    #[inline]
+    unsafe fn qffi_contains(map: &Self::QffiMap, key: &Self) -> bool {
+        crate::ffi::qffi_QMapIntInt_contains(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_value(map: &Self::QffiMap, key: &Self) -> *const i32 {
+        crate::ffi::qffi_QMapIntInt_value(map, (key as *const Self).cast()).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_insert(map: &mut Self::QffiMap, key: &Self, value: &i32) {
+        crate::ffi::qffi_QMapIntInt_insert(
+            map,
+            (key as *const Self).cast(),
+            (value as *const i32).cast(),
+        )
+    }
+
+    #[inline]
+    unsafe fn qffi_remove(map: &mut Self::QffiMap, key: &Self) -> c_int {
+        crate::ffi::qffi_QMapIntInt_remove(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_for_each(map: &Self::QffiMap, data: *mut c_void, call: QffiMapEntryCall) {
+        crate::ffi::qffi_QMapIntInt_forEach(map, data, call)
+    }
+}
+
+unsafe impl QMapKey<crate::core::QString> for i32 {
+    type QffiMap = crate::ffi::QMapIntQString;
+
+    #[inline]
+    unsafe fn qffi_size(map: &Self::QffiMap) -> c_int {
+        crate::ffi::qffi_QMapIntQString_size(map)
+    }
+
+    #[inline]
+    unsafe fn qffi_contains(map: &Self::QffiMap, key: &Self) -> bool {
+        crate::ffi::qffi_QMapIntQString_contains(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_value(map: &Self::QffiMap, key: &Self) -> *const crate::core::QString {
+        crate::ffi::qffi_QMapIntQString_value(map, (key as *const Self).cast()).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_insert(map: &mut Self::QffiMap, key: &Self, value: &crate::core::QString) {
+        crate::ffi::qffi_QMapIntQString_insert(
+            map,
+            (key as *const Self).cast(),
+            (value as *const crate::core::QString).cast(),
+        )
+    }
+
+    #[inline]
+    unsafe fn qffi_remove(map: &mut Self::QffiMap, key: &Self) -> c_int {
+        crate::ffi::qffi_QMapIntQString_remove(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_for_each(map: &Self::QffiMap, data: *mut c_void, call: QffiMapEntryCall) {
+        crate::ffi::qffi_QMapIntQString_forEach(map, data, call)
+    }
+}
+
+unsafe impl QMapKey<crate::core::QByteArray> for i32 {
+    type QffiMap = crate::ffi::QMapIntQByteArray;
+
+    #[inline]
+    unsafe fn qffi_size(map: &Self::QffiMap) -> c_int {
+        crate::ffi::qffi_QMapIntQByteArray_size(map)
+    }
+
+    #[inline]
+    unsafe fn qffi_contains(map: &Self::QffiMap, key: &Self) -> bool {
+        crate::ffi::qffi_QMapIntQByteArray_contains(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_value(map: &Self::QffiMap, key: &Self) -> *const crate::core::QByteArray {
+        crate::ffi::qffi_QMapIntQByteArray_value(map, (key as *const Self).cast()).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_insert(map: &mut Self::QffiMap, key: &Self, value: &crate::core::QByteArray) {
+        crate::ffi::qffi_QMapIntQByteArray_insert(
+            map,
+            (key as *const Self).cast(),
+            (value as *const crate::core::QByteArray).cast(),
+        )
+    }
+
+    #[inline]
+    unsafe fn qffi_remove(map: &mut Self::QffiMap, key: &Self) -> c_int {
+        crate::ffi::qffi_QMapIntQByteArray_remove(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_for_each(map: &Self::QffiMap, data: *mut c_void, call: QffiMapEntryCall) {
+        crate::ffi::qffi_QMapIntQByteArray_forEach(map, data, call)
+    }
+}
+
+unsafe impl QMapKey<crate::core::QVariant> for i32 {
+    type QffiMap = crate::ffi::QMapIntQVariant;
+
+    #[inline]
+    unsafe fn qffi_size(map: &Self::QffiMap) -> c_int {
+        crate::ffi::qffi_QMapIntQVariant_size(map)
+    }
+
+    #[inline]
+    unsafe fn qffi_contains(map: &Self::QffiMap, key: &Self) -> bool {
+        crate::ffi::qffi_QMapIntQVariant_contains(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_value(map: &Self::QffiMap, key: &Self) -> *const crate::core::QVariant {
+        crate::ffi::qffi_QMapIntQVariant_value(map, (key as *const Self).cast()).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_insert(map: &mut Self::QffiMap, key: &Self, value: &crate::core::QVariant) {
+        crate::ffi::qffi_QMapIntQVariant_insert(
+            map,
+            (key as *const Self).cast(),
+            (value as *const crate::core::QVariant).cast(),
+        )
+    }
+
+    #[inline]
+    unsafe fn qffi_remove(map: &mut Self::QffiMap, key: &Self) -> c_int {
+        crate::ffi::qffi_QMapIntQVariant_remove(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_for_each(map: &Self::QffiMap, data: *mut c_void, call: QffiMapEntryCall) {
+        crate::ffi::qffi_QMapIntQVariant_forEach(map, data, call)
+    }
+}
+
+unsafe impl QMapKey<i32> for crate::core::QString {
+    type QffiMap = crate::ffi::QMapQStringInt;
+
+    #[inline]
+    unsafe fn qffi_size(map: &Self::QffiMap) -> c_int {
+        crate::ffi::qffi_QMapQStringInt_size(map)
+    }
+
+    #[inline]
+    unsafe fn qffi_contains(map: &Self::QffiMap, key: &Self) -> bool {
+        crate::ffi::qffi_QMapQStringInt_contains(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_value(map: &Self::QffiMap, key: &Self) -> *const i32 {
+        crate::ffi::qffi_QMapQStringInt_value(map, (key as *const Self).cast()).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_insert(map: &mut Self::QffiMap, key: &Self, value: &i32) {
+        crate::ffi::qffi_QMapQStringInt_insert(
+            map,
+            (key as *const Self).cast(),
+            (value as *const i32).cast(),
+        )
+    }
+
+    #[inline]
+    unsafe fn qffi_remove(map: &mut Self::QffiMap, key: &Self) -> c_int {
+        crate::ffi::qffi_QMapQStringInt_remove(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_for_each(map: &Self::QffiMap, data: *mut c_void, call: QffiMapEntryCall) {
+        crate::ffi::qffi_QMapQStringInt_forEach(map, data, call)
+    }
+}
+
+unsafe impl QMapKey<f64> for crate::core::QString {
+    type QffiMap = crate::ffi::QMapQStringDouble;
+
+    #[inline]
+    unsafe fn qffi_size(map: &Self::QffiMap) -> c_int {
+        crate::ffi::qffi_QMapQStringDouble_size(map)
+    }
+
+    #[inline]
+    unsafe fn qffi_contains(map: &Self::QffiMap, key: &Self) -> bool {
+        crate::ffi::qffi_QMapQStringDouble_contains(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_value(map: &Self::QffiMap, key: &Self) -> *const f64 {
+        crate::ffi::qffi_QMapQStringDouble_value(map, (key as *const Self).cast()).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_insert(map: &mut Self::QffiMap, key: &Self, value: &f64) {
+        crate::ffi::qffi_QMapQStringDouble_insert(
+            map,
+            (key as *const Self).cast(),
+            (value as *const f64).cast(),
+        )
+    }
+
+    #[inline]
+    unsafe fn qffi_remove(map: &mut Self::QffiMap, key: &Self) -> c_int {
+        crate::ffi::qffi_QMapQStringDouble_remove(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_for_each(map: &Self::QffiMap, data: *mut c_void, call: QffiMapEntryCall) {
+        crate::ffi::qffi_QMapQStringDouble_forEach(map, data, call)
+    }
+}
+
+unsafe impl QMapKey<crate::core::QString> for crate::core::QString {
+    type QffiMap = crate::ffi::QMapQStringQString;
+
+    #[inline]
+    unsafe fn qffi_size(map: &Self::QffiMap) -> c_int {
+        crate::ffi::qffi_QMapQStringQString_size(map)
+    }
+
+    #[inline]
+    unsafe fn qffi_contains(map: &Self::QffiMap, key: &Self) -> bool {
+        crate::ffi::qffi_QMapQStringQString_contains(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_value(map: &Self::QffiMap, key: &Self) -> *const crate::core::QString {
+        crate::ffi::qffi_QMapQStringQString_value(map, (key as *const Self).cast()).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_insert(map: &mut Self::QffiMap, key: &Self, value: &crate::core::QString) {
+        crate::ffi::qffi_QMapQStringQString_insert(
+            map,
+            (key as *const Self).cast(),
+            (value as *const crate::core::QString).cast(),
+        )
+    }
+
+    #[inline]
+    unsafe fn qffi_remove(map: &mut Self::QffiMap, key: &Self) -> c_int {
+        crate::ffi::qffi_QMapQStringQString_remove(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_for_each(map: &Self::QffiMap, data: *mut c_void, call: QffiMapEntryCall) {
+        crate::ffi::qffi_QMapQStringQString_forEach(map, data, call)
+    }
+}
+
+unsafe impl QMapKey<crate::core::QVariant> for crate::core::QString {
+    type QffiMap = crate::ffi::QMapQStringQVariant;
+
+    #[inline]
+    unsafe fn qffi_size(map: &Self::QffiMap) -> c_int {
+        crate::ffi::qffi_QMapQStringQVariant_size(map)
+    }
+
+    #[inline]
+    unsafe fn qffi_contains(map: &Self::QffiMap, key: &Self) -> bool {
+        crate::ffi::qffi_QMapQStringQVariant_contains(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_value(map: &Self::QffiMap, key: &Self) -> *const crate::core::QVariant {
+        crate::ffi::qffi_QMapQStringQVariant_value(map, (key as *const Self).cast()).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_insert(map: &mut Self::QffiMap, key: &Self, value: &crate::core::QVariant) {
+        crate::ffi::qffi_QMapQStringQVariant_insert(
+            map,
+            (key as *const Self).cast(),
+            (value as *const crate::core::QVariant).cast(),
+        )
+    }
+
+    #[inline]
+    unsafe fn qffi_remove(map: &mut Self::QffiMap, key: &Self) -> c_int {
+        crate::ffi::qffi_QMapQStringQVariant_remove(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_for_each(map: &Self::QffiMap, data: *mut c_void, call: QffiMapEntryCall) {
+        crate::ffi::qffi_QMapQStringQVariant_forEach(map, data, call)
+    }
+}
+
+
+/// Key type of [`QHash`] with value type `V`.
+///
+/// Implemented for the key and value types listed in `bindgen.yml`.
+///
+/// # Safety
+///
+/// The FFI functions must operate on the C++ container with the key type `Self` and value type `V`.
+pub unsafe trait QHashKey<V>: Sized {
+    #[doc(hidden)]
+    type QffiMap: Default + Clone + PartialEq;
+
+    #[doc(hidden)]
+    unsafe fn qffi_size(map: &Self::QffiMap) -> c_int;
+    #[doc(hidden)]
+    unsafe fn qffi_contains(map: &Self::QffiMap, key: &Self) -> bool;
+    #[doc(hidden)]
+    unsafe fn qffi_value(map: &Self::QffiMap, key: &Self) -> *const V;
+    #[doc(hidden)]
+    unsafe fn qffi_insert(map: &mut Self::QffiMap, key: &Self, value: &V);
+    #[doc(hidden)]
+    unsafe fn qffi_remove(map: &mut Self::QffiMap, key: &Self) -> c_int;
+    #[doc(hidden)]
+    unsafe fn qffi_for_each(map: &Self::QffiMap, data: *mut c_void, call: QffiMapEntryCall);
+}
+
+/// `QHash<K, V>`
+#[repr(transparent)]
+pub struct QHash<K: QHashKey<V>, V>(K::QffiMap, PhantomData<V>);
+
+impl<K: QHashKey<V>, V> QffiWrapper for QHash<K, V> {
+    type QffiObject = K::QffiMap;
+
+    fn create_from_ffi_object(value: K::QffiMap) -> Self {
+        Self(value, PhantomData)
+    }
+
+    fn to_inner(&self) -> &Self::QffiObject {
+        &self.0
+    }
+
+    fn to_inner_mut(&mut self) -> &mut Self::QffiObject {
+        &mut self.0
+    }
+}
+
+impl<K: QHashKey<V>, V> QHash<K, V> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn len(&self) -> i32 {
+        unsafe { K::qffi_size(self.to_inner()) }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        unsafe { K::qffi_contains(self.to_inner(), key) }
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        unsafe { K::qffi_value(self.to_inner(), key).as_ref() }
+    }
+
+    pub fn insert(&mut self, key: &K, value: &V) {
+        unsafe { K::qffi_insert(self.to_inner_mut(), key, value) }
+    }
+
+    /// Remove `key` and return whether it was contained.
+    pub fn remove(&mut self, key: &K) -> bool {
+        unsafe { K::qffi_remove(self.to_inner_mut(), key) != 0 }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        let mut entries: Vec<(*const K, *const V)> = Vec::with_capacity(self.len() as usize);
+        unsafe {
+            K::qffi_for_each(
+                self.to_inner(),
+                &mut entries as *mut _ as *mut c_void,
+                Some(collect_entry::<K, V>),
+            );
+        }
+        entries
+            .into_iter()
+            .map(|(key, value)| unsafe { (&*key, &*value) })
+    }
+}
+
+impl<K: QHashKey<V>, V> Default for QHash<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self(Default::default(), PhantomData)
+    }
+}
+
+impl<K: QHashKey<V>, V> Clone for QHash<K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<K: QHashKey<V>, V> PartialEq for QHash<K, V> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K: QHashKey<V> + Eq, V: Eq> Eq for QHash<K, V> {}
+
+impl<K: QHashKey<V> + fmt::Debug, V: fmt::Debug> fmt::Debug for QHash<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: QHashKey<V>, V> Extend<(K, V)> for QHash<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(&key, &value);
+        }
+    }
+}
+
+impl<'a, K: QHashKey<V> + 'a, V: 'a> Extend<(&'a K, &'a V)> for QHash<K, V> {
+    fn extend<I: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: QHashKey<V>, V> FromIterator<(K, V)> for QHash<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut res = Self::default();
+        res.extend(iter);
+        res
+    }
+}
+
+impl<K: QHashKey<V>, V, S: BuildHasher> From<HashMap<K, V, S>> for QHash<K, V> {
+    fn from(value: HashMap<K, V, S>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<K: QHashKey<V>, V> From<BTreeMap<K, V>> for QHash<K, V> {
+    fn from(value: BTreeMap<K, V>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<K: QHashKey<V> + Eq + Hash + Clone, V: Clone> From<&QHash<K, V>> for HashMap<K, V> {
+    fn from(value: &QHash<K, V>) -> Self {
+        value
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+}
+
+unsafe impl QHashKey<i32> for i32 {
+    type QffiMap = crate::ffi::QHashIntInt;
+
+    #[inline]
+    unsafe fn qffi_size(map: &Self::QffiMap) -> c_int {
+        crate::ffi::qffi_QHashIntInt_size(map)
+    }
+
+    #[inline]
+    unsafe fn qffi_contains(map: &Self::QffiMap, key: &Self) -> bool {
+        crate::ffi::qffi_QHashIntInt_contains(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_value(map: &Self::QffiMap, key: &Self) -> *const i32 {
+        crate::ffi::qffi_QHashIntInt_value(map, (key as *const Self).cast()).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_insert(map: &mut Self::QffiMap, key: &Self, value: &i32) {
+        crate::ffi::qffi_QHashIntInt_insert(
+            map,
+            (key as *const Self).cast(),
+            (value as *const i32).cast(),
+        )
+    }
+
+    #[inline]
+    unsafe fn qffi_remove(map: &mut Self::QffiMap, key: &Self) -> c_int {
+        crate::ffi::qffi_QHashIntInt_remove(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_for_each(map: &Self::QffiMap, data: *mut c_void, call: QffiMapEntryCall) {
+        crate::ffi::qffi_QHashIntInt_forEach(map, data, call)
+    }
+}
+
+unsafe impl QHashKey<crate::core::QString> for i32 {
+    type QffiMap = crate::ffi::QHashIntQString;
+
+    #[inline]
+    unsafe fn qffi_size(map: &Self::QffiMap) -> c_int {
+        crate::ffi::qffi_QHashIntQString_size(map)
+    }
+
+    #[inline]
+    unsafe fn qffi_contains(map: &Self::QffiMap, key: &Self) -> bool {
+        crate::ffi::qffi_QHashIntQString_contains(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_value(map: &Self::QffiMap, key: &Self) -> *const crate::core::QString {
+        crate::ffi::qffi_QHashIntQString_value(map, (key as *const Self).cast()).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_insert(map: &mut Self::QffiMap, key: &Self, value: &crate::core::QString) {
+        crate::ffi::qffi_QHashIntQString_insert(
+            map,
+            (key as *const Self).cast(),
+            (value as *const crate::core::QString).cast(),
+        )
+    }
+
+    #[inline]
+    unsafe fn qffi_remove(map: &mut Self::QffiMap, key: &Self) -> c_int {
+        crate::ffi::qffi_QHashIntQString_remove(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_for_each(map: &Self::QffiMap, data: *mut c_void, call: QffiMapEntryCall) {
+        crate::ffi::qffi_QHashIntQString_forEach(map, data, call)
+    }
+}
+
+unsafe impl QHashKey<crate::core::QByteArray> for i32 {
+    type QffiMap = crate::ffi::QHashIntQByteArray;
+
+    #[inline]
+    unsafe fn qffi_size(map: &Self::QffiMap) -> c_int {
+        crate::ffi::qffi_QHashIntQByteArray_size(map)
+    }
+
+    #[inline]
+    unsafe fn qffi_contains(map: &Self::QffiMap, key: &Self) -> bool {
+        crate::ffi::qffi_QHashIntQByteArray_contains(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_value(map: &Self::QffiMap, key: &Self) -> *const crate::core::QByteArray {
+        crate::ffi::qffi_QHashIntQByteArray_value(map, (key as *const Self).cast()).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_insert(map: &mut Self::QffiMap, key: &Self, value: &crate::core::QByteArray) {
+        crate::ffi::qffi_QHashIntQByteArray_insert(
+            map,
+            (key as *const Self).cast(),
+            (value as *const crate::core::QByteArray).cast(),
+        )
+    }
+
+    #[inline]
+    unsafe fn qffi_remove(map: &mut Self::QffiMap, key: &Self) -> c_int {
+        crate::ffi::qffi_QHashIntQByteArray_remove(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_for_each(map: &Self::QffiMap, data: *mut c_void, call: QffiMapEntryCall) {
+        crate::ffi::qffi_QHashIntQByteArray_forEach(map, data, call)
+    }
+}
+
+unsafe impl QHashKey<crate::core::QVariant> for i32 {
+    type QffiMap = crate::ffi::QHashIntQVariant;
+
+    #[inline]
+    unsafe fn qffi_size(map: &Self::QffiMap) -> c_int {
+        crate::ffi::qffi_QHashIntQVariant_size(map)
+    }
+
+    #[inline]
+    unsafe fn qffi_contains(map: &Self::QffiMap, key: &Self) -> bool {
+        crate::ffi::qffi_QHashIntQVariant_contains(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_value(map: &Self::QffiMap, key: &Self) -> *const crate::core::QVariant {
+        crate::ffi::qffi_QHashIntQVariant_value(map, (key as *const Self).cast()).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_insert(map: &mut Self::QffiMap, key: &Self, value: &crate::core::QVariant) {
+        crate::ffi::qffi_QHashIntQVariant_insert(
+            map,
+            (key as *const Self).cast(),
+            (value as *const crate::core::QVariant).cast(),
+        )
+    }
+
+    #[inline]
+    unsafe fn qffi_remove(map: &mut Self::QffiMap, key: &Self) -> c_int {
+        crate::ffi::qffi_QHashIntQVariant_remove(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_for_each(map: &Self::QffiMap, data: *mut c_void, call: QffiMapEntryCall) {
+        crate::ffi::qffi_QHashIntQVariant_forEach(map, data, call)
+    }
+}
+
+unsafe impl QHashKey<i32> for crate::core::QString {
+    type QffiMap = crate::ffi::QHashQStringInt;
+
+    #[inline]
+    unsafe fn qffi_size(map: &Self::QffiMap) -> c_int {
+        crate::ffi::qffi_QHashQStringInt_size(map)
+    }
+
+    #[inline]
+    unsafe fn qffi_contains(map: &Self::QffiMap, key: &Self) -> bool {
+        crate::ffi::qffi_QHashQStringInt_contains(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_value(map: &Self::QffiMap, key: &Self) -> *const i32 {
+        crate::ffi::qffi_QHashQStringInt_value(map, (key as *const Self).cast()).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_insert(map: &mut Self::QffiMap, key: &Self, value: &i32) {
+        crate::ffi::qffi_QHashQStringInt_insert(
+            map,
+            (key as *const Self).cast(),
+            (value as *const i32).cast(),
+        )
+    }
+
+    #[inline]
+    unsafe fn qffi_remove(map: &mut Self::QffiMap, key: &Self) -> c_int {
+        crate::ffi::qffi_QHashQStringInt_remove(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_for_each(map: &Self::QffiMap, data: *mut c_void, call: QffiMapEntryCall) {
+        crate::ffi::qffi_QHashQStringInt_forEach(map, data, call)
+    }
+}
+
+unsafe impl QHashKey<f64> for crate::core::QString {
+    type QffiMap = crate::ffi::QHashQStringDouble;
+
+    #[inline]
+    unsafe fn qffi_size(map: &Self::QffiMap) -> c_int {
+        crate::ffi::qffi_QHashQStringDouble_size(map)
+    }
+
+    #[inline]
+    unsafe fn qffi_contains(map: &Self::QffiMap, key: &Self) -> bool {
+        crate::ffi::qffi_QHashQStringDouble_contains(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_value(map: &Self::QffiMap, key: &Self) -> *const f64 {
+        crate::ffi::qffi_QHashQStringDouble_value(map, (key as *const Self).cast()).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_insert(map: &mut Self::QffiMap, key: &Self, value: &f64) {
+        crate::ffi::qffi_QHashQStringDouble_insert(
+            map,
+            (key as *const Self).cast(),
+            (value as *const f64).cast(),
+        )
+    }
+
+    #[inline]
+    unsafe fn qffi_remove(map: &mut Self::QffiMap, key: &Self) -> c_int {
+        crate::ffi::qffi_QHashQStringDouble_remove(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_for_each(map: &Self::QffiMap, data: *mut c_void, call: QffiMapEntryCall) {
+        crate::ffi::qffi_QHashQStringDouble_forEach(map, data, call)
+    }
+}
+
+unsafe impl QHashKey<crate::core::QString> for crate::core::QString {
+    type QffiMap = crate::ffi::QHashQStringQString;
+
+    #[inline]
+    unsafe fn qffi_size(map: &Self::QffiMap) -> c_int {
+        crate::ffi::qffi_QHashQStringQString_size(map)
+    }
+
+    #[inline]
+    unsafe fn qffi_contains(map: &Self::QffiMap, key: &Self) -> bool {
+        crate::ffi::qffi_QHashQStringQString_contains(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_value(map: &Self::QffiMap, key: &Self) -> *const crate::core::QString {
+        crate::ffi::qffi_QHashQStringQString_value(map, (key as *const Self).cast()).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_insert(map: &mut Self::QffiMap, key: &Self, value: &crate::core::QString) {
+        crate::ffi::qffi_QHashQStringQString_insert(
+            map,
+            (key as *const Self).cast(),
+            (value as *const crate::core::QString).cast(),
+        )
+    }
+
+    #[inline]
+    unsafe fn qffi_remove(map: &mut Self::QffiMap, key: &Self) -> c_int {
+        crate::ffi::qffi_QHashQStringQString_remove(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_for_each(map: &Self::QffiMap, data: *mut c_void, call: QffiMapEntryCall) {
+        crate::ffi::qffi_QHashQStringQString_forEach(map, data, call)
+    }
+}
+
+unsafe impl QHashKey<crate::core::QVariant> for crate::core::QString {
+    type QffiMap = crate::ffi::QHashQStringQVariant;
+
+    #[inline]
+    unsafe fn qffi_size(map: &Self::QffiMap) -> c_int {
+        crate::ffi::qffi_QHashQStringQVariant_size(map)
+    }
+
+    #[inline]
+    unsafe fn qffi_contains(map: &Self::QffiMap, key: &Self) -> bool {
+        crate::ffi::qffi_QHashQStringQVariant_contains(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_value(map: &Self::QffiMap, key: &Self) -> *const crate::core::QVariant {
+        crate::ffi::qffi_QHashQStringQVariant_value(map, (key as *const Self).cast()).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_insert(map: &mut Self::QffiMap, key: &Self, value: &crate::core::QVariant) {
+        crate::ffi::qffi_QHashQStringQVariant_insert(
+            map,
+            (key as *const Self).cast(),
+            (value as *const crate::core::QVariant).cast(),
+        )
+    }
+
+    #[inline]
+    unsafe fn qffi_remove(map: &mut Self::QffiMap, key: &Self) -> c_int {
+        crate::ffi::qffi_QHashQStringQVariant_remove(map, (key as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_for_each(map: &Self::QffiMap, data: *mut c_void, call: QffiMapEntryCall) {
+        crate::ffi::qffi_QHashQStringQVariant_forEach(map, data, call)
+    }
+}
+
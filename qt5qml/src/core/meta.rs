@@ -1,4 +1,7 @@
-use crate::core::{ConnectionType, ConnectionTypeKind, QObject, QString, QVariant};
+use crate::core::{
+    ConnectionType, ConnectionTypeKind, ItemFlags, Orientation, QModelIndex, QObject, QString,
+    QVariant,
+};
 use crate::ffi::*;
 use std::ffi::{c_void, CStr};
 use std::mem::transmute;
@@ -233,12 +236,24 @@ pub trait QtMetaType {
     fn name() -> &'static CStr;
 }
 
-impl QtMetaType for QString {
-    fn name() -> &'static CStr {
-        cstr!("QString")
-    }
+macro_rules! impl_qt_meta_type {
+    ($rust:ty => $name:literal) => {
+        impl QtMetaType for $rust {
+            fn name() -> &'static CStr {
+                cstr!($name)
+            }
+        }
+    };
 }
 
+impl_qt_meta_type!(bool => "bool");
+impl_qt_meta_type!(i32 => "int");
+impl_qt_meta_type!(QString => "QString");
+impl_qt_meta_type!(QVariant => "QVariant");
+impl_qt_meta_type!(QModelIndex => "QModelIndex");
+impl_qt_meta_type!(Orientation => "Qt::Orientation");
+impl_qt_meta_type!(ItemFlags => "Qt::ItemFlags");
+
 #[repr(C)]
 #[derive(Copy, Clone)]
 struct QGenericArgument {
@@ -1,23 +1,31 @@
 mod application;
 mod debug;
-mod hash;
 mod itemmodel;
 mod list;
+mod listmodel;
+mod map;
 mod meta;
 mod object;
+mod sortfilterproxymodel;
 mod string;
 mod thread;
 mod timer;
+mod treemodel;
 mod url;
 mod variant;
+mod vector;
 
 pub use self::application::*;
-pub use self::hash::*;
 pub use self::itemmodel::*;
 pub use self::list::*;
+pub use self::listmodel::*;
+pub use self::map::*;
 pub use self::meta::*;
 pub use self::object::*;
+pub use self::sortfilterproxymodel::*;
 pub use self::string::*;
 pub use self::timer::*;
+pub use self::treemodel::*;
 pub use self::url::*;
 pub use self::variant::QVariant;
+pub use self::vector::*;
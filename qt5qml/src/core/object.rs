@@ -1,14 +1,19 @@
 use crate::core::thread::QThread;
-use crate::core::{QMetaObject, QMetaObjectConnection};
+use crate::core::{QMetaObject, QMetaObjectConnection, QString};
 use crate::ffi::{
-    init_ffi_struct, qffi_QObject_connect, qffi_QObject_destroy, qffi_QObject_disconnect2,
-    qffi_QObject_disconnect3, qffi_QObject_disconnectConnection, qffi_QObject_inherits,
-    qffi_QObject_init, qffi_QObject_metaObject, qffi_QObject_moveToThread, QffiWrapper,
+    init_ffi_struct, qffi_QObject_connect, qffi_QObject_connectClosure, qffi_QObject_destroy,
+    qffi_QObject_disconnect2, qffi_QObject_disconnect3, qffi_QObject_disconnectConnection,
+    qffi_QObject_inherits, qffi_QObject_init, qffi_QObject_metaObject, qffi_QObject_moveToThread,
+    QffiWrapper,
 };
 use crate::QBox;
 use std::borrow::Cow;
-use std::ffi::{CStr, CString, NulError};
-use std::ptr;
+use std::ffi::{c_void, CStr, CString, NulError};
+use std::marker::PhantomData;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Mutex;
+use std::thread::{self, ThreadId};
+use std::{mem, process, ptr};
 
 #[repr(C)]
 pub struct QObject(pub(crate) crate::ffi::QObject);
@@ -46,6 +51,41 @@ pub struct Signal(Cow<'static, CStr>);
 #[derive(Clone, Eq, PartialEq)]
 pub struct Slot(Cow<'static, CStr>);
 
+/// Arguments of a signal passed to a closure connected with `connect_fn`.
+pub struct SignalArguments(*mut *mut c_void);
+
+/// Signal with argument types `Args`.
+///
+/// `Args` is a tuple of the value types of the signal arguments.
+pub struct TypedSignal<Args> {
+    signal: Signal,
+    _args: PhantomData<fn(Args)>,
+}
+
+/// Slot with argument types `Args`.
+///
+/// `Args` is a tuple of the value types of the slot arguments.
+pub struct TypedSlot<Args> {
+    slot: Slot,
+    _args: PhantomData<fn(Args)>,
+}
+
+/// Argument tuple of a signal that can be passed to a Rust closure.
+pub trait SignalArgs: Sized {
+    /// Copy arguments of signal.
+    ///
+    /// # Safety
+    ///
+    /// `args` must be the arguments of a signal with argument types `Self`.
+    unsafe fn from_signal_arguments(args: &SignalArguments) -> Self;
+}
+
+/// Argument tuple of a slot that can be connected to a signal with arguments `S`.
+///
+/// A slot can have fewer arguments than the signal, but the types of the arguments it has must
+/// match.
+pub trait CompatibleArgs<S> {}
+
 impl QObject {
     pub fn new() -> QBox<QObject> {
         unsafe { QBox::from_raw(std::mem::transmute(qffi_QObject_init(ptr::null_mut()))) }
@@ -98,6 +138,63 @@ impl QObject {
         }))
     }
 
+    /// Connect `signal` to the closure `f`.
+    ///
+    /// `thread` is the only thread, in which the closure may be called and dropped. It is `None`
+    /// for closures, which are `Send`.
+    fn connect_fn_internal<F: FnMut(&SignalArguments) + 'static>(
+        &self,
+        signal: &CStr,
+        f: F,
+        thread: Option<ThreadId>,
+        type_: i32,
+    ) -> QMetaObjectConnection {
+        struct Closure<F> {
+            thread: Option<ThreadId>,
+            f: F,
+        }
+
+        unsafe extern "C" fn call<F: FnMut(&SignalArguments)>(
+            data: *mut c_void,
+            args: *mut *mut c_void,
+        ) {
+            let closure = &mut *(data as *mut Closure<F>);
+            if closure.thread.is_some_and(|t| t != thread::current().id()) {
+                eprintln!("closure connected to a Qt signal called from another thread");
+                process::abort();
+            }
+            let f = &mut closure.f;
+            if catch_unwind(AssertUnwindSafe(|| f(&SignalArguments(args)))).is_err() {
+                eprintln!("panic in closure connected to a Qt signal");
+                process::abort();
+            }
+        }
+
+        unsafe extern "C" fn drop_closure<F>(data: *mut c_void) {
+            let closure = Box::from_raw(data as *mut Closure<F>);
+            if closure.thread.is_none() || closure.thread == Some(thread::current().id()) {
+                crate::panic::abort_on_panic("QObject::connect", || drop(closure));
+            } else {
+                // the captured values may not be dropped in another thread
+                mem::forget(closure);
+            }
+        }
+
+        let closure = Closure { thread, f };
+        let data = Box::into_raw(Box::new(closure)) as *mut c_void;
+        QMetaObjectConnection(init_ffi_struct(|dest| unsafe {
+            qffi_QObject_connectClosure(
+                self.to_inner(),
+                signal.as_ptr(),
+                Some(call::<F>),
+                data,
+                Some(drop_closure::<F>),
+                type_,
+                dest,
+            )
+        }))
+    }
+
     fn disconnect_internal(&self, signal: &CStr, receiver: &QObject, method: &CStr) -> bool {
         unsafe {
             qffi_QObject_disconnect3(
@@ -114,20 +211,23 @@ impl QObject {
         unsafe { qffi_QObject_disconnect2(self.to_inner(), receiver.to_inner(), method) }
     }
 
+    /// Disconnect a connection.
+    ///
+    /// Closures connected with `connect_fn` are dropped.
     pub fn disconnect(connection: &QMetaObjectConnection) -> bool {
         unsafe { qffi_QObject_disconnectConnection(connection.to_inner()) }
     }
 
-    pub fn object_name_changed_signal() -> Signal {
-        signal!("objectNameChanged(const QString&)")
+    pub fn object_name_changed_signal() -> TypedSignal<(QString,)> {
+        unsafe { typed_signal!("objectNameChanged(const QString&)") }
     }
 
-    pub fn destroyed_signal() -> Signal {
-        signal!("destroyed(QObject*)")
+    pub fn destroyed_signal() -> TypedSignal<(*mut QObject,)> {
+        unsafe { typed_signal!("destroyed(QObject*)") }
     }
 
-    pub fn delete_later_slot() -> Slot {
-        slot!("deleteLater()")
+    pub fn delete_later_slot() -> TypedSlot<()> {
+        unsafe { typed_slot!("deleteLater()") }
     }
 }
 
@@ -206,6 +306,145 @@ impl Slot {
     }
 }
 
+impl SignalArguments {
+    /// Get reference to the signal argument at `index`.
+    ///
+    /// # Safety
+    ///
+    /// `T` must be the type of the signal argument and `index` must be lower than the number of
+    /// arguments of the signal.
+    pub unsafe fn get<T>(&self, index: usize) -> &T {
+        &*(*self.0.add(index + 1) as *const T)
+    }
+}
+
+impl<Args> TypedSignal<Args> {
+    /// Create from raw meta method name (Starts with `2`).
+    ///
+    /// # Safety
+    ///
+    /// `Args` must match the argument types of the signal.
+    pub unsafe fn from_raw<T: Into<Cow<'static, CStr>>>(value: T) -> Self {
+        Self {
+            signal: Signal::from_raw(value),
+            _args: PhantomData,
+        }
+    }
+
+    pub fn as_cstr(&self) -> &CStr {
+        self.signal.as_cstr()
+    }
+}
+
+impl<Args> Clone for TypedSignal<Args> {
+    fn clone(&self) -> Self {
+        Self {
+            signal: self.signal.clone(),
+            _args: PhantomData,
+        }
+    }
+}
+
+impl<Args> From<TypedSignal<Args>> for Signal {
+    fn from(value: TypedSignal<Args>) -> Self {
+        value.signal
+    }
+}
+
+impl<Args> From<TypedSignal<Args>> for Slot {
+    fn from(value: TypedSignal<Args>) -> Self {
+        value.signal.into()
+    }
+}
+
+impl<Args> From<TypedSignal<Args>> for TypedSlot<Args> {
+    fn from(value: TypedSignal<Args>) -> Self {
+        Self {
+            slot: value.signal.into(),
+            _args: PhantomData,
+        }
+    }
+}
+
+impl<Args> TypedSlot<Args> {
+    /// Create from raw meta method name (Starts with `1`).
+    ///
+    /// # Safety
+    ///
+    /// `Args` must match the argument types of the slot.
+    pub unsafe fn from_raw<T: Into<Cow<'static, CStr>>>(value: T) -> Self {
+        Self {
+            slot: Slot::from_raw(value),
+            _args: PhantomData,
+        }
+    }
+
+    pub fn as_cstr(&self) -> &CStr {
+        self.slot.as_cstr()
+    }
+}
+
+impl<Args> Clone for TypedSlot<Args> {
+    fn clone(&self) -> Self {
+        Self {
+            slot: self.slot.clone(),
+            _args: PhantomData,
+        }
+    }
+}
+
+impl<Args> From<TypedSlot<Args>> for Slot {
+    fn from(value: TypedSlot<Args>) -> Self {
+        value.slot
+    }
+}
+
+macro_rules! impl_signal_args {
+    ($($ty:ident: $index:tt),*) => {
+        impl<$($ty: Clone),*> SignalArgs for ($($ty,)*) {
+            #[allow(unused_variables, clippy::unused_unit)]
+            unsafe fn from_signal_arguments(args: &SignalArguments) -> Self {
+                ($(args.get::<$ty>($index).clone(),)*)
+            }
+        }
+    };
+}
+
+impl_signal_args!();
+impl_signal_args!(A: 0);
+impl_signal_args!(A: 0, B: 1);
+impl_signal_args!(A: 0, B: 1, C: 2);
+impl_signal_args!(A: 0, B: 1, C: 2, D: 3);
+impl_signal_args!(A: 0, B: 1, C: 2, D: 3, E: 4);
+
+macro_rules! impl_compatible_args {
+    (($($signal:ident),*) => ($($slot:ident),*)) => {
+        impl<$($signal),*> CompatibleArgs<($($signal,)*)> for ($($slot,)*) {}
+    };
+}
+
+impl_compatible_args!(() => ());
+impl_compatible_args!((A) => ());
+impl_compatible_args!((A) => (A));
+impl_compatible_args!((A, B) => ());
+impl_compatible_args!((A, B) => (A));
+impl_compatible_args!((A, B) => (A, B));
+impl_compatible_args!((A, B, C) => ());
+impl_compatible_args!((A, B, C) => (A));
+impl_compatible_args!((A, B, C) => (A, B));
+impl_compatible_args!((A, B, C) => (A, B, C));
+impl_compatible_args!((A, B, C, D) => ());
+impl_compatible_args!((A, B, C, D) => (A));
+impl_compatible_args!((A, B, C, D) => (A, B));
+impl_compatible_args!((A, B, C, D) => (A, B, C));
+impl_compatible_args!((A, B, C, D) => (A, B, C, D));
+impl_compatible_args!((A, B, C, D, E) => ());
+impl_compatible_args!((A, B, C, D, E) => (A));
+impl_compatible_args!((A, B, C, D, E) => (A, B));
+impl_compatible_args!((A, B, C, D, E) => (A, B, C));
+impl_compatible_args!((A, B, C, D, E) => (A, B, C, D));
+impl_compatible_args!((A, B, C, D, E) => (A, B, C, D, E));
+
 impl From<Signal> for Slot {
     fn from(value: Signal) -> Self {
         Slot::from_raw(value.0)
@@ -247,6 +486,102 @@ pub trait QObjectRef {
         )
     }
 
+    /// Connect signal to a Rust closure.
+    ///
+    /// The closure is called directly in the thread that emits the signal, so it must only be
+    /// emitted in the thread that connected the closure. Otherwise the process is aborted,
+    /// because the closure is not required to be `Send`. Use `connect_fn_with_type` for other
+    /// threads and connection types.
+    ///
+    /// The closure is dropped when the connection is disconnected with `QObject::disconnect`
+    /// or the sender is destroyed. When this happens in another thread, the closure is leaked,
+    /// because its captured values may not be dropped there. A closure that panics aborts the
+    /// process.
+    fn connect_fn<R, F>(&self, signal: R, f: F) -> QMetaObjectConnection
+    where
+        R: Into<Signal>,
+        F: FnMut(&SignalArguments) + 'static,
+    {
+        self.as_qobject().connect_fn_internal(
+            signal.into().as_cstr(),
+            f,
+            Some(thread::current().id()),
+            ConnectionType::from(ConnectionTypeKind::Direct).into(),
+        )
+    }
+
+    /// Connect signal to a Rust closure with a connection type.
+    ///
+    /// The closure is called in the thread of the sender for queued connections, so it must be
+    /// `Send`. Calls from several threads through direct connections are serialized. The closure
+    /// is dropped when the connection is disconnected with `QObject::disconnect` or the sender
+    /// is destroyed. A closure that panics aborts the process.
+    fn connect_fn_with_type<R, F, T>(&self, signal: R, f: F, type_: T) -> QMetaObjectConnection
+    where
+        R: Into<Signal>,
+        F: FnMut(&SignalArguments) + Send + 'static,
+        T: Into<ConnectionType>,
+    {
+        let f = Mutex::new(f);
+        self.as_qobject().connect_fn_internal(
+            signal.into().as_cstr(),
+            move |args| (f.lock().unwrap())(args),
+            None,
+            type_.into().into(),
+        )
+    }
+
+    /// Connect signal to slot with compatible argument types.
+    fn connect_typed<A, S, T>(
+        &self,
+        signal: TypedSignal<A>,
+        receiver: &QObject,
+        method: TypedSlot<S>,
+        type_: T,
+    ) -> QMetaObjectConnection
+    where
+        S: CompatibleArgs<A>,
+        T: Into<ConnectionType>,
+    {
+        self.connect(signal, receiver, method, type_)
+    }
+
+    /// Connect signal to a Rust closure that gets the signal arguments.
+    ///
+    /// Same as `connect_fn`, but the arguments are copied into a tuple of the signal argument
+    /// types.
+    fn connect_typed_fn<A, F>(&self, signal: TypedSignal<A>, mut f: F) -> QMetaObjectConnection
+    where
+        A: SignalArgs,
+        F: FnMut(A) + 'static,
+    {
+        self.connect_fn(signal, move |args| {
+            f(unsafe { A::from_signal_arguments(args) })
+        })
+    }
+
+    /// Connect signal to a Rust closure that gets the signal arguments with a connection type.
+    ///
+    /// Same as `connect_fn_with_type`, but the arguments are copied into a tuple of the signal
+    /// argument types.
+    fn connect_typed_fn_with_type<A, F, T>(
+        &self,
+        signal: TypedSignal<A>,
+        mut f: F,
+        type_: T,
+    ) -> QMetaObjectConnection
+    where
+        A: SignalArgs,
+        F: FnMut(A) + Send + 'static,
+        T: Into<ConnectionType>,
+    {
+        self.connect_fn_with_type(
+            signal,
+            move |args| f(unsafe { A::from_signal_arguments(args) }),
+            type_,
+        )
+    }
+
     fn disconnect<R: Into<Signal>, S: Into<Slot>>(
         &self,
         signal: R,
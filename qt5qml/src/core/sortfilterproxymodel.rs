@@ -0,0 +1,202 @@
+use std::ffi::c_void;
+use std::os::raw::c_int;
+use std::ptr;
+
+use crate::core::{QAbstractItemModel, QModelIndex, QObject, QObjectRef, SortOrder};
+use crate::ffi::{init_ffi_struct, QffiWrapper};
+
+type FilterFn = dyn Fn(&QModelIndex) -> bool;
+type LessThanFn = dyn Fn(&QModelIndex, &QModelIndex) -> bool;
+
+#[derive(Default)]
+struct Callbacks {
+    filter: Option<Box<FilterFn>>,
+    less_than: Option<Box<LessThanFn>>,
+}
+
+/// `QSortFilterProxyModel` with filtering and sorting done by Rust closures.
+///
+/// Without a closure, the implementation of `QSortFilterProxyModel` is used. The model is
+/// deleted, when it is dropped.
+pub struct RustSortFilterProxyModel {
+    model: ptr::NonNull<crate::ffi::QffiSortFilterProxyModel>,
+    // owned by `model`
+    callbacks: *mut Callbacks,
+}
+
+unsafe extern "C" fn filter_accepts_row(
+    data: *const c_void,
+    source_index: *const QModelIndex,
+    result: *mut bool,
+) -> bool {
+    crate::panic::abort_on_panic(
+        "RustSortFilterProxyModel::filter_accepts_row",
+        || match &(*(data as *const Callbacks)).filter {
+            Some(filter) => {
+                *result = filter(&*source_index);
+                true
+            }
+            None => false,
+        },
+    )
+}
+
+unsafe extern "C" fn less_than(
+    data: *const c_void,
+    left: *const QModelIndex,
+    right: *const QModelIndex,
+    result: *mut bool,
+) -> bool {
+    crate::panic::abort_on_panic("RustSortFilterProxyModel::less_than", || {
+        match &(*(data as *const Callbacks)).less_than {
+            Some(less_than) => {
+                *result = less_than(&*left, &*right);
+                true
+            }
+            None => false,
+        }
+    })
+}
+
+unsafe extern "C" fn drop_callbacks(data: *mut c_void) {
+    crate::panic::abort_on_panic("RustSortFilterProxyModel::drop", || {
+        drop(Box::from_raw(data as *mut Callbacks))
+    })
+}
+
+impl RustSortFilterProxyModel {
+    pub fn new() -> Self {
+        let vtable = crate::ffi::QffiSortFilterProxyModelVTable {
+            filterAcceptsRow: Some(filter_accepts_row),
+            lessThan: Some(less_than),
+            drop: Some(drop_callbacks),
+        };
+
+        let callbacks = Box::into_raw(Box::new(Callbacks::default()));
+        let model = unsafe {
+            crate::ffi::qffi_QffiSortFilterProxyModel_init(
+                ptr::null_mut(),
+                callbacks as *mut c_void,
+                &vtable,
+            )
+        };
+        Self {
+            model: ptr::NonNull::new(model).unwrap(),
+            callbacks,
+        }
+    }
+
+    #[inline]
+    fn model(&mut self) -> *mut crate::ffi::QffiSortFilterProxyModel {
+        self.model.as_ptr()
+    }
+
+    #[inline]
+    pub fn as_item_model(&self) -> &QAbstractItemModel {
+        unsafe { &*(self.model.as_ptr() as *const QAbstractItemModel) }
+    }
+
+    #[inline]
+    pub fn as_item_model_mut(&mut self) -> &mut QAbstractItemModel {
+        unsafe { &mut *(self.model.as_ptr() as *mut QAbstractItemModel) }
+    }
+
+    /// Set the model to sort and filter.
+    ///
+    /// The proxy model does not take ownership of `source` and resets, when `source` is
+    /// deleted.
+    pub fn set_source_model(&mut self, source: &mut QAbstractItemModel) {
+        unsafe {
+            crate::ffi::qffi_QffiSortFilterProxyModel_setSourceModel(
+                self.model(),
+                source.to_inner_mut(),
+            )
+        }
+    }
+
+    pub fn source_model(&self) -> Option<&QAbstractItemModel> {
+        unsafe {
+            let source = crate::ffi::qffi_QffiSortFilterProxyModel_sourceModel(self.model.as_ptr());
+            (source as *const QAbstractItemModel).as_ref()
+        }
+    }
+
+    /// Filter the rows of the source model with `filter`.
+    ///
+    /// `filter` gets the source index of the first column of a row and returns `true`, if the
+    /// row is accepted. The source model is alive while `filter` is called, so the navigation
+    /// and data methods of the index can be used.
+    pub fn set_filter<F: Fn(&QModelIndex) -> bool + 'static>(&mut self, filter: F) {
+        unsafe { (*self.callbacks).filter = Some(Box::new(filter)) };
+        self.invalidate_filter();
+    }
+
+    /// Compare the source indexes with `less_than` when sorting.
+    pub fn set_less_than<F: Fn(&QModelIndex, &QModelIndex) -> bool + 'static>(
+        &mut self,
+        less_than: F,
+    ) {
+        unsafe { (*self.callbacks).less_than = Some(Box::new(less_than)) };
+        self.invalidate();
+    }
+
+    /// Apply the filter again, e.g. after state captured by the filter closure changed.
+    pub fn invalidate_filter(&mut self) {
+        unsafe { crate::ffi::qffi_QffiSortFilterProxyModel_invalidateFilter(self.model()) }
+    }
+
+    /// Apply sorting and filtering again.
+    pub fn invalidate(&mut self) {
+        unsafe { crate::ffi::qffi_QffiSortFilterProxyModel_invalidate(self.model()) }
+    }
+
+    pub fn sort(&mut self, column: i32, order: SortOrder) {
+        unsafe {
+            crate::ffi::qffi_QffiSortFilterProxyModel_sort(self.model(), column, order as c_int)
+        }
+    }
+
+    pub fn map_to_source(&self, proxy_index: &QModelIndex) -> QModelIndex {
+        init_ffi_struct(|result| unsafe {
+            crate::ffi::qffi_QffiSortFilterProxyModel_mapToSource(
+                self.model.as_ptr(),
+                proxy_index,
+                result,
+            )
+        })
+    }
+
+    pub fn map_from_source(&self, source_index: &QModelIndex) -> QModelIndex {
+        init_ffi_struct(|result| unsafe {
+            crate::ffi::qffi_QffiSortFilterProxyModel_mapFromSource(
+                self.model.as_ptr(),
+                source_index,
+                result,
+            )
+        })
+    }
+}
+
+impl Default for RustSortFilterProxyModel {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl QObjectRef for RustSortFilterProxyModel {
+    fn as_qobject_mut(&mut self) -> &mut QObject {
+        unsafe { &mut *(self.model.as_ptr() as *mut QObject) }
+    }
+
+    fn as_qobject(&self) -> &QObject {
+        unsafe { &*(self.model.as_ptr() as *const QObject) }
+    }
+}
+
+impl Drop for RustSortFilterProxyModel {
+    fn drop(&mut self) {
+        // also drops the callbacks
+        unsafe { self.delete() };
+    }
+}
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::ffi::{CStr, CString};
 use std::fmt::{self, Debug, Display};
+use std::hash::{Hash, Hasher};
 
 use crate::ffi;
 use crate::ffi::QffiWrapper;
@@ -105,6 +106,12 @@ impl Ord for QString {
     }
 }
 
+impl Hash for QString {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.utf16().hash(state)
+    }
+}
+
 impl From<String> for QString {
     #[inline]
     fn from(value: String) -> Self {
@@ -1,7 +1,7 @@
 use std::ptr;
 use std::time::Duration;
 
-use crate::core::{QObject, Signal, Slot};
+use crate::core::{QObject, TypedSignal, TypedSlot};
 use crate::ffi::QffiWrapper;
 use crate::QBox;
 
@@ -112,16 +112,16 @@ impl QTimer {
         unsafe { crate::ffi::qffi_QTimer_stop(&mut self.0) }
     }
 
-    pub fn start_slot() -> Slot {
-        slot!("start()")
+    pub fn start_slot() -> TypedSlot<()> {
+        unsafe { typed_slot!("start()") }
     }
 
-    pub fn stop_slot() -> Slot {
-        slot!("stop()")
+    pub fn stop_slot() -> TypedSlot<()> {
+        unsafe { typed_slot!("stop()") }
     }
 
     /// Note: Private signal
-    pub fn timeout_signal() -> Signal {
-        signal!("timeout()")
+    pub fn timeout_signal() -> TypedSignal<()> {
+        unsafe { typed_signal!("timeout()") }
     }
 }
@@ -0,0 +1,366 @@
+use std::ffi::c_void;
+use std::os::raw::c_int;
+use std::ptr;
+
+use crate::core::{
+    ItemFlags, Orientation, QAbstractItemModel, QByteArray, QHashIntQByteArray, QModelIndex,
+    QObject, QObjectRef, QVariant,
+};
+
+/// Item tree of a [`RustTreeModel`].
+///
+/// Items are identified by an internal id, which is stored in the indexes of the model.
+pub trait TreeModel {
+    /// Internal id of the item at `row` and `column` under `parent`, or `None` if there is no
+    /// such item.
+    fn index(&self, row: i32, column: i32, parent: &QModelIndex) -> Option<usize>;
+
+    /// Row and internal id of the parent item of `index`, or `None` for top-level items.
+    fn parent(&self, index: &QModelIndex) -> Option<(i32, usize)>;
+
+    fn row_count(&self, parent: &QModelIndex) -> i32;
+
+    fn column_count(&self, _parent: &QModelIndex) -> i32 {
+        1
+    }
+
+    fn data(&self, index: &QModelIndex, role: i32) -> QVariant;
+
+    /// Set the data of `index` and return `true`, if the data was changed.
+    ///
+    /// `dataChanged` is emitted by the model.
+    fn set_data(&mut self, _index: &QModelIndex, _value: &QVariant, _role: i32) -> bool {
+        false
+    }
+
+    fn flags(&self, _index: &QModelIndex) -> ItemFlags {
+        ItemFlags::ITEM_IS_SELECTABLE | ItemFlags::ITEM_IS_ENABLED
+    }
+
+    fn header_data(&self, _section: i32, _orientation: Orientation, _role: i32) -> QVariant {
+        QVariant::new()
+    }
+
+    /// Role names of the model. The default role names of Qt are used, when empty.
+    fn role_names(&self) -> Vec<(i32, &'static str)> {
+        vec![]
+    }
+}
+
+/// Item model backed by a [`TreeModel`].
+///
+/// Structural changes are done in closures passed to the model, so that attached views are
+/// notified. The model is a `QAbstractItemModel` and deleted, when it is dropped.
+pub struct RustTreeModel<T: TreeModel> {
+    model: ptr::NonNull<crate::ffi::QffiItemModel>,
+    // owned by `model`
+    tree: *mut T,
+}
+
+unsafe extern "C" fn index<T: TreeModel>(
+    data: *const c_void,
+    row: c_int,
+    column: c_int,
+    parent: *const QModelIndex,
+    id: *mut usize,
+) -> bool {
+    crate::panic::abort_on_panic("RustTreeModel::index", || {
+        match (*(data as *const T)).index(row, column, &*parent) {
+            Some(value) => {
+                *id = value;
+                true
+            }
+            None => false,
+        }
+    })
+}
+
+unsafe extern "C" fn parent<T: TreeModel>(
+    data: *const c_void,
+    index: *const QModelIndex,
+    row: *mut c_int,
+    id: *mut usize,
+) -> bool {
+    crate::panic::abort_on_panic("RustTreeModel::parent", || {
+        match (*(data as *const T)).parent(&*index) {
+            Some((parent_row, parent_id)) => {
+                *row = parent_row;
+                *id = parent_id;
+                true
+            }
+            None => false,
+        }
+    })
+}
+
+unsafe extern "C" fn row_count<T: TreeModel>(
+    data: *const c_void,
+    parent: *const QModelIndex,
+) -> c_int {
+    crate::panic::abort_on_panic("RustTreeModel::row_count", || {
+        (*(data as *const T)).row_count(&*parent)
+    })
+}
+
+unsafe extern "C" fn column_count<T: TreeModel>(
+    data: *const c_void,
+    parent: *const QModelIndex,
+) -> c_int {
+    crate::panic::abort_on_panic("RustTreeModel::column_count", || {
+        (*(data as *const T)).column_count(&*parent)
+    })
+}
+
+unsafe extern "C" fn item_data<T: TreeModel>(
+    data: *const c_void,
+    index: *const QModelIndex,
+    role: c_int,
+    result: *mut crate::ffi::QVariant,
+) {
+    crate::panic::abort_on_panic("RustTreeModel::data", || {
+        *(result as *mut QVariant) = (*(data as *const T)).data(&*index, role);
+    })
+}
+
+unsafe extern "C" fn set_data<T: TreeModel>(
+    data: *mut c_void,
+    index: *const QModelIndex,
+    value: *const crate::ffi::QVariant,
+    role: c_int,
+) -> bool {
+    crate::panic::abort_on_panic("RustTreeModel::set_data", || {
+        (*(data as *mut T)).set_data(&*index, &*(value as *const QVariant), role)
+    })
+}
+
+unsafe extern "C" fn flags<T: TreeModel>(data: *const c_void, index: *const QModelIndex) -> c_int {
+    crate::panic::abort_on_panic("RustTreeModel::flags", || {
+        (*(data as *const T)).flags(&*index).bits()
+    })
+}
+
+unsafe extern "C" fn header_data<T: TreeModel>(
+    data: *const c_void,
+    section: c_int,
+    orientation: c_int,
+    role: c_int,
+    result: *mut crate::ffi::QVariant,
+) {
+    crate::panic::abort_on_panic("RustTreeModel::header_data", || {
+        let orientation = Orientation::from_value(orientation);
+        *(result as *mut QVariant) = (*(data as *const T)).header_data(section, orientation, role);
+    })
+}
+
+unsafe extern "C" fn role_names<T: TreeModel>(
+    data: *const c_void,
+    result: *mut crate::ffi::QHashIntQByteArray,
+) {
+    crate::panic::abort_on_panic("RustTreeModel::role_names", || {
+        let result = &mut *(result as *mut QHashIntQByteArray);
+        for (role, name) in (*(data as *const T)).role_names() {
+            result.insert(&role, &QByteArray::from(name));
+        }
+    })
+}
+
+unsafe extern "C" fn drop_tree<T: TreeModel>(data: *mut c_void) {
+    crate::panic::abort_on_panic("RustTreeModel::drop", || {
+        drop(Box::from_raw(data as *mut T))
+    })
+}
+
+impl<T: TreeModel> RustTreeModel<T> {
+    pub fn new(tree: T) -> Self {
+        let vtable = crate::ffi::QffiItemModelVTable {
+            index: Some(index::<T>),
+            parent: Some(parent::<T>),
+            rowCount: Some(row_count::<T>),
+            columnCount: Some(column_count::<T>),
+            data: Some(item_data::<T>),
+            setData: Some(set_data::<T>),
+            flags: Some(flags::<T>),
+            headerData: Some(header_data::<T>),
+            roleNames: Some(role_names::<T>),
+            drop: Some(drop_tree::<T>),
+        };
+
+        let tree = Box::into_raw(Box::new(tree));
+        let model = unsafe {
+            crate::ffi::qffi_QffiItemModel_init(ptr::null_mut(), tree as *mut c_void, &vtable)
+        };
+        Self {
+            model: ptr::NonNull::new(model).unwrap(),
+            tree,
+        }
+    }
+
+    #[inline]
+    fn model(&mut self) -> *mut crate::ffi::QffiItemModel {
+        self.model.as_ptr()
+    }
+
+    #[inline]
+    pub fn get(&self) -> &T {
+        unsafe { &*self.tree }
+    }
+
+    #[inline]
+    pub fn as_item_model(&self) -> &QAbstractItemModel {
+        unsafe { &*(self.model.as_ptr() as *const QAbstractItemModel) }
+    }
+
+    #[inline]
+    pub fn as_item_model_mut(&mut self) -> &mut QAbstractItemModel {
+        unsafe { &mut *(self.model.as_ptr() as *mut QAbstractItemModel) }
+    }
+
+    /// Index of the item with internal id `id`.
+    ///
+    /// The index is not checked against the tree.
+    pub fn create_index(&self, row: i32, column: i32, id: usize) -> QModelIndex {
+        QModelIndex::new_(
+            row,
+            column,
+            id as *mut c_void,
+            self.model.as_ptr() as *const _,
+        )
+    }
+
+    /// Index of the item at `row` and `column` under `parent`.
+    pub fn index(&self, row: i32, column: i32, parent: &QModelIndex) -> QModelIndex {
+        match self.get().index(row, column, parent) {
+            Some(id) if row >= 0 && column >= 0 => self.create_index(row, column, id),
+            _ => QModelIndex::new(),
+        }
+    }
+
+    /// Insert the rows `first` to `last` under `parent` in `f`.
+    pub fn insert_rows<F: FnOnce(&mut T)>(
+        &mut self,
+        parent: &QModelIndex,
+        first: i32,
+        last: i32,
+        f: F,
+    ) {
+        unsafe {
+            crate::ffi::qffi_QffiItemModel_beginInsertRows(self.model(), parent, first, last);
+            f(&mut *self.tree);
+            crate::ffi::qffi_QffiItemModel_endInsertRows(self.model());
+        }
+    }
+
+    /// Remove the rows `first` to `last` under `parent` in `f`.
+    pub fn remove_rows<F: FnOnce(&mut T)>(
+        &mut self,
+        parent: &QModelIndex,
+        first: i32,
+        last: i32,
+        f: F,
+    ) {
+        unsafe {
+            crate::ffi::qffi_QffiItemModel_beginRemoveRows(self.model(), parent, first, last);
+            f(&mut *self.tree);
+            crate::ffi::qffi_QffiItemModel_endRemoveRows(self.model());
+        }
+    }
+
+    /// Move the rows `first` to `last` under `source_parent` before the row `destination`
+    /// under `destination_parent` in `f`.
+    ///
+    /// Returns `false` without calling `f`, if the move is invalid.
+    pub fn move_rows<F: FnOnce(&mut T)>(
+        &mut self,
+        source_parent: &QModelIndex,
+        first: i32,
+        last: i32,
+        destination_parent: &QModelIndex,
+        destination: i32,
+        f: F,
+    ) -> bool {
+        unsafe {
+            if !crate::ffi::qffi_QffiItemModel_beginMoveRows(
+                self.model(),
+                source_parent,
+                first,
+                last,
+                destination_parent,
+                destination,
+            ) {
+                return false;
+            }
+            f(&mut *self.tree);
+            crate::ffi::qffi_QffiItemModel_endMoveRows(self.model());
+        }
+        true
+    }
+
+    /// Insert the columns `first` to `last` under `parent` in `f`.
+    pub fn insert_columns<F: FnOnce(&mut T)>(
+        &mut self,
+        parent: &QModelIndex,
+        first: i32,
+        last: i32,
+        f: F,
+    ) {
+        unsafe {
+            crate::ffi::qffi_QffiItemModel_beginInsertColumns(self.model(), parent, first, last);
+            f(&mut *self.tree);
+            crate::ffi::qffi_QffiItemModel_endInsertColumns(self.model());
+        }
+    }
+
+    /// Remove the columns `first` to `last` under `parent` in `f`.
+    pub fn remove_columns<F: FnOnce(&mut T)>(
+        &mut self,
+        parent: &QModelIndex,
+        first: i32,
+        last: i32,
+        f: F,
+    ) {
+        unsafe {
+            crate::ffi::qffi_QffiItemModel_beginRemoveColumns(self.model(), parent, first, last);
+            f(&mut *self.tree);
+            crate::ffi::qffi_QffiItemModel_endRemoveColumns(self.model());
+        }
+    }
+
+    /// Change the data of the items from `top_left` to `bottom_right` in `f`.
+    pub fn update<F: FnOnce(&mut T)>(
+        &mut self,
+        top_left: &QModelIndex,
+        bottom_right: &QModelIndex,
+        f: F,
+    ) {
+        unsafe {
+            f(&mut *self.tree);
+            crate::ffi::qffi_QffiItemModel_dataChanged(self.model(), top_left, bottom_right);
+        }
+    }
+
+    /// Change the tree arbitrarily in `f` and reset the model.
+    pub fn reset<F: FnOnce(&mut T)>(&mut self, f: F) {
+        unsafe {
+            crate::ffi::qffi_QffiItemModel_beginResetModel(self.model());
+            f(&mut *self.tree);
+            crate::ffi::qffi_QffiItemModel_endResetModel(self.model());
+        }
+    }
+}
+
+impl<T: TreeModel> QObjectRef for RustTreeModel<T> {
+    fn as_qobject_mut(&mut self) -> &mut QObject {
+        unsafe { &mut *(self.model.as_ptr() as *mut QObject) }
+    }
+
+    fn as_qobject(&self) -> &QObject {
+        unsafe { &*(self.model.as_ptr() as *const QObject) }
+    }
+}
+
+impl<T: TreeModel> Drop for RustTreeModel<T> {
+    fn drop(&mut self) {
+        // also drops the tree
+        unsafe { self.delete() };
+    }
+}
@@ -0,0 +1,392 @@
+use std::fmt;
+use std::iter::FromIterator;
+use std::os::raw::c_int;
+
+use crate::ffi::QffiWrapper;
+
+/// Element type of [`QVector`].
+///
+/// Implemented for the element types listed in `bindgen.yml`.
+///
+/// # Safety
+///
+/// The FFI functions must operate on the C++ container with the element type `Self`.
+pub unsafe trait QVectorItem: Sized {
+    #[doc(hidden)]
+    type QffiVector: Default + Clone + PartialEq;
+
+    #[doc(hidden)]
+    unsafe fn qffi_size(vector: &Self::QffiVector) -> c_int;
+    #[doc(hidden)]
+    unsafe fn qffi_as_slice(vector: &Self::QffiVector, size: &mut c_int) -> *const Self;
+    #[doc(hidden)]
+    unsafe fn qffi_append(vector: &mut Self::QffiVector, item: &Self);
+    #[doc(hidden)]
+    unsafe fn qffi_append_slice(vector: &mut Self::QffiVector, items: &[Self]);
+    #[doc(hidden)]
+    unsafe fn qffi_reserve_additional(vector: &mut Self::QffiVector, additional: c_int);
+}
+
+/// `QVector<T>`
+#[repr(transparent)]
+pub struct QVector<T: QVectorItem>(T::QffiVector);
+
+impl<T: QVectorItem> QffiWrapper for QVector<T> {
+    type QffiObject = T::QffiVector;
+
+    fn create_from_ffi_object(value: T::QffiVector) -> Self {
+        Self(value)
+    }
+
+    fn to_inner(&self) -> &Self::QffiObject {
+        &self.0
+    }
+
+    fn to_inner_mut(&mut self) -> &mut Self::QffiObject {
+        &mut self.0
+    }
+}
+
+impl<T: QVectorItem> QVector<T> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn len(&self) -> i32 {
+        unsafe { T::qffi_size(self.to_inner()) }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        unsafe {
+            let mut size: c_int = 0;
+            let ptr = T::qffi_as_slice(self.to_inner(), &mut size);
+            if size != 0 {
+                std::slice::from_raw_parts(ptr, size as usize)
+            } else {
+                &[]
+            }
+        }
+    }
+
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    pub fn push(&mut self, item: &T) {
+        unsafe { T::qffi_append(self.to_inner_mut(), item) }
+    }
+
+    pub fn extend_from_slice(&mut self, slice: &[T]) {
+        unsafe { T::qffi_append_slice(self.to_inner_mut(), slice) }
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        unsafe { T::qffi_reserve_additional(self.to_inner_mut(), additional as c_int) }
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.as_slice().to_vec()
+    }
+}
+
+impl<T: QVectorItem> Default for QVector<T> {
+    #[inline]
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+impl<T: QVectorItem> Clone for QVector<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: QVectorItem> PartialEq for QVector<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: QVectorItem + Eq> Eq for QVector<T> {}
+
+impl<T: QVectorItem + fmt::Debug> fmt::Debug for QVector<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: QVectorItem> Extend<T> for QVector<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(&item);
+        }
+    }
+}
+
+impl<'a, T: QVectorItem + 'a> Extend<&'a T> for QVector<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T: QVectorItem> FromIterator<T> for QVector<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut res = Self::default();
+        res.extend(iter);
+        res
+    }
+}
+
+impl<'a, T: QVectorItem> IntoIterator for &'a QVector<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: QVectorItem> From<&[T]> for QVector<T> {
+    fn from(value: &[T]) -> Self {
+        let mut res = Self::default();
+        res.extend_from_slice(value);
+        res
+    }
+}
+
+impl<T: QVectorItem> From<Vec<T>> for QVector<T> {
+    fn from(value: Vec<T>) -> Self {
+        Self::from(value.as_slice())
+    }
+}
+
+impl<T: QVectorItem + Clone> From<&QVector<T>> for Vec<T> {
+    fn from(value: &QVector<T>) -> Self {
+        value.to_vec()
+    }
+}
+
+unsafe impl QVectorItem for bool {
+    type QffiVector = crate::ffi::QVectorBool;
+
+    #[inline]
+    unsafe fn qffi_size(vector: &Self::QffiVector) -> c_int {
+        crate::ffi::qffi_QVectorBool_size(vector)
+    }
+
+    #[inline]
+    unsafe fn qffi_as_slice(vector: &Self::QffiVector, size: &mut c_int) -> *const Self {
+        crate::ffi::qffi_QVectorBool_asSlice(vector, size).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_append(vector: &mut Self::QffiVector, item: &Self) {
+        crate::ffi::qffi_QVectorBool_append(vector, (item as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_append_slice(vector: &mut Self::QffiVector, items: &[Self]) {
+        crate::ffi::qffi_QVectorBool_appendSlice(vector, items.as_ptr().cast(), items.len() as c_int)
+    }
+
+    #[inline]
+    unsafe fn qffi_reserve_additional(vector: &mut Self::QffiVector, additional: c_int) {
+        crate::ffi::qffi_QVectorBool_reserveAdditional(vector, additional)
+    }
+}
+
+unsafe impl QVectorItem for i32 {
+    type QffiVector = crate::ffi::QVectorInt;
+
+    #[inline]
+    unsafe fn qffi_size(vector: &Self::QffiVector) -> c_int {
+        crate::ffi::qffi_QVectorInt_size(vector)
+    }
+
+    #[inline]
+    unsafe fn qffi_as_slice(vector: &Self::QffiVector, size: &mut c_int) -> *const Self {
+        crate::ffi::qffi_QVectorInt_asSlice(vector, size).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_append(vector: &mut Self::QffiVector, item: &Self) {
+        crate::ffi::qffi_QVectorInt_append(vector, (item as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_append_slice(vector: &mut Self::QffiVector, items: &[Self]) {
+        crate::ffi::qffi_QVectorInt_appendSlice(vector, items.as_ptr().cast(), items.len() as c_int)
+    }
+
+    #[inline]
+    unsafe fn qffi_reserve_additional(vector: &mut Self::QffiVector, additional: c_int) {
+        crate::ffi::qffi_QVectorInt_reserveAdditional(vector, additional)
+    }
+}
+
+unsafe impl QVectorItem for i64 {
+    type QffiVector = crate::ffi::QVectorLongLong;
+
+    #[inline]
+    unsafe fn qffi_size(vector: &Self::QffiVector) -> c_int {
+        crate::ffi::qffi_QVectorLongLong_size(vector)
+    }
+
+    #[inline]
+    unsafe fn qffi_as_slice(vector: &Self::QffiVector, size: &mut c_int) -> *const Self {
+        crate::ffi::qffi_QVectorLongLong_asSlice(vector, size).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_append(vector: &mut Self::QffiVector, item: &Self) {
+        crate::ffi::qffi_QVectorLongLong_append(vector, (item as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_append_slice(vector: &mut Self::QffiVector, items: &[Self]) {
+        crate::ffi::qffi_QVectorLongLong_appendSlice(vector, items.as_ptr().cast(), items.len() as c_int)
+    }
+
+    #[inline]
+    unsafe fn qffi_reserve_additional(vector: &mut Self::QffiVector, additional: c_int) {
+        crate::ffi::qffi_QVectorLongLong_reserveAdditional(vector, additional)
+    }
+}
+
+unsafe impl QVectorItem for f64 {
+    type QffiVector = crate::ffi::QVectorDouble;
+
+    #[inline]
+    unsafe fn qffi_size(vector: &Self::QffiVector) -> c_int {
+        crate::ffi::qffi_QVectorDouble_size(vector)
+    }
+
+    #[inline]
+    unsafe fn qffi_as_slice(vector: &Self::QffiVector, size: &mut c_int) -> *const Self {
+        crate::ffi::qffi_QVectorDouble_asSlice(vector, size).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_append(vector: &mut Self::QffiVector, item: &Self) {
+        crate::ffi::qffi_QVectorDouble_append(vector, (item as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_append_slice(vector: &mut Self::QffiVector, items: &[Self]) {
+        crate::ffi::qffi_QVectorDouble_appendSlice(vector, items.as_ptr().cast(), items.len() as c_int)
+    }
+
+    #[inline]
+    unsafe fn qffi_reserve_additional(vector: &mut Self::QffiVector, additional: c_int) {
+        crate::ffi::qffi_QVectorDouble_reserveAdditional(vector, additional)
+    }
+}
+
+unsafe impl QVectorItem for crate::core::QString {
+    type QffiVector = crate::ffi::QVectorQString;
+
+    #[inline]
+    unsafe fn qffi_size(vector: &Self::QffiVector) -> c_int {
+        crate::ffi::qffi_QVectorQString_size(vector)
+    }
+
+    #[inline]
+    unsafe fn qffi_as_slice(vector: &Self::QffiVector, size: &mut c_int) -> *const Self {
+        crate::ffi::qffi_QVectorQString_asSlice(vector, size).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_append(vector: &mut Self::QffiVector, item: &Self) {
+        crate::ffi::qffi_QVectorQString_append(vector, (item as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_append_slice(vector: &mut Self::QffiVector, items: &[Self]) {
+        crate::ffi::qffi_QVectorQString_appendSlice(vector, items.as_ptr().cast(), items.len() as c_int)
+    }
+
+    #[inline]
+    unsafe fn qffi_reserve_additional(vector: &mut Self::QffiVector, additional: c_int) {
+        crate::ffi::qffi_QVectorQString_reserveAdditional(vector, additional)
+    }
+}
+
+unsafe impl QVectorItem for crate::core::QByteArray {
+    type QffiVector = crate::ffi::QVectorQByteArray;
+
+    #[inline]
+    unsafe fn qffi_size(vector: &Self::QffiVector) -> c_int {
+        crate::ffi::qffi_QVectorQByteArray_size(vector)
+    }
+
+    #[inline]
+    unsafe fn qffi_as_slice(vector: &Self::QffiVector, size: &mut c_int) -> *const Self {
+        crate::ffi::qffi_QVectorQByteArray_asSlice(vector, size).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_append(vector: &mut Self::QffiVector, item: &Self) {
+        crate::ffi::qffi_QVectorQByteArray_append(vector, (item as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_append_slice(vector: &mut Self::QffiVector, items: &[Self]) {
+        crate::ffi::qffi_QVectorQByteArray_appendSlice(vector, items.as_ptr().cast(), items.len() as c_int)
+    }
+
+    #[inline]
+    unsafe fn qffi_reserve_additional(vector: &mut Self::QffiVector, additional: c_int) {
+        crate::ffi::qffi_QVectorQByteArray_reserveAdditional(vector, additional)
+    }
+}
+
+unsafe impl QVectorItem for crate::core::QVariant {
+    type QffiVector = crate::ffi::QVectorQVariant;
+
+    #[inline]
+    unsafe fn qffi_size(vector: &Self::QffiVector) -> c_int {
+        crate::ffi::qffi_QVectorQVariant_size(vector)
+    }
+
+    #[inline]
+    unsafe fn qffi_as_slice(vector: &Self::QffiVector, size: &mut c_int) -> *const Self {
+        crate::ffi::qffi_QVectorQVariant_asSlice(vector, size).cast()
+    }
+
+    #[inline]
+    unsafe fn qffi_append(vector: &mut Self::QffiVector, item: &Self) {
+        crate::ffi::qffi_QVectorQVariant_append(vector, (item as *const Self).cast())
+    }
+
+    #[inline]
+    unsafe fn qffi_append_slice(vector: &mut Self::QffiVector, items: &[Self]) {
+        crate::ffi::qffi_QVectorQVariant_appendSlice(vector, items.as_ptr().cast(), items.len() as c_int)
+    }
+
+    #[inline]
+    unsafe fn qffi_reserve_additional(vector: &mut Self::QffiVector, additional: c_int) {
+        crate::ffi::qffi_QVectorQVariant_reserveAdditional(vector, additional)
+    }
+}
+
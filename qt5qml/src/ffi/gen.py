@@ -18,6 +18,20 @@ class MethodKind(str, Enum):
 class QList(BaseModel):
     cpp: str
     rs: str
+    slice: bool = False
+    overwrite_name: Optional[str] = Field(default=None, alias="overwrite-name")
+
+
+class QMap(BaseModel):
+    key: QList
+    value: QList
+
+
+class Containers(BaseModel):
+    qlist: List[str] = Field(default_factory=list, alias="QList")
+    qvector: List[str] = Field(default_factory=list, alias="QVector")
+    qmap: List[Tuple[str, str]] = Field(default_factory=list, alias="QMap")
+    qhash: List[Tuple[str, str]] = Field(default_factory=list, alias="QHash")
 
 
 class Method(BaseModel):
@@ -58,6 +72,11 @@ class BindgenConfig(BaseModel):
     classes: Dict[str, Class]
     includes: List[str] = Field(default_factory=list)
     qlists: Dict[str, QList] = Field(default_factory=dict)
+    qvectors: Dict[str, QList] = Field(default_factory=dict)
+    qmaps: Dict[str, QMap] = Field(default_factory=dict)
+    qhashes: Dict[str, QMap] = Field(default_factory=dict)
+    container_items: Dict[str, QList] = Field(default_factory=dict, alias="container-items")
+    containers: Containers = Field(default_factory=Containers)
 
 
 class Check:
@@ -112,9 +131,10 @@ class ReturnNonTrivialClass(Check):
                     name, method, f"C API does not allow to return non-trivially C++ type {method.return_}")
 
 
-def handle_qlist(name: str, ty: QList):
+def container_class(include: str, overwrite_name: Optional[str], methods: dict):
     return {
-        "overwrite-include": "QList",
+        "overwrite-include": include,
+        "overwrite-name": overwrite_name,
         "default-ctor": "true",
         "copy-ctor": "true",
         "copy-assign": "true",
@@ -123,48 +143,168 @@ def handle_qlist(name: str, ty: QList):
 
         "layout": dict(__d="void*"),
 
-        "methods": {
-            "size": {
-                "const": True,
-                "return": "int",
-                "body": "return self->size();"
+        "methods": methods,
+    }
+
+
+def handle_qlist(name: str, ty: QList):
+    methods = {
+        "size": {
+            "const": True,
+            "return": "int",
+            "body": "return self->size();"
+        },
+        "at": {
+            "const": True,
+            "params": {
+                "index": "int"
             },
-            "asSlice": {
-                "const": True,
-                "params": {
-                    "size": "int*"
-                },
-                "return": f"{ty.cpp} const*",
-                "body": "*size = self->size(); if (size == 0) { return nullptr; } else { return & self->front(); }"
+            "return": f"{ty.cpp} const*",
+            "body": "return &self->at(index);"
+        },
+    }
+    if ty.slice:
+        methods["asSlice"] = {
+            "const": True,
+            "params": {
+                "size": "int*"
             },
-            "append": {
-                "params": {
-                    "item": f"{ty.cpp} const*"
-                },
-                "body": "self->append(*item);"
+            "return": f"{ty.cpp} const*",
+            "body": "*size = self->size(); if (*size == 0) { return nullptr; } else { return & self->front(); }"
+        }
+    methods.update({
+        "append": {
+            "params": {
+                "item": f"{ty.cpp} const*"
             },
-            "appendList": {
-                "params": {
-                    "item": f"QList<{ty.cpp}> const*"
-                },
-                "body": "self->append(*item);"
+            "body": "self->append(*item);"
+        },
+        "appendList": {
+            "params": {
+                "item": f"QList<{ty.cpp}> const*"
             },
-            "appendSlice": {
-                "params": {
-                    "items": f"{ty.cpp} const*",
-                    "size": "int"
-                },
-                "body": "self->reserve(self->size() + size); "
-                        "for (int i = 0; i < size; ++i) { self->push_back(items[i]); }"
+            "body": "self->append(*item);"
+        },
+        "appendSlice": {
+            "params": {
+                "items": f"{ty.cpp} const*",
+                "size": "int"
             },
-            "reserveAdditional": {
-                "params": {
-                    "additional": "int"
-                },
-                "body": "self->reserve(self->size() + additional);"
+            "body": "self->reserve(self->size() + size); "
+                    "for (int i = 0; i < size; ++i) { self->push_back(items[i]); }"
+        },
+        "reserveAdditional": {
+            "params": {
+                "additional": "int"
             },
-        }
-    }
+            "body": "self->reserve(self->size() + additional);"
+        },
+    })
+    return container_class("QList", ty.overwrite_name, methods)
+
+
+def handle_qvector(name: str, ty: QList):
+    return container_class("QVector", ty.overwrite_name, {
+        "size": {
+            "const": True,
+            "return": "int",
+            "body": "return self->size();"
+        },
+        "asSlice": {
+            "const": True,
+            "params": {
+                "size": "int*"
+            },
+            "return": f"{ty.cpp} const*",
+            "body": "*size = self->size(); return self->constData();"
+        },
+        "append": {
+            "params": {
+                "item": f"{ty.cpp} const*"
+            },
+            "body": "self->append(*item);"
+        },
+        "appendSlice": {
+            "params": {
+                "items": f"{ty.cpp} const*",
+                "size": "int"
+            },
+            "body": "self->reserve(self->size() + size); "
+                    "for (int i = 0; i < size; ++i) { self->push_back(items[i]); }"
+        },
+        "reserveAdditional": {
+            "params": {
+                "additional": "int"
+            },
+            "body": "self->reserve(self->size() + additional);"
+        },
+    })
+
+
+def handle_qmap(include: str, name: str, ty: QMap):
+    return container_class(include, f"{include}<{ty.key.cpp}, {ty.value.cpp}>", {
+        "size": {
+            "const": True,
+            "return": "int",
+            "body": "return self->size();"
+        },
+        "contains": {
+            "const": True,
+            "params": {
+                "key": f"{ty.key.cpp} const*"
+            },
+            "return": "bool",
+            "body": "return self->contains(*key);"
+        },
+        "value": {
+            "const": True,
+            "params": {
+                "key": f"{ty.key.cpp} const*"
+            },
+            "return": f"{ty.value.cpp} const*",
+            "body": "auto it = self->constFind(*key); return it == self->cend() ? nullptr : &it.value();"
+        },
+        "insert": {
+            "params": {
+                "key": f"{ty.key.cpp} const*",
+                "value": f"{ty.value.cpp} const*"
+            },
+            "body": "self->insert(*key, *value);"
+        },
+        "remove": {
+            "params": {
+                "key": f"{ty.key.cpp} const*"
+            },
+            "return": "int",
+            "body": "return self->remove(*key);"
+        },
+        "forEach": {
+            "const": True,
+            "params": {
+                "data": "void*",
+                "call": "QffiMapEntryCall"
+            },
+            "body": "for (auto it = self->cbegin(); it != self->cend(); ++it) { call(data, &it.key(), &it.value()); }"
+        },
+    })
+
+
+def expand_containers(config: BindgenConfig):
+    items = config.container_items
+    containers = config.containers
+
+    for item in containers.qlist:
+        ty = items[item]
+        config.qlists[f"QList{item}"] = QList(
+            cpp=ty.cpp, rs=ty.rs, slice=ty.slice, **{"overwrite-name": f"QList<{ty.cpp}>"})
+    for item in containers.qvector:
+        ty = items[item]
+        config.qvectors[f"QVector{item}"] = QList(
+            cpp=ty.cpp, rs=ty.rs, **{"overwrite-name": f"QVector<{ty.cpp}>"})
+    for key, value in containers.qmap:
+        config.qmaps[f"QMap{key}{value}"] = QMap(key=items[key], value=items[value])
+    for key, value in containers.qhash:
+        config.qhashes[f"QHash{key}{value}"] = QMap(key=items[key], value=items[value])
 
 
 def generate():
@@ -179,13 +319,23 @@ def generate():
     qffi_template_cpp = env.get_template("qffi_template.cpp.j2")
     qffi_template_rs = env.get_template("qffi_impl_template.rs.j2")
     qlists_template_rs = env.get_template("list_template.rs.j2")
+    qvectors_template_rs = env.get_template("vector_template.rs.j2")
+    qmaps_template_rs = env.get_template("map_template.rs.j2")
     bindgen_template = env.get_template("bindgen.yml")
 
     bindgen = bindgen_template.render({})
     config = BindgenConfig(**yaml.safe_load(bindgen))
 
+    expand_containers(config)
+
     for name, ty in config.qlists.items():
         config.classes[name] = Class(**handle_qlist(name, ty))
+    for name, ty in config.qvectors.items():
+        config.classes[name] = Class(**handle_qvector(name, ty))
+    for name, ty in config.qmaps.items():
+        config.classes[name] = Class(**handle_qmap("QMap", name, ty))
+    for name, ty in config.qhashes.items():
+        config.classes[name] = Class(**handle_qmap("QHash", name, ty))
 
     check_config([ReturnNonTrivialClass()], config)
 
@@ -195,6 +345,8 @@ def generate():
     (root / "qffi.cpp").write_text(qffi_template_cpp.render(config))
     (root / "qffi_impl.rs").write_text(qffi_template_rs.render(config))
     (core_root / "list.rs").write_text(qlists_template_rs.render(config))
+    (core_root / "vector.rs").write_text(qvectors_template_rs.render(config))
+    (core_root / "map.rs").write_text(qmaps_template_rs.render(config))
 
     with (root / "qffi.rs").open("w") as fp:
         subprocess.run(
@@ -3,6 +3,7 @@ use std::mem::MaybeUninit;
 pub(crate) use qffi::*;
 pub use qffi::{QObject, QTimer, QUrl};
 
+#[allow(non_snake_case)]
 mod qffi;
 mod qffi_impl;
 
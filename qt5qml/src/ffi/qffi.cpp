@@ -201,9 +201,15 @@ void qffi_QObject_connect(QObject const* _self, const char* signal, const QObjec
       self->connect(self, signal, receiver, method, Qt::ConnectionType(type_)));
 }
 
+void qffi_QObject_connectClosure(QObject const* _self, const char* signal, QffiClosureCall call, void* data, QffiClosureDrop drop, int type_, QMetaObjectConnection* result) {
+    auto* self = (QObject const*) _self;
+    qffi_call_ctor((QMetaObject::Connection*)result,
+      QffiClosureReceiver::connect(self, signal, call, data, drop, Qt::ConnectionType(type_)));
+}
+
 bool qffi_QObject_disconnectConnection(const QMetaObjectConnection* connection) {
     
-    return QObject::disconnect(*(const QMetaObject::Connection*)connection);
+    return QffiClosureReceiver::disconnect(*(const QMetaObject::Connection*)connection);
 }
 
 bool qffi_QObject_disconnect2(QObject const* _self, const QObject* receiver, const char* method) {
@@ -718,68 +724,289 @@ QGuiApplication* qffi_QGuiApplication_init(int* argc, char const** argv) {
 }
 
 
-// QHashIntQByteArray
+// QThread
 
-static_assert(alignof(QHash<int, QByteArray>) == alignof(QHashIntQByteArray), "Alignment of QHash<int, QByteArray> incompatible");
-static_assert(sizeof(QHash<int, QByteArray>) == sizeof(QHashIntQByteArray), "Size of QHash<int, QByteArray> incompatible");
 
 
-void qffi_QHashIntQByteArray_init(QHashIntQByteArray* self) {
-    ::qffi_call_default_ctor((QHash<int, QByteArray>*)self);
+
+
+
+// QQmlEngine
+QQmlEngine* qffi_QQmlEngine_init(QObject* parent) {
+    return (QQmlEngine*)new QQmlEngine(parent);
 }
 
 
-void qffi_QHashIntQByteArray_clone(QHashIntQByteArray const* self, QHashIntQByteArray* new_) {
-    new ((QHash<int, QByteArray>*)new_) QHash<int, QByteArray>(*(QHash<int, QByteArray> const*)self);
+
+
+
+QObject* qffi_QQmlEngine_singletonInstance(QQmlEngine * _self, int typeId) {
+    auto* self = (QQmlEngine *) _self;
+    return self->singletonInstance<QObject*>(typeId);
 }
 
-bool qffi_QHashIntQByteArray_equals(QHashIntQByteArray const* self, QHashIntQByteArray const* other) {
-    return *((QHash<int, QByteArray> const*)self) == *((QHash<int, QByteArray> const*)other);
+
+// QQmlApplicationEngine
+QQmlApplicationEngine* qffi_QQmlApplicationEngine_init(QObject* parent) {
+    return (QQmlApplicationEngine*)new QQmlApplicationEngine(parent);
 }
 
 
-int qffi_QHashIntQByteArray_size(QHashIntQByteArray const* _self) {
-    auto* self = (QHash<int, QByteArray> const*) _self;
-    return self->size();
+
+
+
+void qffi_QQmlApplicationEngine_load(QQmlApplicationEngine * _self, const QUrl* url) {
+    auto* self = (QQmlApplicationEngine *) _self;
+    self->load(*url);
 }
 
-void qffi_QHashIntQByteArray_insert(QHashIntQByteArray * _self, const int* key, const QByteArray* value) {
-    auto* self = (QHash<int, QByteArray> *) _self;
-    self->insert(*key, *value);
+void qffi_QQmlApplicationEngine_rootObjects(QQmlApplicationEngine * _self, QObjectList* result) {
+    auto* self = (QQmlApplicationEngine *) _self;
+    qffi_call_ctor(result, self->rootObjects());
+}
+
+void qffi_QQmlApplicationEngine_setContextProperty(QQmlApplicationEngine * _self, const QString* name, QObject* value) {
+    auto* self = (QQmlApplicationEngine *) _self;
+    self->rootContext()->setContextProperty(*name, value);
 }
 
 
-// QThread
+// QAbstractItemModel
+
 
 
 
 
+void qffi_QAbstractItemModel_index(QAbstractItemModel const* _self, int row, int column, const QModelIndex* parent, QModelIndex* result) {
+    auto* self = (QAbstractItemModel const*) _self;
+    qffi_call_ctor(result, self->index(row, column, *parent));
+}
 
+void qffi_QAbstractItemModel_parent(QAbstractItemModel const* _self, const QModelIndex* index, QModelIndex* result) {
+    auto* self = (QAbstractItemModel const*) _self;
+    qffi_call_ctor(result, self->parent(*index));
+}
 
-// QQmlEngine
+void qffi_QAbstractItemModel_sibling(QAbstractItemModel const* _self, int row, int column, const QModelIndex* index, QModelIndex* result) {
+    auto* self = (QAbstractItemModel const*) _self;
+    qffi_call_ctor(result, self->sibling(row, column, *index));
+}
 
+void qffi_QAbstractItemModel_data(QAbstractItemModel const* _self, const QModelIndex* index, int role, QVariant* result) {
+    auto* self = (QAbstractItemModel const*) _self;
+    qffi_call_ctor(result, self->data(*index, role));
+}
 
+int qffi_QAbstractItemModel_flags(QAbstractItemModel const* _self, const QModelIndex* index) {
+    auto* self = (QAbstractItemModel const*) _self;
+    return int(self->flags(*index));
+}
 
+int qffi_QAbstractItemModel_rowCount(QAbstractItemModel const* _self, const QModelIndex* parent) {
+    auto* self = (QAbstractItemModel const*) _self;
+    return self->rowCount(*parent);
+}
 
+int qffi_QAbstractItemModel_columnCount(QAbstractItemModel const* _self, const QModelIndex* parent) {
+    auto* self = (QAbstractItemModel const*) _self;
+    return self->columnCount(*parent);
+}
 
+bool qffi_QAbstractItemModel_setData(QAbstractItemModel * _self, const QModelIndex* index, const QVariant* value, int role) {
+    auto* self = (QAbstractItemModel *) _self;
+    return self->setData(*index, *value, role);
+}
 
-// QQmlApplicationEngine
-QQmlApplicationEngine* qffi_QQmlApplicationEngine_init(QObject* parent) {
-    return (QQmlApplicationEngine*)new QQmlApplicationEngine(parent);
+void qffi_QAbstractItemModel_headerData(QAbstractItemModel const* _self, int section, int orientation, int role, QVariant* result) {
+    auto* self = (QAbstractItemModel const*) _self;
+    qffi_call_ctor(result, self->headerData(section, Qt::Orientation(orientation), role));
 }
 
+void qffi_QAbstractItemModel_roleNames(QAbstractItemModel const* _self, QHashIntQByteArray* result) {
+    auto* self = (QAbstractItemModel const*) _self;
+    qffi_call_ctor((QHash<int, QByteArray>*)result, self->roleNames());
+}
 
 
+// QffiListModel
 
 
-void qffi_QQmlApplicationEngine_load(QQmlApplicationEngine * _self, const QUrl* url) {
-    auto* self = (QQmlApplicationEngine *) _self;
-    self->load(*url);
+
+
+
+QffiListModel* qffi_QffiListModel_init(QObject* parent, void* data, QffiListModelRowCount rowCount, QffiListModelData itemData, QffiClosureDrop drop, const QHashIntQByteArray* roleNames) {
+    
+    return new QffiListModel(
+      parent, data, rowCount, itemData, drop, *(const QHash<int, QByteArray>*)roleNames);
 }
 
-void qffi_QQmlApplicationEngine_rootObjects(QQmlApplicationEngine * _self, QObjectList* result) {
-    auto* self = (QQmlApplicationEngine *) _self;
-    qffi_call_ctor(result, self->rootObjects());
+void qffi_QffiListModel_beginInsertRows(QffiListModel * _self, int first, int last) {
+    auto* self = (QffiListModel *) _self;
+    self->beginInsertRows(QModelIndex(), first, last);
+}
+
+void qffi_QffiListModel_endInsertRows(QffiListModel * _self) {
+    auto* self = (QffiListModel *) _self;
+    self->endInsertRows();
+}
+
+void qffi_QffiListModel_beginRemoveRows(QffiListModel * _self, int first, int last) {
+    auto* self = (QffiListModel *) _self;
+    self->beginRemoveRows(QModelIndex(), first, last);
+}
+
+void qffi_QffiListModel_endRemoveRows(QffiListModel * _self) {
+    auto* self = (QffiListModel *) _self;
+    self->endRemoveRows();
+}
+
+bool qffi_QffiListModel_beginMoveRows(QffiListModel * _self, int first, int last, int destination) {
+    auto* self = (QffiListModel *) _self;
+    return self->beginMoveRows(QModelIndex(), first, last, QModelIndex(), destination);
+}
+
+void qffi_QffiListModel_endMoveRows(QffiListModel * _self) {
+    auto* self = (QffiListModel *) _self;
+    self->endMoveRows();
+}
+
+void qffi_QffiListModel_beginResetModel(QffiListModel * _self) {
+    auto* self = (QffiListModel *) _self;
+    self->beginResetModel();
+}
+
+void qffi_QffiListModel_endResetModel(QffiListModel * _self) {
+    auto* self = (QffiListModel *) _self;
+    self->endResetModel();
+}
+
+void qffi_QffiListModel_dataChanged(QffiListModel * _self, int first, int last) {
+    auto* self = (QffiListModel *) _self;
+    Q_EMIT self->dataChanged(self->index(first), self->index(last));
+}
+
+
+// QffiItemModel
+
+
+
+
+
+QffiItemModel* qffi_QffiItemModel_init(QObject* parent, void* data, const QffiItemModelVTable* vtable) {
+    
+    return new QffiItemModel(parent, data, *vtable);
+}
+
+void qffi_QffiItemModel_beginInsertRows(QffiItemModel * _self, const QModelIndex* parent, int first, int last) {
+    auto* self = (QffiItemModel *) _self;
+    self->beginInsertRows(*parent, first, last);
+}
+
+void qffi_QffiItemModel_endInsertRows(QffiItemModel * _self) {
+    auto* self = (QffiItemModel *) _self;
+    self->endInsertRows();
+}
+
+void qffi_QffiItemModel_beginRemoveRows(QffiItemModel * _self, const QModelIndex* parent, int first, int last) {
+    auto* self = (QffiItemModel *) _self;
+    self->beginRemoveRows(*parent, first, last);
+}
+
+void qffi_QffiItemModel_endRemoveRows(QffiItemModel * _self) {
+    auto* self = (QffiItemModel *) _self;
+    self->endRemoveRows();
+}
+
+bool qffi_QffiItemModel_beginMoveRows(QffiItemModel * _self, const QModelIndex* sourceParent, int first, int last, const QModelIndex* destinationParent, int destination) {
+    auto* self = (QffiItemModel *) _self;
+    return self->beginMoveRows(*sourceParent, first, last, *destinationParent, destination);
+}
+
+void qffi_QffiItemModel_endMoveRows(QffiItemModel * _self) {
+    auto* self = (QffiItemModel *) _self;
+    self->endMoveRows();
+}
+
+void qffi_QffiItemModel_beginInsertColumns(QffiItemModel * _self, const QModelIndex* parent, int first, int last) {
+    auto* self = (QffiItemModel *) _self;
+    self->beginInsertColumns(*parent, first, last);
+}
+
+void qffi_QffiItemModel_endInsertColumns(QffiItemModel * _self) {
+    auto* self = (QffiItemModel *) _self;
+    self->endInsertColumns();
+}
+
+void qffi_QffiItemModel_beginRemoveColumns(QffiItemModel * _self, const QModelIndex* parent, int first, int last) {
+    auto* self = (QffiItemModel *) _self;
+    self->beginRemoveColumns(*parent, first, last);
+}
+
+void qffi_QffiItemModel_endRemoveColumns(QffiItemModel * _self) {
+    auto* self = (QffiItemModel *) _self;
+    self->endRemoveColumns();
+}
+
+void qffi_QffiItemModel_beginResetModel(QffiItemModel * _self) {
+    auto* self = (QffiItemModel *) _self;
+    self->beginResetModel();
+}
+
+void qffi_QffiItemModel_endResetModel(QffiItemModel * _self) {
+    auto* self = (QffiItemModel *) _self;
+    self->endResetModel();
+}
+
+void qffi_QffiItemModel_dataChanged(QffiItemModel * _self, const QModelIndex* topLeft, const QModelIndex* bottomRight) {
+    auto* self = (QffiItemModel *) _self;
+    Q_EMIT self->dataChanged(*topLeft, *bottomRight);
+}
+
+
+// QffiSortFilterProxyModel
+
+
+
+
+
+QffiSortFilterProxyModel* qffi_QffiSortFilterProxyModel_init(QObject* parent, void* data, const QffiSortFilterProxyModelVTable* vtable) {
+    
+    return new QffiSortFilterProxyModel(parent, data, *vtable);
+}
+
+void qffi_QffiSortFilterProxyModel_setSourceModel(QffiSortFilterProxyModel * _self, QAbstractItemModel* sourceModel) {
+    auto* self = (QffiSortFilterProxyModel *) _self;
+    self->setSourceModel(sourceModel);
+}
+
+QAbstractItemModel* qffi_QffiSortFilterProxyModel_sourceModel(QffiSortFilterProxyModel const* _self) {
+    auto* self = (QffiSortFilterProxyModel const*) _self;
+    return self->sourceModel();
+}
+
+void qffi_QffiSortFilterProxyModel_invalidateFilter(QffiSortFilterProxyModel * _self) {
+    auto* self = (QffiSortFilterProxyModel *) _self;
+    self->invalidateFilter();
+}
+
+void qffi_QffiSortFilterProxyModel_invalidate(QffiSortFilterProxyModel * _self) {
+    auto* self = (QffiSortFilterProxyModel *) _self;
+    self->invalidate();
+}
+
+void qffi_QffiSortFilterProxyModel_sort(QffiSortFilterProxyModel * _self, int column, int order) {
+    auto* self = (QffiSortFilterProxyModel *) _self;
+    self->sort(column, Qt::SortOrder(order));
+}
+
+void qffi_QffiSortFilterProxyModel_mapToSource(QffiSortFilterProxyModel const* _self, const QModelIndex* proxyIndex, QModelIndex* result) {
+    auto* self = (QffiSortFilterProxyModel const*) _self;
+    qffi_call_ctor(result, self->mapToSource(*proxyIndex));
+}
+
+void qffi_QffiSortFilterProxyModel_mapFromSource(QffiSortFilterProxyModel const* _self, const QModelIndex* sourceIndex, QModelIndex* result) {
+    auto* self = (QffiSortFilterProxyModel const*) _self;
+    qffi_call_ctor(result, self->mapFromSource(*sourceIndex));
 }
 
 
@@ -808,9 +1035,14 @@ int qffi_QObjectList_size(QObjectList const* _self) {
     return self->size();
 }
 
+QObject* const* qffi_QObjectList_at(QObjectList const* _self, int index) {
+    auto* self = (QObjectList const*) _self;
+    return &self->at(index);
+}
+
 QObject* const* qffi_QObjectList_asSlice(QObjectList const* _self, int* size) {
     auto* self = (QObjectList const*) _self;
-    *size = self->size(); if (size == 0) { return nullptr; } else { return & self->front(); }
+    *size = self->size(); if (*size == 0) { return nullptr; } else { return & self->front(); }
 }
 
 void qffi_QObjectList_append(QObjectList * _self, QObject* const* item) {
@@ -859,9 +1091,14 @@ int qffi_QStringList_size(QStringList const* _self) {
     return self->size();
 }
 
+QString const* qffi_QStringList_at(QStringList const* _self, int index) {
+    auto* self = (QStringList const*) _self;
+    return &self->at(index);
+}
+
 QString const* qffi_QStringList_asSlice(QStringList const* _self, int* size) {
     auto* self = (QStringList const*) _self;
-    *size = self->size(); if (size == 0) { return nullptr; } else { return & self->front(); }
+    *size = self->size(); if (*size == 0) { return nullptr; } else { return & self->front(); }
 }
 
 void qffi_QStringList_append(QStringList * _self, QString const* item) {
@@ -884,4 +1121,1453 @@ void qffi_QStringList_reserveAdditional(QStringList * _self, int additional) {
     self->reserve(self->size() + additional);
 }
 
+
+// QListBool
+
+static_assert(alignof(QList<bool>) == alignof(QListBool), "Alignment of QList<bool> incompatible");
+static_assert(sizeof(QList<bool>) == sizeof(QListBool), "Size of QList<bool> incompatible");
+
+
+void qffi_QListBool_init(QListBool* self) {
+    ::qffi_call_default_ctor((QList<bool>*)self);
+}
+
+
+void qffi_QListBool_clone(QListBool const* self, QListBool* new_) {
+    new ((QList<bool>*)new_) QList<bool>(*(QList<bool> const*)self);
+}
+
+bool qffi_QListBool_equals(QListBool const* self, QListBool const* other) {
+    return *((QList<bool> const*)self) == *((QList<bool> const*)other);
+}
+
+
+int qffi_QListBool_size(QListBool const* _self) {
+    auto* self = (QList<bool> const*) _self;
+    return self->size();
+}
+
+bool const* qffi_QListBool_at(QListBool const* _self, int index) {
+    auto* self = (QList<bool> const*) _self;
+    return &self->at(index);
+}
+
+void qffi_QListBool_append(QListBool * _self, bool const* item) {
+    auto* self = (QList<bool> *) _self;
+    self->append(*item);
+}
+
+void qffi_QListBool_appendList(QListBool * _self, QList<bool> const* item) {
+    auto* self = (QList<bool> *) _self;
+    self->append(*item);
+}
+
+void qffi_QListBool_appendSlice(QListBool * _self, bool const* items, int size) {
+    auto* self = (QList<bool> *) _self;
+    self->reserve(self->size() + size); for (int i = 0; i < size; ++i) { self->push_back(items[i]); }
+}
+
+void qffi_QListBool_reserveAdditional(QListBool * _self, int additional) {
+    auto* self = (QList<bool> *) _self;
+    self->reserve(self->size() + additional);
+}
+
+
+// QListInt
+
+static_assert(alignof(QList<int>) == alignof(QListInt), "Alignment of QList<int> incompatible");
+static_assert(sizeof(QList<int>) == sizeof(QListInt), "Size of QList<int> incompatible");
+
+
+void qffi_QListInt_init(QListInt* self) {
+    ::qffi_call_default_ctor((QList<int>*)self);
+}
+
+
+void qffi_QListInt_clone(QListInt const* self, QListInt* new_) {
+    new ((QList<int>*)new_) QList<int>(*(QList<int> const*)self);
+}
+
+bool qffi_QListInt_equals(QListInt const* self, QListInt const* other) {
+    return *((QList<int> const*)self) == *((QList<int> const*)other);
+}
+
+
+int qffi_QListInt_size(QListInt const* _self) {
+    auto* self = (QList<int> const*) _self;
+    return self->size();
+}
+
+int const* qffi_QListInt_at(QListInt const* _self, int index) {
+    auto* self = (QList<int> const*) _self;
+    return &self->at(index);
+}
+
+void qffi_QListInt_append(QListInt * _self, int const* item) {
+    auto* self = (QList<int> *) _self;
+    self->append(*item);
+}
+
+void qffi_QListInt_appendList(QListInt * _self, QList<int> const* item) {
+    auto* self = (QList<int> *) _self;
+    self->append(*item);
+}
+
+void qffi_QListInt_appendSlice(QListInt * _self, int const* items, int size) {
+    auto* self = (QList<int> *) _self;
+    self->reserve(self->size() + size); for (int i = 0; i < size; ++i) { self->push_back(items[i]); }
+}
+
+void qffi_QListInt_reserveAdditional(QListInt * _self, int additional) {
+    auto* self = (QList<int> *) _self;
+    self->reserve(self->size() + additional);
+}
+
+
+// QListLongLong
+
+static_assert(alignof(QList<long long>) == alignof(QListLongLong), "Alignment of QList<long long> incompatible");
+static_assert(sizeof(QList<long long>) == sizeof(QListLongLong), "Size of QList<long long> incompatible");
+
+
+void qffi_QListLongLong_init(QListLongLong* self) {
+    ::qffi_call_default_ctor((QList<long long>*)self);
+}
+
+
+void qffi_QListLongLong_clone(QListLongLong const* self, QListLongLong* new_) {
+    new ((QList<long long>*)new_) QList<long long>(*(QList<long long> const*)self);
+}
+
+bool qffi_QListLongLong_equals(QListLongLong const* self, QListLongLong const* other) {
+    return *((QList<long long> const*)self) == *((QList<long long> const*)other);
+}
+
+
+int qffi_QListLongLong_size(QListLongLong const* _self) {
+    auto* self = (QList<long long> const*) _self;
+    return self->size();
+}
+
+long long const* qffi_QListLongLong_at(QListLongLong const* _self, int index) {
+    auto* self = (QList<long long> const*) _self;
+    return &self->at(index);
+}
+
+void qffi_QListLongLong_append(QListLongLong * _self, long long const* item) {
+    auto* self = (QList<long long> *) _self;
+    self->append(*item);
+}
+
+void qffi_QListLongLong_appendList(QListLongLong * _self, QList<long long> const* item) {
+    auto* self = (QList<long long> *) _self;
+    self->append(*item);
+}
+
+void qffi_QListLongLong_appendSlice(QListLongLong * _self, long long const* items, int size) {
+    auto* self = (QList<long long> *) _self;
+    self->reserve(self->size() + size); for (int i = 0; i < size; ++i) { self->push_back(items[i]); }
+}
+
+void qffi_QListLongLong_reserveAdditional(QListLongLong * _self, int additional) {
+    auto* self = (QList<long long> *) _self;
+    self->reserve(self->size() + additional);
+}
+
+
+// QListDouble
+
+static_assert(alignof(QList<double>) == alignof(QListDouble), "Alignment of QList<double> incompatible");
+static_assert(sizeof(QList<double>) == sizeof(QListDouble), "Size of QList<double> incompatible");
+
+
+void qffi_QListDouble_init(QListDouble* self) {
+    ::qffi_call_default_ctor((QList<double>*)self);
+}
+
+
+void qffi_QListDouble_clone(QListDouble const* self, QListDouble* new_) {
+    new ((QList<double>*)new_) QList<double>(*(QList<double> const*)self);
+}
+
+bool qffi_QListDouble_equals(QListDouble const* self, QListDouble const* other) {
+    return *((QList<double> const*)self) == *((QList<double> const*)other);
+}
+
+
+int qffi_QListDouble_size(QListDouble const* _self) {
+    auto* self = (QList<double> const*) _self;
+    return self->size();
+}
+
+double const* qffi_QListDouble_at(QListDouble const* _self, int index) {
+    auto* self = (QList<double> const*) _self;
+    return &self->at(index);
+}
+
+void qffi_QListDouble_append(QListDouble * _self, double const* item) {
+    auto* self = (QList<double> *) _self;
+    self->append(*item);
+}
+
+void qffi_QListDouble_appendList(QListDouble * _self, QList<double> const* item) {
+    auto* self = (QList<double> *) _self;
+    self->append(*item);
+}
+
+void qffi_QListDouble_appendSlice(QListDouble * _self, double const* items, int size) {
+    auto* self = (QList<double> *) _self;
+    self->reserve(self->size() + size); for (int i = 0; i < size; ++i) { self->push_back(items[i]); }
+}
+
+void qffi_QListDouble_reserveAdditional(QListDouble * _self, int additional) {
+    auto* self = (QList<double> *) _self;
+    self->reserve(self->size() + additional);
+}
+
+
+// QListQByteArray
+
+static_assert(alignof(QList<QByteArray>) == alignof(QListQByteArray), "Alignment of QList<QByteArray> incompatible");
+static_assert(sizeof(QList<QByteArray>) == sizeof(QListQByteArray), "Size of QList<QByteArray> incompatible");
+
+
+void qffi_QListQByteArray_init(QListQByteArray* self) {
+    ::qffi_call_default_ctor((QList<QByteArray>*)self);
+}
+
+
+void qffi_QListQByteArray_clone(QListQByteArray const* self, QListQByteArray* new_) {
+    new ((QList<QByteArray>*)new_) QList<QByteArray>(*(QList<QByteArray> const*)self);
+}
+
+bool qffi_QListQByteArray_equals(QListQByteArray const* self, QListQByteArray const* other) {
+    return *((QList<QByteArray> const*)self) == *((QList<QByteArray> const*)other);
+}
+
+
+int qffi_QListQByteArray_size(QListQByteArray const* _self) {
+    auto* self = (QList<QByteArray> const*) _self;
+    return self->size();
+}
+
+QByteArray const* qffi_QListQByteArray_at(QListQByteArray const* _self, int index) {
+    auto* self = (QList<QByteArray> const*) _self;
+    return &self->at(index);
+}
+
+QByteArray const* qffi_QListQByteArray_asSlice(QListQByteArray const* _self, int* size) {
+    auto* self = (QList<QByteArray> const*) _self;
+    *size = self->size(); if (*size == 0) { return nullptr; } else { return & self->front(); }
+}
+
+void qffi_QListQByteArray_append(QListQByteArray * _self, QByteArray const* item) {
+    auto* self = (QList<QByteArray> *) _self;
+    self->append(*item);
+}
+
+void qffi_QListQByteArray_appendList(QListQByteArray * _self, QList<QByteArray> const* item) {
+    auto* self = (QList<QByteArray> *) _self;
+    self->append(*item);
+}
+
+void qffi_QListQByteArray_appendSlice(QListQByteArray * _self, QByteArray const* items, int size) {
+    auto* self = (QList<QByteArray> *) _self;
+    self->reserve(self->size() + size); for (int i = 0; i < size; ++i) { self->push_back(items[i]); }
+}
+
+void qffi_QListQByteArray_reserveAdditional(QListQByteArray * _self, int additional) {
+    auto* self = (QList<QByteArray> *) _self;
+    self->reserve(self->size() + additional);
+}
+
+
+// QListQVariant
+
+static_assert(alignof(QList<QVariant>) == alignof(QListQVariant), "Alignment of QList<QVariant> incompatible");
+static_assert(sizeof(QList<QVariant>) == sizeof(QListQVariant), "Size of QList<QVariant> incompatible");
+
+
+void qffi_QListQVariant_init(QListQVariant* self) {
+    ::qffi_call_default_ctor((QList<QVariant>*)self);
+}
+
+
+void qffi_QListQVariant_clone(QListQVariant const* self, QListQVariant* new_) {
+    new ((QList<QVariant>*)new_) QList<QVariant>(*(QList<QVariant> const*)self);
+}
+
+bool qffi_QListQVariant_equals(QListQVariant const* self, QListQVariant const* other) {
+    return *((QList<QVariant> const*)self) == *((QList<QVariant> const*)other);
+}
+
+
+int qffi_QListQVariant_size(QListQVariant const* _self) {
+    auto* self = (QList<QVariant> const*) _self;
+    return self->size();
+}
+
+QVariant const* qffi_QListQVariant_at(QListQVariant const* _self, int index) {
+    auto* self = (QList<QVariant> const*) _self;
+    return &self->at(index);
+}
+
+void qffi_QListQVariant_append(QListQVariant * _self, QVariant const* item) {
+    auto* self = (QList<QVariant> *) _self;
+    self->append(*item);
+}
+
+void qffi_QListQVariant_appendList(QListQVariant * _self, QList<QVariant> const* item) {
+    auto* self = (QList<QVariant> *) _self;
+    self->append(*item);
+}
+
+void qffi_QListQVariant_appendSlice(QListQVariant * _self, QVariant const* items, int size) {
+    auto* self = (QList<QVariant> *) _self;
+    self->reserve(self->size() + size); for (int i = 0; i < size; ++i) { self->push_back(items[i]); }
+}
+
+void qffi_QListQVariant_reserveAdditional(QListQVariant * _self, int additional) {
+    auto* self = (QList<QVariant> *) _self;
+    self->reserve(self->size() + additional);
+}
+
+
+// QVectorBool
+
+static_assert(alignof(QVector<bool>) == alignof(QVectorBool), "Alignment of QVector<bool> incompatible");
+static_assert(sizeof(QVector<bool>) == sizeof(QVectorBool), "Size of QVector<bool> incompatible");
+
+
+void qffi_QVectorBool_init(QVectorBool* self) {
+    ::qffi_call_default_ctor((QVector<bool>*)self);
+}
+
+
+void qffi_QVectorBool_clone(QVectorBool const* self, QVectorBool* new_) {
+    new ((QVector<bool>*)new_) QVector<bool>(*(QVector<bool> const*)self);
+}
+
+bool qffi_QVectorBool_equals(QVectorBool const* self, QVectorBool const* other) {
+    return *((QVector<bool> const*)self) == *((QVector<bool> const*)other);
+}
+
+
+int qffi_QVectorBool_size(QVectorBool const* _self) {
+    auto* self = (QVector<bool> const*) _self;
+    return self->size();
+}
+
+bool const* qffi_QVectorBool_asSlice(QVectorBool const* _self, int* size) {
+    auto* self = (QVector<bool> const*) _self;
+    *size = self->size(); return self->constData();
+}
+
+void qffi_QVectorBool_append(QVectorBool * _self, bool const* item) {
+    auto* self = (QVector<bool> *) _self;
+    self->append(*item);
+}
+
+void qffi_QVectorBool_appendSlice(QVectorBool * _self, bool const* items, int size) {
+    auto* self = (QVector<bool> *) _self;
+    self->reserve(self->size() + size); for (int i = 0; i < size; ++i) { self->push_back(items[i]); }
+}
+
+void qffi_QVectorBool_reserveAdditional(QVectorBool * _self, int additional) {
+    auto* self = (QVector<bool> *) _self;
+    self->reserve(self->size() + additional);
+}
+
+
+// QVectorInt
+
+static_assert(alignof(QVector<int>) == alignof(QVectorInt), "Alignment of QVector<int> incompatible");
+static_assert(sizeof(QVector<int>) == sizeof(QVectorInt), "Size of QVector<int> incompatible");
+
+
+void qffi_QVectorInt_init(QVectorInt* self) {
+    ::qffi_call_default_ctor((QVector<int>*)self);
+}
+
+
+void qffi_QVectorInt_clone(QVectorInt const* self, QVectorInt* new_) {
+    new ((QVector<int>*)new_) QVector<int>(*(QVector<int> const*)self);
+}
+
+bool qffi_QVectorInt_equals(QVectorInt const* self, QVectorInt const* other) {
+    return *((QVector<int> const*)self) == *((QVector<int> const*)other);
+}
+
+
+int qffi_QVectorInt_size(QVectorInt const* _self) {
+    auto* self = (QVector<int> const*) _self;
+    return self->size();
+}
+
+int const* qffi_QVectorInt_asSlice(QVectorInt const* _self, int* size) {
+    auto* self = (QVector<int> const*) _self;
+    *size = self->size(); return self->constData();
+}
+
+void qffi_QVectorInt_append(QVectorInt * _self, int const* item) {
+    auto* self = (QVector<int> *) _self;
+    self->append(*item);
+}
+
+void qffi_QVectorInt_appendSlice(QVectorInt * _self, int const* items, int size) {
+    auto* self = (QVector<int> *) _self;
+    self->reserve(self->size() + size); for (int i = 0; i < size; ++i) { self->push_back(items[i]); }
+}
+
+void qffi_QVectorInt_reserveAdditional(QVectorInt * _self, int additional) {
+    auto* self = (QVector<int> *) _self;
+    self->reserve(self->size() + additional);
+}
+
+
+// QVectorLongLong
+
+static_assert(alignof(QVector<long long>) == alignof(QVectorLongLong), "Alignment of QVector<long long> incompatible");
+static_assert(sizeof(QVector<long long>) == sizeof(QVectorLongLong), "Size of QVector<long long> incompatible");
+
+
+void qffi_QVectorLongLong_init(QVectorLongLong* self) {
+    ::qffi_call_default_ctor((QVector<long long>*)self);
+}
+
+
+void qffi_QVectorLongLong_clone(QVectorLongLong const* self, QVectorLongLong* new_) {
+    new ((QVector<long long>*)new_) QVector<long long>(*(QVector<long long> const*)self);
+}
+
+bool qffi_QVectorLongLong_equals(QVectorLongLong const* self, QVectorLongLong const* other) {
+    return *((QVector<long long> const*)self) == *((QVector<long long> const*)other);
+}
+
+
+int qffi_QVectorLongLong_size(QVectorLongLong const* _self) {
+    auto* self = (QVector<long long> const*) _self;
+    return self->size();
+}
+
+long long const* qffi_QVectorLongLong_asSlice(QVectorLongLong const* _self, int* size) {
+    auto* self = (QVector<long long> const*) _self;
+    *size = self->size(); return self->constData();
+}
+
+void qffi_QVectorLongLong_append(QVectorLongLong * _self, long long const* item) {
+    auto* self = (QVector<long long> *) _self;
+    self->append(*item);
+}
+
+void qffi_QVectorLongLong_appendSlice(QVectorLongLong * _self, long long const* items, int size) {
+    auto* self = (QVector<long long> *) _self;
+    self->reserve(self->size() + size); for (int i = 0; i < size; ++i) { self->push_back(items[i]); }
+}
+
+void qffi_QVectorLongLong_reserveAdditional(QVectorLongLong * _self, int additional) {
+    auto* self = (QVector<long long> *) _self;
+    self->reserve(self->size() + additional);
+}
+
+
+// QVectorDouble
+
+static_assert(alignof(QVector<double>) == alignof(QVectorDouble), "Alignment of QVector<double> incompatible");
+static_assert(sizeof(QVector<double>) == sizeof(QVectorDouble), "Size of QVector<double> incompatible");
+
+
+void qffi_QVectorDouble_init(QVectorDouble* self) {
+    ::qffi_call_default_ctor((QVector<double>*)self);
+}
+
+
+void qffi_QVectorDouble_clone(QVectorDouble const* self, QVectorDouble* new_) {
+    new ((QVector<double>*)new_) QVector<double>(*(QVector<double> const*)self);
+}
+
+bool qffi_QVectorDouble_equals(QVectorDouble const* self, QVectorDouble const* other) {
+    return *((QVector<double> const*)self) == *((QVector<double> const*)other);
+}
+
+
+int qffi_QVectorDouble_size(QVectorDouble const* _self) {
+    auto* self = (QVector<double> const*) _self;
+    return self->size();
+}
+
+double const* qffi_QVectorDouble_asSlice(QVectorDouble const* _self, int* size) {
+    auto* self = (QVector<double> const*) _self;
+    *size = self->size(); return self->constData();
+}
+
+void qffi_QVectorDouble_append(QVectorDouble * _self, double const* item) {
+    auto* self = (QVector<double> *) _self;
+    self->append(*item);
+}
+
+void qffi_QVectorDouble_appendSlice(QVectorDouble * _self, double const* items, int size) {
+    auto* self = (QVector<double> *) _self;
+    self->reserve(self->size() + size); for (int i = 0; i < size; ++i) { self->push_back(items[i]); }
+}
+
+void qffi_QVectorDouble_reserveAdditional(QVectorDouble * _self, int additional) {
+    auto* self = (QVector<double> *) _self;
+    self->reserve(self->size() + additional);
+}
+
+
+// QVectorQString
+
+static_assert(alignof(QVector<QString>) == alignof(QVectorQString), "Alignment of QVector<QString> incompatible");
+static_assert(sizeof(QVector<QString>) == sizeof(QVectorQString), "Size of QVector<QString> incompatible");
+
+
+void qffi_QVectorQString_init(QVectorQString* self) {
+    ::qffi_call_default_ctor((QVector<QString>*)self);
+}
+
+
+void qffi_QVectorQString_clone(QVectorQString const* self, QVectorQString* new_) {
+    new ((QVector<QString>*)new_) QVector<QString>(*(QVector<QString> const*)self);
+}
+
+bool qffi_QVectorQString_equals(QVectorQString const* self, QVectorQString const* other) {
+    return *((QVector<QString> const*)self) == *((QVector<QString> const*)other);
+}
+
+
+int qffi_QVectorQString_size(QVectorQString const* _self) {
+    auto* self = (QVector<QString> const*) _self;
+    return self->size();
+}
+
+QString const* qffi_QVectorQString_asSlice(QVectorQString const* _self, int* size) {
+    auto* self = (QVector<QString> const*) _self;
+    *size = self->size(); return self->constData();
+}
+
+void qffi_QVectorQString_append(QVectorQString * _self, QString const* item) {
+    auto* self = (QVector<QString> *) _self;
+    self->append(*item);
+}
+
+void qffi_QVectorQString_appendSlice(QVectorQString * _self, QString const* items, int size) {
+    auto* self = (QVector<QString> *) _self;
+    self->reserve(self->size() + size); for (int i = 0; i < size; ++i) { self->push_back(items[i]); }
+}
+
+void qffi_QVectorQString_reserveAdditional(QVectorQString * _self, int additional) {
+    auto* self = (QVector<QString> *) _self;
+    self->reserve(self->size() + additional);
+}
+
+
+// QVectorQByteArray
+
+static_assert(alignof(QVector<QByteArray>) == alignof(QVectorQByteArray), "Alignment of QVector<QByteArray> incompatible");
+static_assert(sizeof(QVector<QByteArray>) == sizeof(QVectorQByteArray), "Size of QVector<QByteArray> incompatible");
+
+
+void qffi_QVectorQByteArray_init(QVectorQByteArray* self) {
+    ::qffi_call_default_ctor((QVector<QByteArray>*)self);
+}
+
+
+void qffi_QVectorQByteArray_clone(QVectorQByteArray const* self, QVectorQByteArray* new_) {
+    new ((QVector<QByteArray>*)new_) QVector<QByteArray>(*(QVector<QByteArray> const*)self);
+}
+
+bool qffi_QVectorQByteArray_equals(QVectorQByteArray const* self, QVectorQByteArray const* other) {
+    return *((QVector<QByteArray> const*)self) == *((QVector<QByteArray> const*)other);
+}
+
+
+int qffi_QVectorQByteArray_size(QVectorQByteArray const* _self) {
+    auto* self = (QVector<QByteArray> const*) _self;
+    return self->size();
+}
+
+QByteArray const* qffi_QVectorQByteArray_asSlice(QVectorQByteArray const* _self, int* size) {
+    auto* self = (QVector<QByteArray> const*) _self;
+    *size = self->size(); return self->constData();
+}
+
+void qffi_QVectorQByteArray_append(QVectorQByteArray * _self, QByteArray const* item) {
+    auto* self = (QVector<QByteArray> *) _self;
+    self->append(*item);
+}
+
+void qffi_QVectorQByteArray_appendSlice(QVectorQByteArray * _self, QByteArray const* items, int size) {
+    auto* self = (QVector<QByteArray> *) _self;
+    self->reserve(self->size() + size); for (int i = 0; i < size; ++i) { self->push_back(items[i]); }
+}
+
+void qffi_QVectorQByteArray_reserveAdditional(QVectorQByteArray * _self, int additional) {
+    auto* self = (QVector<QByteArray> *) _self;
+    self->reserve(self->size() + additional);
+}
+
+
+// QVectorQVariant
+
+static_assert(alignof(QVector<QVariant>) == alignof(QVectorQVariant), "Alignment of QVector<QVariant> incompatible");
+static_assert(sizeof(QVector<QVariant>) == sizeof(QVectorQVariant), "Size of QVector<QVariant> incompatible");
+
+
+void qffi_QVectorQVariant_init(QVectorQVariant* self) {
+    ::qffi_call_default_ctor((QVector<QVariant>*)self);
+}
+
+
+void qffi_QVectorQVariant_clone(QVectorQVariant const* self, QVectorQVariant* new_) {
+    new ((QVector<QVariant>*)new_) QVector<QVariant>(*(QVector<QVariant> const*)self);
+}
+
+bool qffi_QVectorQVariant_equals(QVectorQVariant const* self, QVectorQVariant const* other) {
+    return *((QVector<QVariant> const*)self) == *((QVector<QVariant> const*)other);
+}
+
+
+int qffi_QVectorQVariant_size(QVectorQVariant const* _self) {
+    auto* self = (QVector<QVariant> const*) _self;
+    return self->size();
+}
+
+QVariant const* qffi_QVectorQVariant_asSlice(QVectorQVariant const* _self, int* size) {
+    auto* self = (QVector<QVariant> const*) _self;
+    *size = self->size(); return self->constData();
+}
+
+void qffi_QVectorQVariant_append(QVectorQVariant * _self, QVariant const* item) {
+    auto* self = (QVector<QVariant> *) _self;
+    self->append(*item);
+}
+
+void qffi_QVectorQVariant_appendSlice(QVectorQVariant * _self, QVariant const* items, int size) {
+    auto* self = (QVector<QVariant> *) _self;
+    self->reserve(self->size() + size); for (int i = 0; i < size; ++i) { self->push_back(items[i]); }
+}
+
+void qffi_QVectorQVariant_reserveAdditional(QVectorQVariant * _self, int additional) {
+    auto* self = (QVector<QVariant> *) _self;
+    self->reserve(self->size() + additional);
+}
+
+
+// QMapIntInt
+
+static_assert(alignof(QMap<int, int>) == alignof(QMapIntInt), "Alignment of QMap<int, int> incompatible");
+static_assert(sizeof(QMap<int, int>) == sizeof(QMapIntInt), "Size of QMap<int, int> incompatible");
+
+
+void qffi_QMapIntInt_init(QMapIntInt* self) {
+    ::qffi_call_default_ctor((QMap<int, int>*)self);
+}
+
+
+void qffi_QMapIntInt_clone(QMapIntInt const* self, QMapIntInt* new_) {
+    new ((QMap<int, int>*)new_) QMap<int, int>(*(QMap<int, int> const*)self);
+}
+
+bool qffi_QMapIntInt_equals(QMapIntInt const* self, QMapIntInt const* other) {
+    return *((QMap<int, int> const*)self) == *((QMap<int, int> const*)other);
+}
+
+
+int qffi_QMapIntInt_size(QMapIntInt const* _self) {
+    auto* self = (QMap<int, int> const*) _self;
+    return self->size();
+}
+
+bool qffi_QMapIntInt_contains(QMapIntInt const* _self, int const* key) {
+    auto* self = (QMap<int, int> const*) _self;
+    return self->contains(*key);
+}
+
+int const* qffi_QMapIntInt_value(QMapIntInt const* _self, int const* key) {
+    auto* self = (QMap<int, int> const*) _self;
+    auto it = self->constFind(*key); return it == self->cend() ? nullptr : &it.value();
+}
+
+void qffi_QMapIntInt_insert(QMapIntInt * _self, int const* key, int const* value) {
+    auto* self = (QMap<int, int> *) _self;
+    self->insert(*key, *value);
+}
+
+int qffi_QMapIntInt_remove(QMapIntInt * _self, int const* key) {
+    auto* self = (QMap<int, int> *) _self;
+    return self->remove(*key);
+}
+
+void qffi_QMapIntInt_forEach(QMapIntInt const* _self, void* data, QffiMapEntryCall call) {
+    auto* self = (QMap<int, int> const*) _self;
+    for (auto it = self->cbegin(); it != self->cend(); ++it) { call(data, &it.key(), &it.value()); }
+}
+
+
+// QMapIntQString
+
+static_assert(alignof(QMap<int, QString>) == alignof(QMapIntQString), "Alignment of QMap<int, QString> incompatible");
+static_assert(sizeof(QMap<int, QString>) == sizeof(QMapIntQString), "Size of QMap<int, QString> incompatible");
+
+
+void qffi_QMapIntQString_init(QMapIntQString* self) {
+    ::qffi_call_default_ctor((QMap<int, QString>*)self);
+}
+
+
+void qffi_QMapIntQString_clone(QMapIntQString const* self, QMapIntQString* new_) {
+    new ((QMap<int, QString>*)new_) QMap<int, QString>(*(QMap<int, QString> const*)self);
+}
+
+bool qffi_QMapIntQString_equals(QMapIntQString const* self, QMapIntQString const* other) {
+    return *((QMap<int, QString> const*)self) == *((QMap<int, QString> const*)other);
+}
+
+
+int qffi_QMapIntQString_size(QMapIntQString const* _self) {
+    auto* self = (QMap<int, QString> const*) _self;
+    return self->size();
+}
+
+bool qffi_QMapIntQString_contains(QMapIntQString const* _self, int const* key) {
+    auto* self = (QMap<int, QString> const*) _self;
+    return self->contains(*key);
+}
+
+QString const* qffi_QMapIntQString_value(QMapIntQString const* _self, int const* key) {
+    auto* self = (QMap<int, QString> const*) _self;
+    auto it = self->constFind(*key); return it == self->cend() ? nullptr : &it.value();
+}
+
+void qffi_QMapIntQString_insert(QMapIntQString * _self, int const* key, QString const* value) {
+    auto* self = (QMap<int, QString> *) _self;
+    self->insert(*key, *value);
+}
+
+int qffi_QMapIntQString_remove(QMapIntQString * _self, int const* key) {
+    auto* self = (QMap<int, QString> *) _self;
+    return self->remove(*key);
+}
+
+void qffi_QMapIntQString_forEach(QMapIntQString const* _self, void* data, QffiMapEntryCall call) {
+    auto* self = (QMap<int, QString> const*) _self;
+    for (auto it = self->cbegin(); it != self->cend(); ++it) { call(data, &it.key(), &it.value()); }
+}
+
+
+// QMapIntQByteArray
+
+static_assert(alignof(QMap<int, QByteArray>) == alignof(QMapIntQByteArray), "Alignment of QMap<int, QByteArray> incompatible");
+static_assert(sizeof(QMap<int, QByteArray>) == sizeof(QMapIntQByteArray), "Size of QMap<int, QByteArray> incompatible");
+
+
+void qffi_QMapIntQByteArray_init(QMapIntQByteArray* self) {
+    ::qffi_call_default_ctor((QMap<int, QByteArray>*)self);
+}
+
+
+void qffi_QMapIntQByteArray_clone(QMapIntQByteArray const* self, QMapIntQByteArray* new_) {
+    new ((QMap<int, QByteArray>*)new_) QMap<int, QByteArray>(*(QMap<int, QByteArray> const*)self);
+}
+
+bool qffi_QMapIntQByteArray_equals(QMapIntQByteArray const* self, QMapIntQByteArray const* other) {
+    return *((QMap<int, QByteArray> const*)self) == *((QMap<int, QByteArray> const*)other);
+}
+
+
+int qffi_QMapIntQByteArray_size(QMapIntQByteArray const* _self) {
+    auto* self = (QMap<int, QByteArray> const*) _self;
+    return self->size();
+}
+
+bool qffi_QMapIntQByteArray_contains(QMapIntQByteArray const* _self, int const* key) {
+    auto* self = (QMap<int, QByteArray> const*) _self;
+    return self->contains(*key);
+}
+
+QByteArray const* qffi_QMapIntQByteArray_value(QMapIntQByteArray const* _self, int const* key) {
+    auto* self = (QMap<int, QByteArray> const*) _self;
+    auto it = self->constFind(*key); return it == self->cend() ? nullptr : &it.value();
+}
+
+void qffi_QMapIntQByteArray_insert(QMapIntQByteArray * _self, int const* key, QByteArray const* value) {
+    auto* self = (QMap<int, QByteArray> *) _self;
+    self->insert(*key, *value);
+}
+
+int qffi_QMapIntQByteArray_remove(QMapIntQByteArray * _self, int const* key) {
+    auto* self = (QMap<int, QByteArray> *) _self;
+    return self->remove(*key);
+}
+
+void qffi_QMapIntQByteArray_forEach(QMapIntQByteArray const* _self, void* data, QffiMapEntryCall call) {
+    auto* self = (QMap<int, QByteArray> const*) _self;
+    for (auto it = self->cbegin(); it != self->cend(); ++it) { call(data, &it.key(), &it.value()); }
+}
+
+
+// QMapIntQVariant
+
+static_assert(alignof(QMap<int, QVariant>) == alignof(QMapIntQVariant), "Alignment of QMap<int, QVariant> incompatible");
+static_assert(sizeof(QMap<int, QVariant>) == sizeof(QMapIntQVariant), "Size of QMap<int, QVariant> incompatible");
+
+
+void qffi_QMapIntQVariant_init(QMapIntQVariant* self) {
+    ::qffi_call_default_ctor((QMap<int, QVariant>*)self);
+}
+
+
+void qffi_QMapIntQVariant_clone(QMapIntQVariant const* self, QMapIntQVariant* new_) {
+    new ((QMap<int, QVariant>*)new_) QMap<int, QVariant>(*(QMap<int, QVariant> const*)self);
+}
+
+bool qffi_QMapIntQVariant_equals(QMapIntQVariant const* self, QMapIntQVariant const* other) {
+    return *((QMap<int, QVariant> const*)self) == *((QMap<int, QVariant> const*)other);
+}
+
+
+int qffi_QMapIntQVariant_size(QMapIntQVariant const* _self) {
+    auto* self = (QMap<int, QVariant> const*) _self;
+    return self->size();
+}
+
+bool qffi_QMapIntQVariant_contains(QMapIntQVariant const* _self, int const* key) {
+    auto* self = (QMap<int, QVariant> const*) _self;
+    return self->contains(*key);
+}
+
+QVariant const* qffi_QMapIntQVariant_value(QMapIntQVariant const* _self, int const* key) {
+    auto* self = (QMap<int, QVariant> const*) _self;
+    auto it = self->constFind(*key); return it == self->cend() ? nullptr : &it.value();
+}
+
+void qffi_QMapIntQVariant_insert(QMapIntQVariant * _self, int const* key, QVariant const* value) {
+    auto* self = (QMap<int, QVariant> *) _self;
+    self->insert(*key, *value);
+}
+
+int qffi_QMapIntQVariant_remove(QMapIntQVariant * _self, int const* key) {
+    auto* self = (QMap<int, QVariant> *) _self;
+    return self->remove(*key);
+}
+
+void qffi_QMapIntQVariant_forEach(QMapIntQVariant const* _self, void* data, QffiMapEntryCall call) {
+    auto* self = (QMap<int, QVariant> const*) _self;
+    for (auto it = self->cbegin(); it != self->cend(); ++it) { call(data, &it.key(), &it.value()); }
+}
+
+
+// QMapQStringInt
+
+static_assert(alignof(QMap<QString, int>) == alignof(QMapQStringInt), "Alignment of QMap<QString, int> incompatible");
+static_assert(sizeof(QMap<QString, int>) == sizeof(QMapQStringInt), "Size of QMap<QString, int> incompatible");
+
+
+void qffi_QMapQStringInt_init(QMapQStringInt* self) {
+    ::qffi_call_default_ctor((QMap<QString, int>*)self);
+}
+
+
+void qffi_QMapQStringInt_clone(QMapQStringInt const* self, QMapQStringInt* new_) {
+    new ((QMap<QString, int>*)new_) QMap<QString, int>(*(QMap<QString, int> const*)self);
+}
+
+bool qffi_QMapQStringInt_equals(QMapQStringInt const* self, QMapQStringInt const* other) {
+    return *((QMap<QString, int> const*)self) == *((QMap<QString, int> const*)other);
+}
+
+
+int qffi_QMapQStringInt_size(QMapQStringInt const* _self) {
+    auto* self = (QMap<QString, int> const*) _self;
+    return self->size();
+}
+
+bool qffi_QMapQStringInt_contains(QMapQStringInt const* _self, QString const* key) {
+    auto* self = (QMap<QString, int> const*) _self;
+    return self->contains(*key);
+}
+
+int const* qffi_QMapQStringInt_value(QMapQStringInt const* _self, QString const* key) {
+    auto* self = (QMap<QString, int> const*) _self;
+    auto it = self->constFind(*key); return it == self->cend() ? nullptr : &it.value();
+}
+
+void qffi_QMapQStringInt_insert(QMapQStringInt * _self, QString const* key, int const* value) {
+    auto* self = (QMap<QString, int> *) _self;
+    self->insert(*key, *value);
+}
+
+int qffi_QMapQStringInt_remove(QMapQStringInt * _self, QString const* key) {
+    auto* self = (QMap<QString, int> *) _self;
+    return self->remove(*key);
+}
+
+void qffi_QMapQStringInt_forEach(QMapQStringInt const* _self, void* data, QffiMapEntryCall call) {
+    auto* self = (QMap<QString, int> const*) _self;
+    for (auto it = self->cbegin(); it != self->cend(); ++it) { call(data, &it.key(), &it.value()); }
+}
+
+
+// QMapQStringDouble
+
+static_assert(alignof(QMap<QString, double>) == alignof(QMapQStringDouble), "Alignment of QMap<QString, double> incompatible");
+static_assert(sizeof(QMap<QString, double>) == sizeof(QMapQStringDouble), "Size of QMap<QString, double> incompatible");
+
+
+void qffi_QMapQStringDouble_init(QMapQStringDouble* self) {
+    ::qffi_call_default_ctor((QMap<QString, double>*)self);
+}
+
+
+void qffi_QMapQStringDouble_clone(QMapQStringDouble const* self, QMapQStringDouble* new_) {
+    new ((QMap<QString, double>*)new_) QMap<QString, double>(*(QMap<QString, double> const*)self);
+}
+
+bool qffi_QMapQStringDouble_equals(QMapQStringDouble const* self, QMapQStringDouble const* other) {
+    return *((QMap<QString, double> const*)self) == *((QMap<QString, double> const*)other);
+}
+
+
+int qffi_QMapQStringDouble_size(QMapQStringDouble const* _self) {
+    auto* self = (QMap<QString, double> const*) _self;
+    return self->size();
+}
+
+bool qffi_QMapQStringDouble_contains(QMapQStringDouble const* _self, QString const* key) {
+    auto* self = (QMap<QString, double> const*) _self;
+    return self->contains(*key);
+}
+
+double const* qffi_QMapQStringDouble_value(QMapQStringDouble const* _self, QString const* key) {
+    auto* self = (QMap<QString, double> const*) _self;
+    auto it = self->constFind(*key); return it == self->cend() ? nullptr : &it.value();
+}
+
+void qffi_QMapQStringDouble_insert(QMapQStringDouble * _self, QString const* key, double const* value) {
+    auto* self = (QMap<QString, double> *) _self;
+    self->insert(*key, *value);
+}
+
+int qffi_QMapQStringDouble_remove(QMapQStringDouble * _self, QString const* key) {
+    auto* self = (QMap<QString, double> *) _self;
+    return self->remove(*key);
+}
+
+void qffi_QMapQStringDouble_forEach(QMapQStringDouble const* _self, void* data, QffiMapEntryCall call) {
+    auto* self = (QMap<QString, double> const*) _self;
+    for (auto it = self->cbegin(); it != self->cend(); ++it) { call(data, &it.key(), &it.value()); }
+}
+
+
+// QMapQStringQString
+
+static_assert(alignof(QMap<QString, QString>) == alignof(QMapQStringQString), "Alignment of QMap<QString, QString> incompatible");
+static_assert(sizeof(QMap<QString, QString>) == sizeof(QMapQStringQString), "Size of QMap<QString, QString> incompatible");
+
+
+void qffi_QMapQStringQString_init(QMapQStringQString* self) {
+    ::qffi_call_default_ctor((QMap<QString, QString>*)self);
+}
+
+
+void qffi_QMapQStringQString_clone(QMapQStringQString const* self, QMapQStringQString* new_) {
+    new ((QMap<QString, QString>*)new_) QMap<QString, QString>(*(QMap<QString, QString> const*)self);
+}
+
+bool qffi_QMapQStringQString_equals(QMapQStringQString const* self, QMapQStringQString const* other) {
+    return *((QMap<QString, QString> const*)self) == *((QMap<QString, QString> const*)other);
+}
+
+
+int qffi_QMapQStringQString_size(QMapQStringQString const* _self) {
+    auto* self = (QMap<QString, QString> const*) _self;
+    return self->size();
+}
+
+bool qffi_QMapQStringQString_contains(QMapQStringQString const* _self, QString const* key) {
+    auto* self = (QMap<QString, QString> const*) _self;
+    return self->contains(*key);
+}
+
+QString const* qffi_QMapQStringQString_value(QMapQStringQString const* _self, QString const* key) {
+    auto* self = (QMap<QString, QString> const*) _self;
+    auto it = self->constFind(*key); return it == self->cend() ? nullptr : &it.value();
+}
+
+void qffi_QMapQStringQString_insert(QMapQStringQString * _self, QString const* key, QString const* value) {
+    auto* self = (QMap<QString, QString> *) _self;
+    self->insert(*key, *value);
+}
+
+int qffi_QMapQStringQString_remove(QMapQStringQString * _self, QString const* key) {
+    auto* self = (QMap<QString, QString> *) _self;
+    return self->remove(*key);
+}
+
+void qffi_QMapQStringQString_forEach(QMapQStringQString const* _self, void* data, QffiMapEntryCall call) {
+    auto* self = (QMap<QString, QString> const*) _self;
+    for (auto it = self->cbegin(); it != self->cend(); ++it) { call(data, &it.key(), &it.value()); }
+}
+
+
+// QMapQStringQVariant
+
+static_assert(alignof(QMap<QString, QVariant>) == alignof(QMapQStringQVariant), "Alignment of QMap<QString, QVariant> incompatible");
+static_assert(sizeof(QMap<QString, QVariant>) == sizeof(QMapQStringQVariant), "Size of QMap<QString, QVariant> incompatible");
+
+
+void qffi_QMapQStringQVariant_init(QMapQStringQVariant* self) {
+    ::qffi_call_default_ctor((QMap<QString, QVariant>*)self);
+}
+
+
+void qffi_QMapQStringQVariant_clone(QMapQStringQVariant const* self, QMapQStringQVariant* new_) {
+    new ((QMap<QString, QVariant>*)new_) QMap<QString, QVariant>(*(QMap<QString, QVariant> const*)self);
+}
+
+bool qffi_QMapQStringQVariant_equals(QMapQStringQVariant const* self, QMapQStringQVariant const* other) {
+    return *((QMap<QString, QVariant> const*)self) == *((QMap<QString, QVariant> const*)other);
+}
+
+
+int qffi_QMapQStringQVariant_size(QMapQStringQVariant const* _self) {
+    auto* self = (QMap<QString, QVariant> const*) _self;
+    return self->size();
+}
+
+bool qffi_QMapQStringQVariant_contains(QMapQStringQVariant const* _self, QString const* key) {
+    auto* self = (QMap<QString, QVariant> const*) _self;
+    return self->contains(*key);
+}
+
+QVariant const* qffi_QMapQStringQVariant_value(QMapQStringQVariant const* _self, QString const* key) {
+    auto* self = (QMap<QString, QVariant> const*) _self;
+    auto it = self->constFind(*key); return it == self->cend() ? nullptr : &it.value();
+}
+
+void qffi_QMapQStringQVariant_insert(QMapQStringQVariant * _self, QString const* key, QVariant const* value) {
+    auto* self = (QMap<QString, QVariant> *) _self;
+    self->insert(*key, *value);
+}
+
+int qffi_QMapQStringQVariant_remove(QMapQStringQVariant * _self, QString const* key) {
+    auto* self = (QMap<QString, QVariant> *) _self;
+    return self->remove(*key);
+}
+
+void qffi_QMapQStringQVariant_forEach(QMapQStringQVariant const* _self, void* data, QffiMapEntryCall call) {
+    auto* self = (QMap<QString, QVariant> const*) _self;
+    for (auto it = self->cbegin(); it != self->cend(); ++it) { call(data, &it.key(), &it.value()); }
+}
+
+
+// QHashIntInt
+
+static_assert(alignof(QHash<int, int>) == alignof(QHashIntInt), "Alignment of QHash<int, int> incompatible");
+static_assert(sizeof(QHash<int, int>) == sizeof(QHashIntInt), "Size of QHash<int, int> incompatible");
+
+
+void qffi_QHashIntInt_init(QHashIntInt* self) {
+    ::qffi_call_default_ctor((QHash<int, int>*)self);
+}
+
+
+void qffi_QHashIntInt_clone(QHashIntInt const* self, QHashIntInt* new_) {
+    new ((QHash<int, int>*)new_) QHash<int, int>(*(QHash<int, int> const*)self);
+}
+
+bool qffi_QHashIntInt_equals(QHashIntInt const* self, QHashIntInt const* other) {
+    return *((QHash<int, int> const*)self) == *((QHash<int, int> const*)other);
+}
+
+
+int qffi_QHashIntInt_size(QHashIntInt const* _self) {
+    auto* self = (QHash<int, int> const*) _self;
+    return self->size();
+}
+
+bool qffi_QHashIntInt_contains(QHashIntInt const* _self, int const* key) {
+    auto* self = (QHash<int, int> const*) _self;
+    return self->contains(*key);
+}
+
+int const* qffi_QHashIntInt_value(QHashIntInt const* _self, int const* key) {
+    auto* self = (QHash<int, int> const*) _self;
+    auto it = self->constFind(*key); return it == self->cend() ? nullptr : &it.value();
+}
+
+void qffi_QHashIntInt_insert(QHashIntInt * _self, int const* key, int const* value) {
+    auto* self = (QHash<int, int> *) _self;
+    self->insert(*key, *value);
+}
+
+int qffi_QHashIntInt_remove(QHashIntInt * _self, int const* key) {
+    auto* self = (QHash<int, int> *) _self;
+    return self->remove(*key);
+}
+
+void qffi_QHashIntInt_forEach(QHashIntInt const* _self, void* data, QffiMapEntryCall call) {
+    auto* self = (QHash<int, int> const*) _self;
+    for (auto it = self->cbegin(); it != self->cend(); ++it) { call(data, &it.key(), &it.value()); }
+}
+
+
+// QHashIntQString
+
+static_assert(alignof(QHash<int, QString>) == alignof(QHashIntQString), "Alignment of QHash<int, QString> incompatible");
+static_assert(sizeof(QHash<int, QString>) == sizeof(QHashIntQString), "Size of QHash<int, QString> incompatible");
+
+
+void qffi_QHashIntQString_init(QHashIntQString* self) {
+    ::qffi_call_default_ctor((QHash<int, QString>*)self);
+}
+
+
+void qffi_QHashIntQString_clone(QHashIntQString const* self, QHashIntQString* new_) {
+    new ((QHash<int, QString>*)new_) QHash<int, QString>(*(QHash<int, QString> const*)self);
+}
+
+bool qffi_QHashIntQString_equals(QHashIntQString const* self, QHashIntQString const* other) {
+    return *((QHash<int, QString> const*)self) == *((QHash<int, QString> const*)other);
+}
+
+
+int qffi_QHashIntQString_size(QHashIntQString const* _self) {
+    auto* self = (QHash<int, QString> const*) _self;
+    return self->size();
+}
+
+bool qffi_QHashIntQString_contains(QHashIntQString const* _self, int const* key) {
+    auto* self = (QHash<int, QString> const*) _self;
+    return self->contains(*key);
+}
+
+QString const* qffi_QHashIntQString_value(QHashIntQString const* _self, int const* key) {
+    auto* self = (QHash<int, QString> const*) _self;
+    auto it = self->constFind(*key); return it == self->cend() ? nullptr : &it.value();
+}
+
+void qffi_QHashIntQString_insert(QHashIntQString * _self, int const* key, QString const* value) {
+    auto* self = (QHash<int, QString> *) _self;
+    self->insert(*key, *value);
+}
+
+int qffi_QHashIntQString_remove(QHashIntQString * _self, int const* key) {
+    auto* self = (QHash<int, QString> *) _self;
+    return self->remove(*key);
+}
+
+void qffi_QHashIntQString_forEach(QHashIntQString const* _self, void* data, QffiMapEntryCall call) {
+    auto* self = (QHash<int, QString> const*) _self;
+    for (auto it = self->cbegin(); it != self->cend(); ++it) { call(data, &it.key(), &it.value()); }
+}
+
+
+// QHashIntQByteArray
+
+static_assert(alignof(QHash<int, QByteArray>) == alignof(QHashIntQByteArray), "Alignment of QHash<int, QByteArray> incompatible");
+static_assert(sizeof(QHash<int, QByteArray>) == sizeof(QHashIntQByteArray), "Size of QHash<int, QByteArray> incompatible");
+
+
+void qffi_QHashIntQByteArray_init(QHashIntQByteArray* self) {
+    ::qffi_call_default_ctor((QHash<int, QByteArray>*)self);
+}
+
+
+void qffi_QHashIntQByteArray_clone(QHashIntQByteArray const* self, QHashIntQByteArray* new_) {
+    new ((QHash<int, QByteArray>*)new_) QHash<int, QByteArray>(*(QHash<int, QByteArray> const*)self);
+}
+
+bool qffi_QHashIntQByteArray_equals(QHashIntQByteArray const* self, QHashIntQByteArray const* other) {
+    return *((QHash<int, QByteArray> const*)self) == *((QHash<int, QByteArray> const*)other);
+}
+
+
+int qffi_QHashIntQByteArray_size(QHashIntQByteArray const* _self) {
+    auto* self = (QHash<int, QByteArray> const*) _self;
+    return self->size();
+}
+
+bool qffi_QHashIntQByteArray_contains(QHashIntQByteArray const* _self, int const* key) {
+    auto* self = (QHash<int, QByteArray> const*) _self;
+    return self->contains(*key);
+}
+
+QByteArray const* qffi_QHashIntQByteArray_value(QHashIntQByteArray const* _self, int const* key) {
+    auto* self = (QHash<int, QByteArray> const*) _self;
+    auto it = self->constFind(*key); return it == self->cend() ? nullptr : &it.value();
+}
+
+void qffi_QHashIntQByteArray_insert(QHashIntQByteArray * _self, int const* key, QByteArray const* value) {
+    auto* self = (QHash<int, QByteArray> *) _self;
+    self->insert(*key, *value);
+}
+
+int qffi_QHashIntQByteArray_remove(QHashIntQByteArray * _self, int const* key) {
+    auto* self = (QHash<int, QByteArray> *) _self;
+    return self->remove(*key);
+}
+
+void qffi_QHashIntQByteArray_forEach(QHashIntQByteArray const* _self, void* data, QffiMapEntryCall call) {
+    auto* self = (QHash<int, QByteArray> const*) _self;
+    for (auto it = self->cbegin(); it != self->cend(); ++it) { call(data, &it.key(), &it.value()); }
+}
+
+
+// QHashIntQVariant
+
+static_assert(alignof(QHash<int, QVariant>) == alignof(QHashIntQVariant), "Alignment of QHash<int, QVariant> incompatible");
+static_assert(sizeof(QHash<int, QVariant>) == sizeof(QHashIntQVariant), "Size of QHash<int, QVariant> incompatible");
+
+
+void qffi_QHashIntQVariant_init(QHashIntQVariant* self) {
+    ::qffi_call_default_ctor((QHash<int, QVariant>*)self);
+}
+
+
+void qffi_QHashIntQVariant_clone(QHashIntQVariant const* self, QHashIntQVariant* new_) {
+    new ((QHash<int, QVariant>*)new_) QHash<int, QVariant>(*(QHash<int, QVariant> const*)self);
+}
+
+bool qffi_QHashIntQVariant_equals(QHashIntQVariant const* self, QHashIntQVariant const* other) {
+    return *((QHash<int, QVariant> const*)self) == *((QHash<int, QVariant> const*)other);
+}
+
+
+int qffi_QHashIntQVariant_size(QHashIntQVariant const* _self) {
+    auto* self = (QHash<int, QVariant> const*) _self;
+    return self->size();
+}
+
+bool qffi_QHashIntQVariant_contains(QHashIntQVariant const* _self, int const* key) {
+    auto* self = (QHash<int, QVariant> const*) _self;
+    return self->contains(*key);
+}
+
+QVariant const* qffi_QHashIntQVariant_value(QHashIntQVariant const* _self, int const* key) {
+    auto* self = (QHash<int, QVariant> const*) _self;
+    auto it = self->constFind(*key); return it == self->cend() ? nullptr : &it.value();
+}
+
+void qffi_QHashIntQVariant_insert(QHashIntQVariant * _self, int const* key, QVariant const* value) {
+    auto* self = (QHash<int, QVariant> *) _self;
+    self->insert(*key, *value);
+}
+
+int qffi_QHashIntQVariant_remove(QHashIntQVariant * _self, int const* key) {
+    auto* self = (QHash<int, QVariant> *) _self;
+    return self->remove(*key);
+}
+
+void qffi_QHashIntQVariant_forEach(QHashIntQVariant const* _self, void* data, QffiMapEntryCall call) {
+    auto* self = (QHash<int, QVariant> const*) _self;
+    for (auto it = self->cbegin(); it != self->cend(); ++it) { call(data, &it.key(), &it.value()); }
+}
+
+
+// QHashQStringInt
+
+static_assert(alignof(QHash<QString, int>) == alignof(QHashQStringInt), "Alignment of QHash<QString, int> incompatible");
+static_assert(sizeof(QHash<QString, int>) == sizeof(QHashQStringInt), "Size of QHash<QString, int> incompatible");
+
+
+void qffi_QHashQStringInt_init(QHashQStringInt* self) {
+    ::qffi_call_default_ctor((QHash<QString, int>*)self);
+}
+
+
+void qffi_QHashQStringInt_clone(QHashQStringInt const* self, QHashQStringInt* new_) {
+    new ((QHash<QString, int>*)new_) QHash<QString, int>(*(QHash<QString, int> const*)self);
+}
+
+bool qffi_QHashQStringInt_equals(QHashQStringInt const* self, QHashQStringInt const* other) {
+    return *((QHash<QString, int> const*)self) == *((QHash<QString, int> const*)other);
+}
+
+
+int qffi_QHashQStringInt_size(QHashQStringInt const* _self) {
+    auto* self = (QHash<QString, int> const*) _self;
+    return self->size();
+}
+
+bool qffi_QHashQStringInt_contains(QHashQStringInt const* _self, QString const* key) {
+    auto* self = (QHash<QString, int> const*) _self;
+    return self->contains(*key);
+}
+
+int const* qffi_QHashQStringInt_value(QHashQStringInt const* _self, QString const* key) {
+    auto* self = (QHash<QString, int> const*) _self;
+    auto it = self->constFind(*key); return it == self->cend() ? nullptr : &it.value();
+}
+
+void qffi_QHashQStringInt_insert(QHashQStringInt * _self, QString const* key, int const* value) {
+    auto* self = (QHash<QString, int> *) _self;
+    self->insert(*key, *value);
+}
+
+int qffi_QHashQStringInt_remove(QHashQStringInt * _self, QString const* key) {
+    auto* self = (QHash<QString, int> *) _self;
+    return self->remove(*key);
+}
+
+void qffi_QHashQStringInt_forEach(QHashQStringInt const* _self, void* data, QffiMapEntryCall call) {
+    auto* self = (QHash<QString, int> const*) _self;
+    for (auto it = self->cbegin(); it != self->cend(); ++it) { call(data, &it.key(), &it.value()); }
+}
+
+
+// QHashQStringDouble
+
+static_assert(alignof(QHash<QString, double>) == alignof(QHashQStringDouble), "Alignment of QHash<QString, double> incompatible");
+static_assert(sizeof(QHash<QString, double>) == sizeof(QHashQStringDouble), "Size of QHash<QString, double> incompatible");
+
+
+void qffi_QHashQStringDouble_init(QHashQStringDouble* self) {
+    ::qffi_call_default_ctor((QHash<QString, double>*)self);
+}
+
+
+void qffi_QHashQStringDouble_clone(QHashQStringDouble const* self, QHashQStringDouble* new_) {
+    new ((QHash<QString, double>*)new_) QHash<QString, double>(*(QHash<QString, double> const*)self);
+}
+
+bool qffi_QHashQStringDouble_equals(QHashQStringDouble const* self, QHashQStringDouble const* other) {
+    return *((QHash<QString, double> const*)self) == *((QHash<QString, double> const*)other);
+}
+
+
+int qffi_QHashQStringDouble_size(QHashQStringDouble const* _self) {
+    auto* self = (QHash<QString, double> const*) _self;
+    return self->size();
+}
+
+bool qffi_QHashQStringDouble_contains(QHashQStringDouble const* _self, QString const* key) {
+    auto* self = (QHash<QString, double> const*) _self;
+    return self->contains(*key);
+}
+
+double const* qffi_QHashQStringDouble_value(QHashQStringDouble const* _self, QString const* key) {
+    auto* self = (QHash<QString, double> const*) _self;
+    auto it = self->constFind(*key); return it == self->cend() ? nullptr : &it.value();
+}
+
+void qffi_QHashQStringDouble_insert(QHashQStringDouble * _self, QString const* key, double const* value) {
+    auto* self = (QHash<QString, double> *) _self;
+    self->insert(*key, *value);
+}
+
+int qffi_QHashQStringDouble_remove(QHashQStringDouble * _self, QString const* key) {
+    auto* self = (QHash<QString, double> *) _self;
+    return self->remove(*key);
+}
+
+void qffi_QHashQStringDouble_forEach(QHashQStringDouble const* _self, void* data, QffiMapEntryCall call) {
+    auto* self = (QHash<QString, double> const*) _self;
+    for (auto it = self->cbegin(); it != self->cend(); ++it) { call(data, &it.key(), &it.value()); }
+}
+
+
+// QHashQStringQString
+
+static_assert(alignof(QHash<QString, QString>) == alignof(QHashQStringQString), "Alignment of QHash<QString, QString> incompatible");
+static_assert(sizeof(QHash<QString, QString>) == sizeof(QHashQStringQString), "Size of QHash<QString, QString> incompatible");
+
+
+void qffi_QHashQStringQString_init(QHashQStringQString* self) {
+    ::qffi_call_default_ctor((QHash<QString, QString>*)self);
+}
+
+
+void qffi_QHashQStringQString_clone(QHashQStringQString const* self, QHashQStringQString* new_) {
+    new ((QHash<QString, QString>*)new_) QHash<QString, QString>(*(QHash<QString, QString> const*)self);
+}
+
+bool qffi_QHashQStringQString_equals(QHashQStringQString const* self, QHashQStringQString const* other) {
+    return *((QHash<QString, QString> const*)self) == *((QHash<QString, QString> const*)other);
+}
+
+
+int qffi_QHashQStringQString_size(QHashQStringQString const* _self) {
+    auto* self = (QHash<QString, QString> const*) _self;
+    return self->size();
+}
+
+bool qffi_QHashQStringQString_contains(QHashQStringQString const* _self, QString const* key) {
+    auto* self = (QHash<QString, QString> const*) _self;
+    return self->contains(*key);
+}
+
+QString const* qffi_QHashQStringQString_value(QHashQStringQString const* _self, QString const* key) {
+    auto* self = (QHash<QString, QString> const*) _self;
+    auto it = self->constFind(*key); return it == self->cend() ? nullptr : &it.value();
+}
+
+void qffi_QHashQStringQString_insert(QHashQStringQString * _self, QString const* key, QString const* value) {
+    auto* self = (QHash<QString, QString> *) _self;
+    self->insert(*key, *value);
+}
+
+int qffi_QHashQStringQString_remove(QHashQStringQString * _self, QString const* key) {
+    auto* self = (QHash<QString, QString> *) _self;
+    return self->remove(*key);
+}
+
+void qffi_QHashQStringQString_forEach(QHashQStringQString const* _self, void* data, QffiMapEntryCall call) {
+    auto* self = (QHash<QString, QString> const*) _self;
+    for (auto it = self->cbegin(); it != self->cend(); ++it) { call(data, &it.key(), &it.value()); }
+}
+
+
+// QHashQStringQVariant
+
+static_assert(alignof(QHash<QString, QVariant>) == alignof(QHashQStringQVariant), "Alignment of QHash<QString, QVariant> incompatible");
+static_assert(sizeof(QHash<QString, QVariant>) == sizeof(QHashQStringQVariant), "Size of QHash<QString, QVariant> incompatible");
+
+
+void qffi_QHashQStringQVariant_init(QHashQStringQVariant* self) {
+    ::qffi_call_default_ctor((QHash<QString, QVariant>*)self);
+}
+
+
+void qffi_QHashQStringQVariant_clone(QHashQStringQVariant const* self, QHashQStringQVariant* new_) {
+    new ((QHash<QString, QVariant>*)new_) QHash<QString, QVariant>(*(QHash<QString, QVariant> const*)self);
+}
+
+bool qffi_QHashQStringQVariant_equals(QHashQStringQVariant const* self, QHashQStringQVariant const* other) {
+    return *((QHash<QString, QVariant> const*)self) == *((QHash<QString, QVariant> const*)other);
+}
+
+
+int qffi_QHashQStringQVariant_size(QHashQStringQVariant const* _self) {
+    auto* self = (QHash<QString, QVariant> const*) _self;
+    return self->size();
+}
+
+bool qffi_QHashQStringQVariant_contains(QHashQStringQVariant const* _self, QString const* key) {
+    auto* self = (QHash<QString, QVariant> const*) _self;
+    return self->contains(*key);
+}
+
+QVariant const* qffi_QHashQStringQVariant_value(QHashQStringQVariant const* _self, QString const* key) {
+    auto* self = (QHash<QString, QVariant> const*) _self;
+    auto it = self->constFind(*key); return it == self->cend() ? nullptr : &it.value();
+}
+
+void qffi_QHashQStringQVariant_insert(QHashQStringQVariant * _self, QString const* key, QVariant const* value) {
+    auto* self = (QHash<QString, QVariant> *) _self;
+    self->insert(*key, *value);
+}
+
+int qffi_QHashQStringQVariant_remove(QHashQStringQVariant * _self, QString const* key) {
+    auto* self = (QHash<QString, QVariant> *) _self;
+    return self->remove(*key);
+}
+
+void qffi_QHashQStringQVariant_forEach(QHashQStringQVariant const* _self, void* data, QffiMapEntryCall call) {
+    auto* self = (QHash<QString, QVariant> const*) _self;
+    for (auto it = self->cbegin(); it != self->cend(); ++it) { call(data, &it.key(), &it.value()); }
+}
+
 }
\ No newline at end of file
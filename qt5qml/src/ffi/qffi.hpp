@@ -9,18 +9,24 @@
     #include <QMetaMethod>
     #include <QMetaEnum>
     #include <QMetaProperty>
-    #include <QMetaObject>
     #include <QVariant>
     #include <QTimer>
     #include <QCoreApplication>
     #include <QGuiApplication>
-    #include <QHash>
     #include <QThread>
     #include <QQmlEngine>
     #include <QQmlApplicationEngine>
+    #include <QAbstractItemModel>
+    #include <qffi_listmodel.hpp>
+    #include <qffi_itemmodel.hpp>
+    #include <qffi_sortfilterproxymodel.hpp>
     #include <QList>
-    #include <QList>
+    #include <QVector>
+    #include <QMap>
+    #include <QHash>
     #include <QDebug>
+    #include <QQmlContext>
+    #include <qffi_closure.hpp>
 #endif
 
 #ifdef BINDGEN
@@ -34,6 +40,14 @@ class QList;
 
 class QMetaObject;
 
+typedef void (*QffiClosureCall)(void* data, void** args);
+typedef void (*QffiClosureDrop)(void* data);
+typedef void (*QffiMapEntryCall)(void* data, void const* key, void const* value);
+typedef int (*QffiListModelRowCount)(void const* data);
+typedef void (*QffiListModelData)(void const* data, int row, int role, void* result);
+struct QffiItemModelVTable;
+struct QffiSortFilterProxyModelVTable;
+
 
 
 class QFFI_CLASSNAME(QString) {
@@ -113,16 +127,20 @@ class QFFI_CLASSNAME(QCoreApplication);
 
 class QFFI_CLASSNAME(QGuiApplication);
 
-class QHashIntQByteArray {
-    void* __d;
-};
-
 class QFFI_CLASSNAME(QThread);
 
 class QFFI_CLASSNAME(QQmlEngine);
 
 class QFFI_CLASSNAME(QQmlApplicationEngine);
 
+class QFFI_CLASSNAME(QAbstractItemModel);
+
+class QFFI_CLASSNAME(QffiListModel);
+
+class QFFI_CLASSNAME(QffiItemModel);
+
+class QFFI_CLASSNAME(QffiSortFilterProxyModel);
+
 class QFFI_CLASSNAME(QObjectList) {
     void* __d;
 };
@@ -131,6 +149,122 @@ class QFFI_CLASSNAME(QStringList) {
     void* __d;
 };
 
+class QListBool {
+    void* __d;
+};
+
+class QListInt {
+    void* __d;
+};
+
+class QListLongLong {
+    void* __d;
+};
+
+class QListDouble {
+    void* __d;
+};
+
+class QListQByteArray {
+    void* __d;
+};
+
+class QListQVariant {
+    void* __d;
+};
+
+class QVectorBool {
+    void* __d;
+};
+
+class QVectorInt {
+    void* __d;
+};
+
+class QVectorLongLong {
+    void* __d;
+};
+
+class QVectorDouble {
+    void* __d;
+};
+
+class QVectorQString {
+    void* __d;
+};
+
+class QVectorQByteArray {
+    void* __d;
+};
+
+class QVectorQVariant {
+    void* __d;
+};
+
+class QMapIntInt {
+    void* __d;
+};
+
+class QMapIntQString {
+    void* __d;
+};
+
+class QMapIntQByteArray {
+    void* __d;
+};
+
+class QMapIntQVariant {
+    void* __d;
+};
+
+class QMapQStringInt {
+    void* __d;
+};
+
+class QMapQStringDouble {
+    void* __d;
+};
+
+class QMapQStringQString {
+    void* __d;
+};
+
+class QMapQStringQVariant {
+    void* __d;
+};
+
+class QHashIntInt {
+    void* __d;
+};
+
+class QHashIntQString {
+    void* __d;
+};
+
+class QHashIntQByteArray {
+    void* __d;
+};
+
+class QHashIntQVariant {
+    void* __d;
+};
+
+class QHashQStringInt {
+    void* __d;
+};
+
+class QHashQStringDouble {
+    void* __d;
+};
+
+class QHashQStringQString {
+    void* __d;
+};
+
+class QHashQStringQVariant {
+    void* __d;
+};
+
 #undef QFFI_CLASSNAME
 
 extern "C" {
@@ -172,6 +306,7 @@ void qffi_QObject_deleteLater(QObject * self);
 const QMetaObject* qffi_QObject_metaObject(QObject const* self);
 void qffi_QObject_moveToThread(QObject * self, QThread* targetThread);
 void qffi_QObject_connect(QObject const* self, const char* signal, const QObject* receiver, const char* method, int type_, QMetaObjectConnection* result);
+void qffi_QObject_connectClosure(QObject const* self, const char* signal, QffiClosureCall call, void* data, QffiClosureDrop drop, int type_, QMetaObjectConnection* result);
 bool qffi_QObject_disconnectConnection(const QMetaObjectConnection* connection);
 bool qffi_QObject_disconnect2(QObject const* self, const QObject* receiver, const char* method);
 bool qffi_QObject_disconnect3(QObject const* self, const char* signal, const QObject* receiver, const char* method);
@@ -282,26 +417,73 @@ int qffi_QGuiApplication_exec();
 QGuiApplication* qffi_QGuiApplication_init(int* argc, char const** argv);
 
 
-void qffi_QHashIntQByteArray_init(QHashIntQByteArray* self);
-void qffi_QHashIntQByteArray_clone(QHashIntQByteArray const* self, QHashIntQByteArray* new_);
-bool qffi_QHashIntQByteArray_equals(QHashIntQByteArray const* self, QHashIntQByteArray const* other);
-int qffi_QHashIntQByteArray_size(QHashIntQByteArray const* self);
-void qffi_QHashIntQByteArray_insert(QHashIntQByteArray * self, const int* key, const QByteArray* value);
-
-
 
 
+QQmlEngine* qffi_QQmlEngine_init(QObject* parent);
+QObject* qffi_QQmlEngine_singletonInstance(QQmlEngine * self, int typeId);
 
 
 QQmlApplicationEngine* qffi_QQmlApplicationEngine_init(QObject* parent);
 void qffi_QQmlApplicationEngine_load(QQmlApplicationEngine * self, const QUrl* url);
 void qffi_QQmlApplicationEngine_rootObjects(QQmlApplicationEngine * self, QObjectList* result);
+void qffi_QQmlApplicationEngine_setContextProperty(QQmlApplicationEngine * self, const QString* name, QObject* value);
+
+
+void qffi_QAbstractItemModel_index(QAbstractItemModel const* self, int row, int column, const QModelIndex* parent, QModelIndex* result);
+void qffi_QAbstractItemModel_parent(QAbstractItemModel const* self, const QModelIndex* index, QModelIndex* result);
+void qffi_QAbstractItemModel_sibling(QAbstractItemModel const* self, int row, int column, const QModelIndex* index, QModelIndex* result);
+void qffi_QAbstractItemModel_data(QAbstractItemModel const* self, const QModelIndex* index, int role, QVariant* result);
+int qffi_QAbstractItemModel_flags(QAbstractItemModel const* self, const QModelIndex* index);
+int qffi_QAbstractItemModel_rowCount(QAbstractItemModel const* self, const QModelIndex* parent);
+int qffi_QAbstractItemModel_columnCount(QAbstractItemModel const* self, const QModelIndex* parent);
+bool qffi_QAbstractItemModel_setData(QAbstractItemModel * self, const QModelIndex* index, const QVariant* value, int role);
+void qffi_QAbstractItemModel_headerData(QAbstractItemModel const* self, int section, int orientation, int role, QVariant* result);
+void qffi_QAbstractItemModel_roleNames(QAbstractItemModel const* self, QHashIntQByteArray* result);
+
+
+QffiListModel* qffi_QffiListModel_init(QObject* parent, void* data, QffiListModelRowCount rowCount, QffiListModelData itemData, QffiClosureDrop drop, const QHashIntQByteArray* roleNames);
+void qffi_QffiListModel_beginInsertRows(QffiListModel * self, int first, int last);
+void qffi_QffiListModel_endInsertRows(QffiListModel * self);
+void qffi_QffiListModel_beginRemoveRows(QffiListModel * self, int first, int last);
+void qffi_QffiListModel_endRemoveRows(QffiListModel * self);
+bool qffi_QffiListModel_beginMoveRows(QffiListModel * self, int first, int last, int destination);
+void qffi_QffiListModel_endMoveRows(QffiListModel * self);
+void qffi_QffiListModel_beginResetModel(QffiListModel * self);
+void qffi_QffiListModel_endResetModel(QffiListModel * self);
+void qffi_QffiListModel_dataChanged(QffiListModel * self, int first, int last);
+
+
+QffiItemModel* qffi_QffiItemModel_init(QObject* parent, void* data, const QffiItemModelVTable* vtable);
+void qffi_QffiItemModel_beginInsertRows(QffiItemModel * self, const QModelIndex* parent, int first, int last);
+void qffi_QffiItemModel_endInsertRows(QffiItemModel * self);
+void qffi_QffiItemModel_beginRemoveRows(QffiItemModel * self, const QModelIndex* parent, int first, int last);
+void qffi_QffiItemModel_endRemoveRows(QffiItemModel * self);
+bool qffi_QffiItemModel_beginMoveRows(QffiItemModel * self, const QModelIndex* sourceParent, int first, int last, const QModelIndex* destinationParent, int destination);
+void qffi_QffiItemModel_endMoveRows(QffiItemModel * self);
+void qffi_QffiItemModel_beginInsertColumns(QffiItemModel * self, const QModelIndex* parent, int first, int last);
+void qffi_QffiItemModel_endInsertColumns(QffiItemModel * self);
+void qffi_QffiItemModel_beginRemoveColumns(QffiItemModel * self, const QModelIndex* parent, int first, int last);
+void qffi_QffiItemModel_endRemoveColumns(QffiItemModel * self);
+void qffi_QffiItemModel_beginResetModel(QffiItemModel * self);
+void qffi_QffiItemModel_endResetModel(QffiItemModel * self);
+void qffi_QffiItemModel_dataChanged(QffiItemModel * self, const QModelIndex* topLeft, const QModelIndex* bottomRight);
+
+
+QffiSortFilterProxyModel* qffi_QffiSortFilterProxyModel_init(QObject* parent, void* data, const QffiSortFilterProxyModelVTable* vtable);
+void qffi_QffiSortFilterProxyModel_setSourceModel(QffiSortFilterProxyModel * self, QAbstractItemModel* sourceModel);
+QAbstractItemModel* qffi_QffiSortFilterProxyModel_sourceModel(QffiSortFilterProxyModel const* self);
+void qffi_QffiSortFilterProxyModel_invalidateFilter(QffiSortFilterProxyModel * self);
+void qffi_QffiSortFilterProxyModel_invalidate(QffiSortFilterProxyModel * self);
+void qffi_QffiSortFilterProxyModel_sort(QffiSortFilterProxyModel * self, int column, int order);
+void qffi_QffiSortFilterProxyModel_mapToSource(QffiSortFilterProxyModel const* self, const QModelIndex* proxyIndex, QModelIndex* result);
+void qffi_QffiSortFilterProxyModel_mapFromSource(QffiSortFilterProxyModel const* self, const QModelIndex* sourceIndex, QModelIndex* result);
 
 
 void qffi_QObjectList_init(QObjectList* self);
 void qffi_QObjectList_clone(QObjectList const* self, QObjectList* new_);
 bool qffi_QObjectList_equals(QObjectList const* self, QObjectList const* other);
 int qffi_QObjectList_size(QObjectList const* self);
+QObject* const* qffi_QObjectList_at(QObjectList const* self, int index);
 QObject* const* qffi_QObjectList_asSlice(QObjectList const* self, int* size);
 void qffi_QObjectList_append(QObjectList * self, QObject* const* item);
 void qffi_QObjectList_appendList(QObjectList * self, QList<QObject*> const* item);
@@ -313,6 +495,7 @@ void qffi_QStringList_init(QStringList* self);
 void qffi_QStringList_clone(QStringList const* self, QStringList* new_);
 bool qffi_QStringList_equals(QStringList const* self, QStringList const* other);
 int qffi_QStringList_size(QStringList const* self);
+QString const* qffi_QStringList_at(QStringList const* self, int index);
 QString const* qffi_QStringList_asSlice(QStringList const* self, int* size);
 void qffi_QStringList_append(QStringList * self, QString const* item);
 void qffi_QStringList_appendList(QStringList * self, QList<QString> const* item);
@@ -320,5 +503,318 @@ void qffi_QStringList_appendSlice(QStringList * self, QString const* items, int
 void qffi_QStringList_reserveAdditional(QStringList * self, int additional);
 
 
+void qffi_QListBool_init(QListBool* self);
+void qffi_QListBool_clone(QListBool const* self, QListBool* new_);
+bool qffi_QListBool_equals(QListBool const* self, QListBool const* other);
+int qffi_QListBool_size(QListBool const* self);
+bool const* qffi_QListBool_at(QListBool const* self, int index);
+void qffi_QListBool_append(QListBool * self, bool const* item);
+void qffi_QListBool_appendList(QListBool * self, QList<bool> const* item);
+void qffi_QListBool_appendSlice(QListBool * self, bool const* items, int size);
+void qffi_QListBool_reserveAdditional(QListBool * self, int additional);
+
+
+void qffi_QListInt_init(QListInt* self);
+void qffi_QListInt_clone(QListInt const* self, QListInt* new_);
+bool qffi_QListInt_equals(QListInt const* self, QListInt const* other);
+int qffi_QListInt_size(QListInt const* self);
+int const* qffi_QListInt_at(QListInt const* self, int index);
+void qffi_QListInt_append(QListInt * self, int const* item);
+void qffi_QListInt_appendList(QListInt * self, QList<int> const* item);
+void qffi_QListInt_appendSlice(QListInt * self, int const* items, int size);
+void qffi_QListInt_reserveAdditional(QListInt * self, int additional);
+
+
+void qffi_QListLongLong_init(QListLongLong* self);
+void qffi_QListLongLong_clone(QListLongLong const* self, QListLongLong* new_);
+bool qffi_QListLongLong_equals(QListLongLong const* self, QListLongLong const* other);
+int qffi_QListLongLong_size(QListLongLong const* self);
+long long const* qffi_QListLongLong_at(QListLongLong const* self, int index);
+void qffi_QListLongLong_append(QListLongLong * self, long long const* item);
+void qffi_QListLongLong_appendList(QListLongLong * self, QList<long long> const* item);
+void qffi_QListLongLong_appendSlice(QListLongLong * self, long long const* items, int size);
+void qffi_QListLongLong_reserveAdditional(QListLongLong * self, int additional);
+
+
+void qffi_QListDouble_init(QListDouble* self);
+void qffi_QListDouble_clone(QListDouble const* self, QListDouble* new_);
+bool qffi_QListDouble_equals(QListDouble const* self, QListDouble const* other);
+int qffi_QListDouble_size(QListDouble const* self);
+double const* qffi_QListDouble_at(QListDouble const* self, int index);
+void qffi_QListDouble_append(QListDouble * self, double const* item);
+void qffi_QListDouble_appendList(QListDouble * self, QList<double> const* item);
+void qffi_QListDouble_appendSlice(QListDouble * self, double const* items, int size);
+void qffi_QListDouble_reserveAdditional(QListDouble * self, int additional);
+
+
+void qffi_QListQByteArray_init(QListQByteArray* self);
+void qffi_QListQByteArray_clone(QListQByteArray const* self, QListQByteArray* new_);
+bool qffi_QListQByteArray_equals(QListQByteArray const* self, QListQByteArray const* other);
+int qffi_QListQByteArray_size(QListQByteArray const* self);
+QByteArray const* qffi_QListQByteArray_at(QListQByteArray const* self, int index);
+QByteArray const* qffi_QListQByteArray_asSlice(QListQByteArray const* self, int* size);
+void qffi_QListQByteArray_append(QListQByteArray * self, QByteArray const* item);
+void qffi_QListQByteArray_appendList(QListQByteArray * self, QList<QByteArray> const* item);
+void qffi_QListQByteArray_appendSlice(QListQByteArray * self, QByteArray const* items, int size);
+void qffi_QListQByteArray_reserveAdditional(QListQByteArray * self, int additional);
+
+
+void qffi_QListQVariant_init(QListQVariant* self);
+void qffi_QListQVariant_clone(QListQVariant const* self, QListQVariant* new_);
+bool qffi_QListQVariant_equals(QListQVariant const* self, QListQVariant const* other);
+int qffi_QListQVariant_size(QListQVariant const* self);
+QVariant const* qffi_QListQVariant_at(QListQVariant const* self, int index);
+void qffi_QListQVariant_append(QListQVariant * self, QVariant const* item);
+void qffi_QListQVariant_appendList(QListQVariant * self, QList<QVariant> const* item);
+void qffi_QListQVariant_appendSlice(QListQVariant * self, QVariant const* items, int size);
+void qffi_QListQVariant_reserveAdditional(QListQVariant * self, int additional);
+
+
+void qffi_QVectorBool_init(QVectorBool* self);
+void qffi_QVectorBool_clone(QVectorBool const* self, QVectorBool* new_);
+bool qffi_QVectorBool_equals(QVectorBool const* self, QVectorBool const* other);
+int qffi_QVectorBool_size(QVectorBool const* self);
+bool const* qffi_QVectorBool_asSlice(QVectorBool const* self, int* size);
+void qffi_QVectorBool_append(QVectorBool * self, bool const* item);
+void qffi_QVectorBool_appendSlice(QVectorBool * self, bool const* items, int size);
+void qffi_QVectorBool_reserveAdditional(QVectorBool * self, int additional);
+
+
+void qffi_QVectorInt_init(QVectorInt* self);
+void qffi_QVectorInt_clone(QVectorInt const* self, QVectorInt* new_);
+bool qffi_QVectorInt_equals(QVectorInt const* self, QVectorInt const* other);
+int qffi_QVectorInt_size(QVectorInt const* self);
+int const* qffi_QVectorInt_asSlice(QVectorInt const* self, int* size);
+void qffi_QVectorInt_append(QVectorInt * self, int const* item);
+void qffi_QVectorInt_appendSlice(QVectorInt * self, int const* items, int size);
+void qffi_QVectorInt_reserveAdditional(QVectorInt * self, int additional);
+
+
+void qffi_QVectorLongLong_init(QVectorLongLong* self);
+void qffi_QVectorLongLong_clone(QVectorLongLong const* self, QVectorLongLong* new_);
+bool qffi_QVectorLongLong_equals(QVectorLongLong const* self, QVectorLongLong const* other);
+int qffi_QVectorLongLong_size(QVectorLongLong const* self);
+long long const* qffi_QVectorLongLong_asSlice(QVectorLongLong const* self, int* size);
+void qffi_QVectorLongLong_append(QVectorLongLong * self, long long const* item);
+void qffi_QVectorLongLong_appendSlice(QVectorLongLong * self, long long const* items, int size);
+void qffi_QVectorLongLong_reserveAdditional(QVectorLongLong * self, int additional);
+
+
+void qffi_QVectorDouble_init(QVectorDouble* self);
+void qffi_QVectorDouble_clone(QVectorDouble const* self, QVectorDouble* new_);
+bool qffi_QVectorDouble_equals(QVectorDouble const* self, QVectorDouble const* other);
+int qffi_QVectorDouble_size(QVectorDouble const* self);
+double const* qffi_QVectorDouble_asSlice(QVectorDouble const* self, int* size);
+void qffi_QVectorDouble_append(QVectorDouble * self, double const* item);
+void qffi_QVectorDouble_appendSlice(QVectorDouble * self, double const* items, int size);
+void qffi_QVectorDouble_reserveAdditional(QVectorDouble * self, int additional);
+
+
+void qffi_QVectorQString_init(QVectorQString* self);
+void qffi_QVectorQString_clone(QVectorQString const* self, QVectorQString* new_);
+bool qffi_QVectorQString_equals(QVectorQString const* self, QVectorQString const* other);
+int qffi_QVectorQString_size(QVectorQString const* self);
+QString const* qffi_QVectorQString_asSlice(QVectorQString const* self, int* size);
+void qffi_QVectorQString_append(QVectorQString * self, QString const* item);
+void qffi_QVectorQString_appendSlice(QVectorQString * self, QString const* items, int size);
+void qffi_QVectorQString_reserveAdditional(QVectorQString * self, int additional);
+
+
+void qffi_QVectorQByteArray_init(QVectorQByteArray* self);
+void qffi_QVectorQByteArray_clone(QVectorQByteArray const* self, QVectorQByteArray* new_);
+bool qffi_QVectorQByteArray_equals(QVectorQByteArray const* self, QVectorQByteArray const* other);
+int qffi_QVectorQByteArray_size(QVectorQByteArray const* self);
+QByteArray const* qffi_QVectorQByteArray_asSlice(QVectorQByteArray const* self, int* size);
+void qffi_QVectorQByteArray_append(QVectorQByteArray * self, QByteArray const* item);
+void qffi_QVectorQByteArray_appendSlice(QVectorQByteArray * self, QByteArray const* items, int size);
+void qffi_QVectorQByteArray_reserveAdditional(QVectorQByteArray * self, int additional);
+
+
+void qffi_QVectorQVariant_init(QVectorQVariant* self);
+void qffi_QVectorQVariant_clone(QVectorQVariant const* self, QVectorQVariant* new_);
+bool qffi_QVectorQVariant_equals(QVectorQVariant const* self, QVectorQVariant const* other);
+int qffi_QVectorQVariant_size(QVectorQVariant const* self);
+QVariant const* qffi_QVectorQVariant_asSlice(QVectorQVariant const* self, int* size);
+void qffi_QVectorQVariant_append(QVectorQVariant * self, QVariant const* item);
+void qffi_QVectorQVariant_appendSlice(QVectorQVariant * self, QVariant const* items, int size);
+void qffi_QVectorQVariant_reserveAdditional(QVectorQVariant * self, int additional);
+
+
+void qffi_QMapIntInt_init(QMapIntInt* self);
+void qffi_QMapIntInt_clone(QMapIntInt const* self, QMapIntInt* new_);
+bool qffi_QMapIntInt_equals(QMapIntInt const* self, QMapIntInt const* other);
+int qffi_QMapIntInt_size(QMapIntInt const* self);
+bool qffi_QMapIntInt_contains(QMapIntInt const* self, int const* key);
+int const* qffi_QMapIntInt_value(QMapIntInt const* self, int const* key);
+void qffi_QMapIntInt_insert(QMapIntInt * self, int const* key, int const* value);
+int qffi_QMapIntInt_remove(QMapIntInt * self, int const* key);
+void qffi_QMapIntInt_forEach(QMapIntInt const* self, void* data, QffiMapEntryCall call);
+
+
+void qffi_QMapIntQString_init(QMapIntQString* self);
+void qffi_QMapIntQString_clone(QMapIntQString const* self, QMapIntQString* new_);
+bool qffi_QMapIntQString_equals(QMapIntQString const* self, QMapIntQString const* other);
+int qffi_QMapIntQString_size(QMapIntQString const* self);
+bool qffi_QMapIntQString_contains(QMapIntQString const* self, int const* key);
+QString const* qffi_QMapIntQString_value(QMapIntQString const* self, int const* key);
+void qffi_QMapIntQString_insert(QMapIntQString * self, int const* key, QString const* value);
+int qffi_QMapIntQString_remove(QMapIntQString * self, int const* key);
+void qffi_QMapIntQString_forEach(QMapIntQString const* self, void* data, QffiMapEntryCall call);
+
+
+void qffi_QMapIntQByteArray_init(QMapIntQByteArray* self);
+void qffi_QMapIntQByteArray_clone(QMapIntQByteArray const* self, QMapIntQByteArray* new_);
+bool qffi_QMapIntQByteArray_equals(QMapIntQByteArray const* self, QMapIntQByteArray const* other);
+int qffi_QMapIntQByteArray_size(QMapIntQByteArray const* self);
+bool qffi_QMapIntQByteArray_contains(QMapIntQByteArray const* self, int const* key);
+QByteArray const* qffi_QMapIntQByteArray_value(QMapIntQByteArray const* self, int const* key);
+void qffi_QMapIntQByteArray_insert(QMapIntQByteArray * self, int const* key, QByteArray const* value);
+int qffi_QMapIntQByteArray_remove(QMapIntQByteArray * self, int const* key);
+void qffi_QMapIntQByteArray_forEach(QMapIntQByteArray const* self, void* data, QffiMapEntryCall call);
+
+
+void qffi_QMapIntQVariant_init(QMapIntQVariant* self);
+void qffi_QMapIntQVariant_clone(QMapIntQVariant const* self, QMapIntQVariant* new_);
+bool qffi_QMapIntQVariant_equals(QMapIntQVariant const* self, QMapIntQVariant const* other);
+int qffi_QMapIntQVariant_size(QMapIntQVariant const* self);
+bool qffi_QMapIntQVariant_contains(QMapIntQVariant const* self, int const* key);
+QVariant const* qffi_QMapIntQVariant_value(QMapIntQVariant const* self, int const* key);
+void qffi_QMapIntQVariant_insert(QMapIntQVariant * self, int const* key, QVariant const* value);
+int qffi_QMapIntQVariant_remove(QMapIntQVariant * self, int const* key);
+void qffi_QMapIntQVariant_forEach(QMapIntQVariant const* self, void* data, QffiMapEntryCall call);
+
+
+void qffi_QMapQStringInt_init(QMapQStringInt* self);
+void qffi_QMapQStringInt_clone(QMapQStringInt const* self, QMapQStringInt* new_);
+bool qffi_QMapQStringInt_equals(QMapQStringInt const* self, QMapQStringInt const* other);
+int qffi_QMapQStringInt_size(QMapQStringInt const* self);
+bool qffi_QMapQStringInt_contains(QMapQStringInt const* self, QString const* key);
+int const* qffi_QMapQStringInt_value(QMapQStringInt const* self, QString const* key);
+void qffi_QMapQStringInt_insert(QMapQStringInt * self, QString const* key, int const* value);
+int qffi_QMapQStringInt_remove(QMapQStringInt * self, QString const* key);
+void qffi_QMapQStringInt_forEach(QMapQStringInt const* self, void* data, QffiMapEntryCall call);
+
+
+void qffi_QMapQStringDouble_init(QMapQStringDouble* self);
+void qffi_QMapQStringDouble_clone(QMapQStringDouble const* self, QMapQStringDouble* new_);
+bool qffi_QMapQStringDouble_equals(QMapQStringDouble const* self, QMapQStringDouble const* other);
+int qffi_QMapQStringDouble_size(QMapQStringDouble const* self);
+bool qffi_QMapQStringDouble_contains(QMapQStringDouble const* self, QString const* key);
+double const* qffi_QMapQStringDouble_value(QMapQStringDouble const* self, QString const* key);
+void qffi_QMapQStringDouble_insert(QMapQStringDouble * self, QString const* key, double const* value);
+int qffi_QMapQStringDouble_remove(QMapQStringDouble * self, QString const* key);
+void qffi_QMapQStringDouble_forEach(QMapQStringDouble const* self, void* data, QffiMapEntryCall call);
+
+
+void qffi_QMapQStringQString_init(QMapQStringQString* self);
+void qffi_QMapQStringQString_clone(QMapQStringQString const* self, QMapQStringQString* new_);
+bool qffi_QMapQStringQString_equals(QMapQStringQString const* self, QMapQStringQString const* other);
+int qffi_QMapQStringQString_size(QMapQStringQString const* self);
+bool qffi_QMapQStringQString_contains(QMapQStringQString const* self, QString const* key);
+QString const* qffi_QMapQStringQString_value(QMapQStringQString const* self, QString const* key);
+void qffi_QMapQStringQString_insert(QMapQStringQString * self, QString const* key, QString const* value);
+int qffi_QMapQStringQString_remove(QMapQStringQString * self, QString const* key);
+void qffi_QMapQStringQString_forEach(QMapQStringQString const* self, void* data, QffiMapEntryCall call);
+
+
+void qffi_QMapQStringQVariant_init(QMapQStringQVariant* self);
+void qffi_QMapQStringQVariant_clone(QMapQStringQVariant const* self, QMapQStringQVariant* new_);
+bool qffi_QMapQStringQVariant_equals(QMapQStringQVariant const* self, QMapQStringQVariant const* other);
+int qffi_QMapQStringQVariant_size(QMapQStringQVariant const* self);
+bool qffi_QMapQStringQVariant_contains(QMapQStringQVariant const* self, QString const* key);
+QVariant const* qffi_QMapQStringQVariant_value(QMapQStringQVariant const* self, QString const* key);
+void qffi_QMapQStringQVariant_insert(QMapQStringQVariant * self, QString const* key, QVariant const* value);
+int qffi_QMapQStringQVariant_remove(QMapQStringQVariant * self, QString const* key);
+void qffi_QMapQStringQVariant_forEach(QMapQStringQVariant const* self, void* data, QffiMapEntryCall call);
+
+
+void qffi_QHashIntInt_init(QHashIntInt* self);
+void qffi_QHashIntInt_clone(QHashIntInt const* self, QHashIntInt* new_);
+bool qffi_QHashIntInt_equals(QHashIntInt const* self, QHashIntInt const* other);
+int qffi_QHashIntInt_size(QHashIntInt const* self);
+bool qffi_QHashIntInt_contains(QHashIntInt const* self, int const* key);
+int const* qffi_QHashIntInt_value(QHashIntInt const* self, int const* key);
+void qffi_QHashIntInt_insert(QHashIntInt * self, int const* key, int const* value);
+int qffi_QHashIntInt_remove(QHashIntInt * self, int const* key);
+void qffi_QHashIntInt_forEach(QHashIntInt const* self, void* data, QffiMapEntryCall call);
+
+
+void qffi_QHashIntQString_init(QHashIntQString* self);
+void qffi_QHashIntQString_clone(QHashIntQString const* self, QHashIntQString* new_);
+bool qffi_QHashIntQString_equals(QHashIntQString const* self, QHashIntQString const* other);
+int qffi_QHashIntQString_size(QHashIntQString const* self);
+bool qffi_QHashIntQString_contains(QHashIntQString const* self, int const* key);
+QString const* qffi_QHashIntQString_value(QHashIntQString const* self, int const* key);
+void qffi_QHashIntQString_insert(QHashIntQString * self, int const* key, QString const* value);
+int qffi_QHashIntQString_remove(QHashIntQString * self, int const* key);
+void qffi_QHashIntQString_forEach(QHashIntQString const* self, void* data, QffiMapEntryCall call);
+
+
+void qffi_QHashIntQByteArray_init(QHashIntQByteArray* self);
+void qffi_QHashIntQByteArray_clone(QHashIntQByteArray const* self, QHashIntQByteArray* new_);
+bool qffi_QHashIntQByteArray_equals(QHashIntQByteArray const* self, QHashIntQByteArray const* other);
+int qffi_QHashIntQByteArray_size(QHashIntQByteArray const* self);
+bool qffi_QHashIntQByteArray_contains(QHashIntQByteArray const* self, int const* key);
+QByteArray const* qffi_QHashIntQByteArray_value(QHashIntQByteArray const* self, int const* key);
+void qffi_QHashIntQByteArray_insert(QHashIntQByteArray * self, int const* key, QByteArray const* value);
+int qffi_QHashIntQByteArray_remove(QHashIntQByteArray * self, int const* key);
+void qffi_QHashIntQByteArray_forEach(QHashIntQByteArray const* self, void* data, QffiMapEntryCall call);
+
+
+void qffi_QHashIntQVariant_init(QHashIntQVariant* self);
+void qffi_QHashIntQVariant_clone(QHashIntQVariant const* self, QHashIntQVariant* new_);
+bool qffi_QHashIntQVariant_equals(QHashIntQVariant const* self, QHashIntQVariant const* other);
+int qffi_QHashIntQVariant_size(QHashIntQVariant const* self);
+bool qffi_QHashIntQVariant_contains(QHashIntQVariant const* self, int const* key);
+QVariant const* qffi_QHashIntQVariant_value(QHashIntQVariant const* self, int const* key);
+void qffi_QHashIntQVariant_insert(QHashIntQVariant * self, int const* key, QVariant const* value);
+int qffi_QHashIntQVariant_remove(QHashIntQVariant * self, int const* key);
+void qffi_QHashIntQVariant_forEach(QHashIntQVariant const* self, void* data, QffiMapEntryCall call);
+
+
+void qffi_QHashQStringInt_init(QHashQStringInt* self);
+void qffi_QHashQStringInt_clone(QHashQStringInt const* self, QHashQStringInt* new_);
+bool qffi_QHashQStringInt_equals(QHashQStringInt const* self, QHashQStringInt const* other);
+int qffi_QHashQStringInt_size(QHashQStringInt const* self);
+bool qffi_QHashQStringInt_contains(QHashQStringInt const* self, QString const* key);
+int const* qffi_QHashQStringInt_value(QHashQStringInt const* self, QString const* key);
+void qffi_QHashQStringInt_insert(QHashQStringInt * self, QString const* key, int const* value);
+int qffi_QHashQStringInt_remove(QHashQStringInt * self, QString const* key);
+void qffi_QHashQStringInt_forEach(QHashQStringInt const* self, void* data, QffiMapEntryCall call);
+
+
+void qffi_QHashQStringDouble_init(QHashQStringDouble* self);
+void qffi_QHashQStringDouble_clone(QHashQStringDouble const* self, QHashQStringDouble* new_);
+bool qffi_QHashQStringDouble_equals(QHashQStringDouble const* self, QHashQStringDouble const* other);
+int qffi_QHashQStringDouble_size(QHashQStringDouble const* self);
+bool qffi_QHashQStringDouble_contains(QHashQStringDouble const* self, QString const* key);
+double const* qffi_QHashQStringDouble_value(QHashQStringDouble const* self, QString const* key);
+void qffi_QHashQStringDouble_insert(QHashQStringDouble * self, QString const* key, double const* value);
+int qffi_QHashQStringDouble_remove(QHashQStringDouble * self, QString const* key);
+void qffi_QHashQStringDouble_forEach(QHashQStringDouble const* self, void* data, QffiMapEntryCall call);
+
+
+void qffi_QHashQStringQString_init(QHashQStringQString* self);
+void qffi_QHashQStringQString_clone(QHashQStringQString const* self, QHashQStringQString* new_);
+bool qffi_QHashQStringQString_equals(QHashQStringQString const* self, QHashQStringQString const* other);
+int qffi_QHashQStringQString_size(QHashQStringQString const* self);
+bool qffi_QHashQStringQString_contains(QHashQStringQString const* self, QString const* key);
+QString const* qffi_QHashQStringQString_value(QHashQStringQString const* self, QString const* key);
+void qffi_QHashQStringQString_insert(QHashQStringQString * self, QString const* key, QString const* value);
+int qffi_QHashQStringQString_remove(QHashQStringQString * self, QString const* key);
+void qffi_QHashQStringQString_forEach(QHashQStringQString const* self, void* data, QffiMapEntryCall call);
+
+
+void qffi_QHashQStringQVariant_init(QHashQStringQVariant* self);
+void qffi_QHashQStringQVariant_clone(QHashQStringQVariant const* self, QHashQStringQVariant* new_);
+bool qffi_QHashQStringQVariant_equals(QHashQStringQVariant const* self, QHashQStringQVariant const* other);
+int qffi_QHashQStringQVariant_size(QHashQStringQVariant const* self);
+bool qffi_QHashQStringQVariant_contains(QHashQStringQVariant const* self, QString const* key);
+QVariant const* qffi_QHashQStringQVariant_value(QHashQStringQVariant const* self, QString const* key);
+void qffi_QHashQStringQVariant_insert(QHashQStringQVariant * self, QString const* key, QVariant const* value);
+int qffi_QHashQStringQVariant_remove(QHashQStringQVariant * self, QString const* key);
+void qffi_QHashQStringQVariant_forEach(QHashQStringQVariant const* self, void* data, QffiMapEntryCall call);
+
+
 
 }
\ No newline at end of file
@@ -131,6 +131,115 @@ pub struct QList {
 pub struct QMetaObject {
     _unused: [u8; 0],
 }
+pub type QffiClosureCall = ::std::option::Option<
+    unsafe extern "C" fn(data: *mut ::std::os::raw::c_void, args: *mut *mut ::std::os::raw::c_void),
+>;
+pub type QffiClosureDrop =
+    ::std::option::Option<unsafe extern "C" fn(data: *mut ::std::os::raw::c_void)>;
+pub type QffiMapEntryCall = ::std::option::Option<
+    unsafe extern "C" fn(
+        data: *mut ::std::os::raw::c_void,
+        key: *const ::std::os::raw::c_void,
+        value: *const ::std::os::raw::c_void,
+    ),
+>;
+pub type QffiListModelRowCount = ::std::option::Option<
+    unsafe extern "C" fn(data: *const ::std::os::raw::c_void) -> ::std::os::raw::c_int,
+>;
+pub type QffiListModelData = ::std::option::Option<
+    unsafe extern "C" fn(
+        data: *const ::std::os::raw::c_void,
+        row: ::std::os::raw::c_int,
+        role: ::std::os::raw::c_int,
+        result: *mut ::std::os::raw::c_void,
+    ),
+>;
+#[repr(C)]
+pub struct QffiItemModelVTable {
+    pub index: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *const ::std::os::raw::c_void,
+            row: ::std::os::raw::c_int,
+            column: ::std::os::raw::c_int,
+            parent: *const crate::core::QModelIndex,
+            id: *mut usize,
+        ) -> bool,
+    >,
+    pub parent: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *const ::std::os::raw::c_void,
+            index: *const crate::core::QModelIndex,
+            row: *mut ::std::os::raw::c_int,
+            id: *mut usize,
+        ) -> bool,
+    >,
+    pub rowCount: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *const ::std::os::raw::c_void,
+            parent: *const crate::core::QModelIndex,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub columnCount: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *const ::std::os::raw::c_void,
+            parent: *const crate::core::QModelIndex,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub data: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *const ::std::os::raw::c_void,
+            index: *const crate::core::QModelIndex,
+            role: ::std::os::raw::c_int,
+            result: *mut QVariant,
+        ),
+    >,
+    pub setData: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *mut ::std::os::raw::c_void,
+            index: *const crate::core::QModelIndex,
+            value: *const QVariant,
+            role: ::std::os::raw::c_int,
+        ) -> bool,
+    >,
+    pub flags: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *const ::std::os::raw::c_void,
+            index: *const crate::core::QModelIndex,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub headerData: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *const ::std::os::raw::c_void,
+            section: ::std::os::raw::c_int,
+            orientation: ::std::os::raw::c_int,
+            role: ::std::os::raw::c_int,
+            result: *mut QVariant,
+        ),
+    >,
+    pub roleNames: ::std::option::Option<
+        unsafe extern "C" fn(data: *const ::std::os::raw::c_void, result: *mut QHashIntQByteArray),
+    >,
+    pub drop: ::std::option::Option<unsafe extern "C" fn(data: *mut ::std::os::raw::c_void)>,
+}
+#[repr(C)]
+pub struct QffiSortFilterProxyModelVTable {
+    pub filterAcceptsRow: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *const ::std::os::raw::c_void,
+            sourceIndex: *const crate::core::QModelIndex,
+            result: *mut bool,
+        ) -> bool,
+    >,
+    pub lessThan: ::std::option::Option<
+        unsafe extern "C" fn(
+            data: *const ::std::os::raw::c_void,
+            left: *const crate::core::QModelIndex,
+            right: *const crate::core::QModelIndex,
+            result: *mut bool,
+        ) -> bool,
+    >,
+    pub drop: ::std::option::Option<unsafe extern "C" fn(data: *mut ::std::os::raw::c_void)>,
+}
 #[repr(C)]
 pub struct QString {
     pub __d: *mut ::std::os::raw::c_void,
@@ -273,10 +382,6 @@ pub struct QGuiApplication {
     _unused: [u8; 0],
 }
 #[repr(C)]
-pub struct QHashIntQByteArray {
-    pub __d: *mut ::std::os::raw::c_void,
-}
-#[repr(C)]
 pub struct QThread {
     _unused: [u8; 0],
 }
@@ -289,6 +394,22 @@ pub struct QQmlApplicationEngine {
     _unused: [u8; 0],
 }
 #[repr(C)]
+pub struct QAbstractItemModel {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+pub struct QffiListModel {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+pub struct QffiItemModel {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+pub struct QffiSortFilterProxyModel {
+    _unused: [u8; 0],
+}
+#[repr(C)]
 pub struct QObjectList {
     pub __d: *mut ::std::os::raw::c_void,
 }
@@ -296,6 +417,122 @@ pub struct QObjectList {
 pub struct QStringList {
     pub __d: *mut ::std::os::raw::c_void,
 }
+#[repr(C)]
+pub struct QListBool {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QListInt {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QListLongLong {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QListDouble {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QListQByteArray {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QListQVariant {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QVectorBool {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QVectorInt {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QVectorLongLong {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QVectorDouble {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QVectorQString {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QVectorQByteArray {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QVectorQVariant {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QMapIntInt {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QMapIntQString {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QMapIntQByteArray {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QMapIntQVariant {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QMapQStringInt {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QMapQStringDouble {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QMapQStringQString {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QMapQStringQVariant {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QHashIntInt {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QHashIntQString {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QHashIntQByteArray {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QHashIntQVariant {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QHashQStringInt {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QHashQStringDouble {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QHashQStringQString {
+    pub __d: *mut ::std::os::raw::c_void,
+}
+#[repr(C)]
+pub struct QHashQStringQVariant {
+    pub __d: *mut ::std::os::raw::c_void,
+}
 extern "C" {
     pub fn qffi_QString_init(self_: *mut QString);
 }
@@ -424,6 +661,17 @@ extern "C" {
         result: *mut QMetaObjectConnection,
     );
 }
+extern "C" {
+    pub fn qffi_QObject_connectClosure(
+        self_: *const QObject,
+        signal: *const ::std::os::raw::c_char,
+        call: QffiClosureCall,
+        data: *mut ::std::os::raw::c_void,
+        drop: QffiClosureDrop,
+        type_: ::std::os::raw::c_int,
+        result: *mut QMetaObjectConnection,
+    );
+}
 extern "C" {
     pub fn qffi_QObject_disconnectConnection(connection: *const QMetaObjectConnection) -> bool;
 }
@@ -751,40 +999,281 @@ extern "C" {
     ) -> *mut QGuiApplication;
 }
 extern "C" {
-    pub fn qffi_QHashIntQByteArray_init(self_: *mut QHashIntQByteArray);
+    pub fn qffi_QQmlEngine_init(parent: *mut QObject) -> *mut QQmlEngine;
 }
 extern "C" {
-    pub fn qffi_QHashIntQByteArray_clone(
-        self_: *const QHashIntQByteArray,
-        new_: *mut QHashIntQByteArray,
+    pub fn qffi_QQmlEngine_singletonInstance(
+        self_: *mut QQmlEngine,
+        typeId: ::std::os::raw::c_int,
+    ) -> *mut QObject;
+}
+extern "C" {
+    pub fn qffi_QQmlApplicationEngine_init(parent: *mut QObject) -> *mut QQmlApplicationEngine;
+}
+extern "C" {
+    pub fn qffi_QQmlApplicationEngine_load(self_: *mut QQmlApplicationEngine, url: *const QUrl);
+}
+extern "C" {
+    pub fn qffi_QQmlApplicationEngine_rootObjects(
+        self_: *mut QQmlApplicationEngine,
+        result: *mut QObjectList,
     );
 }
 extern "C" {
-    pub fn qffi_QHashIntQByteArray_equals(
-        self_: *const QHashIntQByteArray,
-        other: *const QHashIntQByteArray,
+    pub fn qffi_QQmlApplicationEngine_setContextProperty(
+        self_: *mut QQmlApplicationEngine,
+        name: *const QString,
+        value: *mut QObject,
+    );
+}
+extern "C" {
+    pub fn qffi_QAbstractItemModel_index(
+        self_: *const QAbstractItemModel,
+        row: ::std::os::raw::c_int,
+        column: ::std::os::raw::c_int,
+        parent: *const crate::core::QModelIndex,
+        result: *mut crate::core::QModelIndex,
+    );
+}
+extern "C" {
+    pub fn qffi_QAbstractItemModel_parent(
+        self_: *const QAbstractItemModel,
+        index: *const crate::core::QModelIndex,
+        result: *mut crate::core::QModelIndex,
+    );
+}
+extern "C" {
+    pub fn qffi_QAbstractItemModel_sibling(
+        self_: *const QAbstractItemModel,
+        row: ::std::os::raw::c_int,
+        column: ::std::os::raw::c_int,
+        index: *const crate::core::QModelIndex,
+        result: *mut crate::core::QModelIndex,
+    );
+}
+extern "C" {
+    pub fn qffi_QAbstractItemModel_data(
+        self_: *const QAbstractItemModel,
+        index: *const crate::core::QModelIndex,
+        role: ::std::os::raw::c_int,
+        result: *mut QVariant,
+    );
+}
+extern "C" {
+    pub fn qffi_QAbstractItemModel_flags(
+        self_: *const QAbstractItemModel,
+        index: *const crate::core::QModelIndex,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QAbstractItemModel_rowCount(
+        self_: *const QAbstractItemModel,
+        parent: *const crate::core::QModelIndex,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QAbstractItemModel_columnCount(
+        self_: *const QAbstractItemModel,
+        parent: *const crate::core::QModelIndex,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QAbstractItemModel_setData(
+        self_: *mut QAbstractItemModel,
+        index: *const crate::core::QModelIndex,
+        value: *const QVariant,
+        role: ::std::os::raw::c_int,
     ) -> bool;
 }
 extern "C" {
-    pub fn qffi_QHashIntQByteArray_size(self_: *const QHashIntQByteArray) -> ::std::os::raw::c_int;
+    pub fn qffi_QAbstractItemModel_headerData(
+        self_: *const QAbstractItemModel,
+        section: ::std::os::raw::c_int,
+        orientation: ::std::os::raw::c_int,
+        role: ::std::os::raw::c_int,
+        result: *mut QVariant,
+    );
 }
 extern "C" {
-    pub fn qffi_QHashIntQByteArray_insert(
-        self_: *mut QHashIntQByteArray,
-        key: *const ::std::os::raw::c_int,
-        value: *const QByteArray,
+    pub fn qffi_QAbstractItemModel_roleNames(
+        self_: *const QAbstractItemModel,
+        result: *mut QHashIntQByteArray,
     );
 }
 extern "C" {
-    pub fn qffi_QQmlApplicationEngine_init(parent: *mut QObject) -> *mut QQmlApplicationEngine;
+    pub fn qffi_QffiListModel_init(
+        parent: *mut QObject,
+        data: *mut ::std::os::raw::c_void,
+        rowCount: QffiListModelRowCount,
+        itemData: QffiListModelData,
+        drop: QffiClosureDrop,
+        roleNames: *const QHashIntQByteArray,
+    ) -> *mut QffiListModel;
 }
 extern "C" {
-    pub fn qffi_QQmlApplicationEngine_load(self_: *mut QQmlApplicationEngine, url: *const QUrl);
+    pub fn qffi_QffiListModel_beginInsertRows(
+        self_: *mut QffiListModel,
+        first: ::std::os::raw::c_int,
+        last: ::std::os::raw::c_int,
+    );
 }
 extern "C" {
-    pub fn qffi_QQmlApplicationEngine_rootObjects(
-        self_: *mut QQmlApplicationEngine,
-        result: *mut QObjectList,
+    pub fn qffi_QffiListModel_endInsertRows(self_: *mut QffiListModel);
+}
+extern "C" {
+    pub fn qffi_QffiListModel_beginRemoveRows(
+        self_: *mut QffiListModel,
+        first: ::std::os::raw::c_int,
+        last: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QffiListModel_endRemoveRows(self_: *mut QffiListModel);
+}
+extern "C" {
+    pub fn qffi_QffiListModel_beginMoveRows(
+        self_: *mut QffiListModel,
+        first: ::std::os::raw::c_int,
+        last: ::std::os::raw::c_int,
+        destination: ::std::os::raw::c_int,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QffiListModel_endMoveRows(self_: *mut QffiListModel);
+}
+extern "C" {
+    pub fn qffi_QffiListModel_beginResetModel(self_: *mut QffiListModel);
+}
+extern "C" {
+    pub fn qffi_QffiListModel_endResetModel(self_: *mut QffiListModel);
+}
+extern "C" {
+    pub fn qffi_QffiListModel_dataChanged(
+        self_: *mut QffiListModel,
+        first: ::std::os::raw::c_int,
+        last: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QffiItemModel_init(
+        parent: *mut QObject,
+        data: *mut ::std::os::raw::c_void,
+        vtable: *const QffiItemModelVTable,
+    ) -> *mut QffiItemModel;
+}
+extern "C" {
+    pub fn qffi_QffiItemModel_beginInsertRows(
+        self_: *mut QffiItemModel,
+        parent: *const crate::core::QModelIndex,
+        first: ::std::os::raw::c_int,
+        last: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QffiItemModel_endInsertRows(self_: *mut QffiItemModel);
+}
+extern "C" {
+    pub fn qffi_QffiItemModel_beginRemoveRows(
+        self_: *mut QffiItemModel,
+        parent: *const crate::core::QModelIndex,
+        first: ::std::os::raw::c_int,
+        last: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QffiItemModel_endRemoveRows(self_: *mut QffiItemModel);
+}
+extern "C" {
+    pub fn qffi_QffiItemModel_beginMoveRows(
+        self_: *mut QffiItemModel,
+        sourceParent: *const crate::core::QModelIndex,
+        first: ::std::os::raw::c_int,
+        last: ::std::os::raw::c_int,
+        destinationParent: *const crate::core::QModelIndex,
+        destination: ::std::os::raw::c_int,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QffiItemModel_endMoveRows(self_: *mut QffiItemModel);
+}
+extern "C" {
+    pub fn qffi_QffiItemModel_beginInsertColumns(
+        self_: *mut QffiItemModel,
+        parent: *const crate::core::QModelIndex,
+        first: ::std::os::raw::c_int,
+        last: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QffiItemModel_endInsertColumns(self_: *mut QffiItemModel);
+}
+extern "C" {
+    pub fn qffi_QffiItemModel_beginRemoveColumns(
+        self_: *mut QffiItemModel,
+        parent: *const crate::core::QModelIndex,
+        first: ::std::os::raw::c_int,
+        last: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QffiItemModel_endRemoveColumns(self_: *mut QffiItemModel);
+}
+extern "C" {
+    pub fn qffi_QffiItemModel_beginResetModel(self_: *mut QffiItemModel);
+}
+extern "C" {
+    pub fn qffi_QffiItemModel_endResetModel(self_: *mut QffiItemModel);
+}
+extern "C" {
+    pub fn qffi_QffiItemModel_dataChanged(
+        self_: *mut QffiItemModel,
+        topLeft: *const crate::core::QModelIndex,
+        bottomRight: *const crate::core::QModelIndex,
+    );
+}
+extern "C" {
+    pub fn qffi_QffiSortFilterProxyModel_init(
+        parent: *mut QObject,
+        data: *mut ::std::os::raw::c_void,
+        vtable: *const QffiSortFilterProxyModelVTable,
+    ) -> *mut QffiSortFilterProxyModel;
+}
+extern "C" {
+    pub fn qffi_QffiSortFilterProxyModel_setSourceModel(
+        self_: *mut QffiSortFilterProxyModel,
+        sourceModel: *mut QAbstractItemModel,
+    );
+}
+extern "C" {
+    pub fn qffi_QffiSortFilterProxyModel_sourceModel(
+        self_: *const QffiSortFilterProxyModel,
+    ) -> *mut QAbstractItemModel;
+}
+extern "C" {
+    pub fn qffi_QffiSortFilterProxyModel_invalidateFilter(self_: *mut QffiSortFilterProxyModel);
+}
+extern "C" {
+    pub fn qffi_QffiSortFilterProxyModel_invalidate(self_: *mut QffiSortFilterProxyModel);
+}
+extern "C" {
+    pub fn qffi_QffiSortFilterProxyModel_sort(
+        self_: *mut QffiSortFilterProxyModel,
+        column: ::std::os::raw::c_int,
+        order: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QffiSortFilterProxyModel_mapToSource(
+        self_: *const QffiSortFilterProxyModel,
+        proxyIndex: *const crate::core::QModelIndex,
+        result: *mut crate::core::QModelIndex,
+    );
+}
+extern "C" {
+    pub fn qffi_QffiSortFilterProxyModel_mapFromSource(
+        self_: *const QffiSortFilterProxyModel,
+        sourceIndex: *const crate::core::QModelIndex,
+        result: *mut crate::core::QModelIndex,
     );
 }
 extern "C" {
@@ -799,6 +1288,12 @@ extern "C" {
 extern "C" {
     pub fn qffi_QObjectList_size(self_: *const QObjectList) -> ::std::os::raw::c_int;
 }
+extern "C" {
+    pub fn qffi_QObjectList_at(
+        self_: *const QObjectList,
+        index: ::std::os::raw::c_int,
+    ) -> *const *mut QObject;
+}
 extern "C" {
     pub fn qffi_QObjectList_asSlice(
         self_: *const QObjectList,
@@ -836,6 +1331,12 @@ extern "C" {
 extern "C" {
     pub fn qffi_QStringList_size(self_: *const QStringList) -> ::std::os::raw::c_int;
 }
+extern "C" {
+    pub fn qffi_QStringList_at(
+        self_: *const QStringList,
+        index: ::std::os::raw::c_int,
+    ) -> *const QString;
+}
 extern "C" {
     pub fn qffi_QStringList_asSlice(
         self_: *const QStringList,
@@ -861,3 +1362,1269 @@ extern "C" {
         additional: ::std::os::raw::c_int,
     );
 }
+extern "C" {
+    pub fn qffi_QListBool_init(self_: *mut QListBool);
+}
+extern "C" {
+    pub fn qffi_QListBool_clone(self_: *const QListBool, new_: *mut QListBool);
+}
+extern "C" {
+    pub fn qffi_QListBool_equals(self_: *const QListBool, other: *const QListBool) -> bool;
+}
+extern "C" {
+    pub fn qffi_QListBool_size(self_: *const QListBool) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QListBool_at(self_: *const QListBool, index: ::std::os::raw::c_int) -> *const bool;
+}
+extern "C" {
+    pub fn qffi_QListBool_append(self_: *mut QListBool, item: *const bool);
+}
+extern "C" {
+    pub fn qffi_QListBool_appendList(self_: *mut QListBool, item: *const QList);
+}
+extern "C" {
+    pub fn qffi_QListBool_appendSlice(
+        self_: *mut QListBool,
+        items: *const bool,
+        size: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QListBool_reserveAdditional(
+        self_: *mut QListBool,
+        additional: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QListInt_init(self_: *mut QListInt);
+}
+extern "C" {
+    pub fn qffi_QListInt_clone(self_: *const QListInt, new_: *mut QListInt);
+}
+extern "C" {
+    pub fn qffi_QListInt_equals(self_: *const QListInt, other: *const QListInt) -> bool;
+}
+extern "C" {
+    pub fn qffi_QListInt_size(self_: *const QListInt) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QListInt_at(
+        self_: *const QListInt,
+        index: ::std::os::raw::c_int,
+    ) -> *const ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QListInt_append(self_: *mut QListInt, item: *const ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn qffi_QListInt_appendList(self_: *mut QListInt, item: *const QList);
+}
+extern "C" {
+    pub fn qffi_QListInt_appendSlice(
+        self_: *mut QListInt,
+        items: *const ::std::os::raw::c_int,
+        size: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QListInt_reserveAdditional(self_: *mut QListInt, additional: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn qffi_QListLongLong_init(self_: *mut QListLongLong);
+}
+extern "C" {
+    pub fn qffi_QListLongLong_clone(self_: *const QListLongLong, new_: *mut QListLongLong);
+}
+extern "C" {
+    pub fn qffi_QListLongLong_equals(
+        self_: *const QListLongLong,
+        other: *const QListLongLong,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QListLongLong_size(self_: *const QListLongLong) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QListLongLong_at(
+        self_: *const QListLongLong,
+        index: ::std::os::raw::c_int,
+    ) -> *const ::std::os::raw::c_longlong;
+}
+extern "C" {
+    pub fn qffi_QListLongLong_append(
+        self_: *mut QListLongLong,
+        item: *const ::std::os::raw::c_longlong,
+    );
+}
+extern "C" {
+    pub fn qffi_QListLongLong_appendList(self_: *mut QListLongLong, item: *const QList);
+}
+extern "C" {
+    pub fn qffi_QListLongLong_appendSlice(
+        self_: *mut QListLongLong,
+        items: *const ::std::os::raw::c_longlong,
+        size: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QListLongLong_reserveAdditional(
+        self_: *mut QListLongLong,
+        additional: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QListDouble_init(self_: *mut QListDouble);
+}
+extern "C" {
+    pub fn qffi_QListDouble_clone(self_: *const QListDouble, new_: *mut QListDouble);
+}
+extern "C" {
+    pub fn qffi_QListDouble_equals(self_: *const QListDouble, other: *const QListDouble) -> bool;
+}
+extern "C" {
+    pub fn qffi_QListDouble_size(self_: *const QListDouble) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QListDouble_at(
+        self_: *const QListDouble,
+        index: ::std::os::raw::c_int,
+    ) -> *const f64;
+}
+extern "C" {
+    pub fn qffi_QListDouble_append(self_: *mut QListDouble, item: *const f64);
+}
+extern "C" {
+    pub fn qffi_QListDouble_appendList(self_: *mut QListDouble, item: *const QList);
+}
+extern "C" {
+    pub fn qffi_QListDouble_appendSlice(
+        self_: *mut QListDouble,
+        items: *const f64,
+        size: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QListDouble_reserveAdditional(
+        self_: *mut QListDouble,
+        additional: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QListQByteArray_init(self_: *mut QListQByteArray);
+}
+extern "C" {
+    pub fn qffi_QListQByteArray_clone(self_: *const QListQByteArray, new_: *mut QListQByteArray);
+}
+extern "C" {
+    pub fn qffi_QListQByteArray_equals(
+        self_: *const QListQByteArray,
+        other: *const QListQByteArray,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QListQByteArray_size(self_: *const QListQByteArray) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QListQByteArray_at(
+        self_: *const QListQByteArray,
+        index: ::std::os::raw::c_int,
+    ) -> *const QByteArray;
+}
+extern "C" {
+    pub fn qffi_QListQByteArray_asSlice(
+        self_: *const QListQByteArray,
+        size: *mut ::std::os::raw::c_int,
+    ) -> *const QByteArray;
+}
+extern "C" {
+    pub fn qffi_QListQByteArray_append(self_: *mut QListQByteArray, item: *const QByteArray);
+}
+extern "C" {
+    pub fn qffi_QListQByteArray_appendList(self_: *mut QListQByteArray, item: *const QList);
+}
+extern "C" {
+    pub fn qffi_QListQByteArray_appendSlice(
+        self_: *mut QListQByteArray,
+        items: *const QByteArray,
+        size: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QListQByteArray_reserveAdditional(
+        self_: *mut QListQByteArray,
+        additional: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QListQVariant_init(self_: *mut QListQVariant);
+}
+extern "C" {
+    pub fn qffi_QListQVariant_clone(self_: *const QListQVariant, new_: *mut QListQVariant);
+}
+extern "C" {
+    pub fn qffi_QListQVariant_equals(
+        self_: *const QListQVariant,
+        other: *const QListQVariant,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QListQVariant_size(self_: *const QListQVariant) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QListQVariant_at(
+        self_: *const QListQVariant,
+        index: ::std::os::raw::c_int,
+    ) -> *const QVariant;
+}
+extern "C" {
+    pub fn qffi_QListQVariant_append(self_: *mut QListQVariant, item: *const QVariant);
+}
+extern "C" {
+    pub fn qffi_QListQVariant_appendList(self_: *mut QListQVariant, item: *const QList);
+}
+extern "C" {
+    pub fn qffi_QListQVariant_appendSlice(
+        self_: *mut QListQVariant,
+        items: *const QVariant,
+        size: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QListQVariant_reserveAdditional(
+        self_: *mut QListQVariant,
+        additional: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QVectorBool_init(self_: *mut QVectorBool);
+}
+extern "C" {
+    pub fn qffi_QVectorBool_clone(self_: *const QVectorBool, new_: *mut QVectorBool);
+}
+extern "C" {
+    pub fn qffi_QVectorBool_equals(self_: *const QVectorBool, other: *const QVectorBool) -> bool;
+}
+extern "C" {
+    pub fn qffi_QVectorBool_size(self_: *const QVectorBool) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QVectorBool_asSlice(
+        self_: *const QVectorBool,
+        size: *mut ::std::os::raw::c_int,
+    ) -> *const bool;
+}
+extern "C" {
+    pub fn qffi_QVectorBool_append(self_: *mut QVectorBool, item: *const bool);
+}
+extern "C" {
+    pub fn qffi_QVectorBool_appendSlice(
+        self_: *mut QVectorBool,
+        items: *const bool,
+        size: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QVectorBool_reserveAdditional(
+        self_: *mut QVectorBool,
+        additional: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QVectorInt_init(self_: *mut QVectorInt);
+}
+extern "C" {
+    pub fn qffi_QVectorInt_clone(self_: *const QVectorInt, new_: *mut QVectorInt);
+}
+extern "C" {
+    pub fn qffi_QVectorInt_equals(self_: *const QVectorInt, other: *const QVectorInt) -> bool;
+}
+extern "C" {
+    pub fn qffi_QVectorInt_size(self_: *const QVectorInt) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QVectorInt_asSlice(
+        self_: *const QVectorInt,
+        size: *mut ::std::os::raw::c_int,
+    ) -> *const ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QVectorInt_append(self_: *mut QVectorInt, item: *const ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn qffi_QVectorInt_appendSlice(
+        self_: *mut QVectorInt,
+        items: *const ::std::os::raw::c_int,
+        size: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QVectorInt_reserveAdditional(
+        self_: *mut QVectorInt,
+        additional: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QVectorLongLong_init(self_: *mut QVectorLongLong);
+}
+extern "C" {
+    pub fn qffi_QVectorLongLong_clone(self_: *const QVectorLongLong, new_: *mut QVectorLongLong);
+}
+extern "C" {
+    pub fn qffi_QVectorLongLong_equals(
+        self_: *const QVectorLongLong,
+        other: *const QVectorLongLong,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QVectorLongLong_size(self_: *const QVectorLongLong) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QVectorLongLong_asSlice(
+        self_: *const QVectorLongLong,
+        size: *mut ::std::os::raw::c_int,
+    ) -> *const ::std::os::raw::c_longlong;
+}
+extern "C" {
+    pub fn qffi_QVectorLongLong_append(
+        self_: *mut QVectorLongLong,
+        item: *const ::std::os::raw::c_longlong,
+    );
+}
+extern "C" {
+    pub fn qffi_QVectorLongLong_appendSlice(
+        self_: *mut QVectorLongLong,
+        items: *const ::std::os::raw::c_longlong,
+        size: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QVectorLongLong_reserveAdditional(
+        self_: *mut QVectorLongLong,
+        additional: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QVectorDouble_init(self_: *mut QVectorDouble);
+}
+extern "C" {
+    pub fn qffi_QVectorDouble_clone(self_: *const QVectorDouble, new_: *mut QVectorDouble);
+}
+extern "C" {
+    pub fn qffi_QVectorDouble_equals(
+        self_: *const QVectorDouble,
+        other: *const QVectorDouble,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QVectorDouble_size(self_: *const QVectorDouble) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QVectorDouble_asSlice(
+        self_: *const QVectorDouble,
+        size: *mut ::std::os::raw::c_int,
+    ) -> *const f64;
+}
+extern "C" {
+    pub fn qffi_QVectorDouble_append(self_: *mut QVectorDouble, item: *const f64);
+}
+extern "C" {
+    pub fn qffi_QVectorDouble_appendSlice(
+        self_: *mut QVectorDouble,
+        items: *const f64,
+        size: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QVectorDouble_reserveAdditional(
+        self_: *mut QVectorDouble,
+        additional: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QVectorQString_init(self_: *mut QVectorQString);
+}
+extern "C" {
+    pub fn qffi_QVectorQString_clone(self_: *const QVectorQString, new_: *mut QVectorQString);
+}
+extern "C" {
+    pub fn qffi_QVectorQString_equals(
+        self_: *const QVectorQString,
+        other: *const QVectorQString,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QVectorQString_size(self_: *const QVectorQString) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QVectorQString_asSlice(
+        self_: *const QVectorQString,
+        size: *mut ::std::os::raw::c_int,
+    ) -> *const QString;
+}
+extern "C" {
+    pub fn qffi_QVectorQString_append(self_: *mut QVectorQString, item: *const QString);
+}
+extern "C" {
+    pub fn qffi_QVectorQString_appendSlice(
+        self_: *mut QVectorQString,
+        items: *const QString,
+        size: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QVectorQString_reserveAdditional(
+        self_: *mut QVectorQString,
+        additional: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QVectorQByteArray_init(self_: *mut QVectorQByteArray);
+}
+extern "C" {
+    pub fn qffi_QVectorQByteArray_clone(
+        self_: *const QVectorQByteArray,
+        new_: *mut QVectorQByteArray,
+    );
+}
+extern "C" {
+    pub fn qffi_QVectorQByteArray_equals(
+        self_: *const QVectorQByteArray,
+        other: *const QVectorQByteArray,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QVectorQByteArray_size(self_: *const QVectorQByteArray) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QVectorQByteArray_asSlice(
+        self_: *const QVectorQByteArray,
+        size: *mut ::std::os::raw::c_int,
+    ) -> *const QByteArray;
+}
+extern "C" {
+    pub fn qffi_QVectorQByteArray_append(self_: *mut QVectorQByteArray, item: *const QByteArray);
+}
+extern "C" {
+    pub fn qffi_QVectorQByteArray_appendSlice(
+        self_: *mut QVectorQByteArray,
+        items: *const QByteArray,
+        size: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QVectorQByteArray_reserveAdditional(
+        self_: *mut QVectorQByteArray,
+        additional: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QVectorQVariant_init(self_: *mut QVectorQVariant);
+}
+extern "C" {
+    pub fn qffi_QVectorQVariant_clone(self_: *const QVectorQVariant, new_: *mut QVectorQVariant);
+}
+extern "C" {
+    pub fn qffi_QVectorQVariant_equals(
+        self_: *const QVectorQVariant,
+        other: *const QVectorQVariant,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QVectorQVariant_size(self_: *const QVectorQVariant) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QVectorQVariant_asSlice(
+        self_: *const QVectorQVariant,
+        size: *mut ::std::os::raw::c_int,
+    ) -> *const QVariant;
+}
+extern "C" {
+    pub fn qffi_QVectorQVariant_append(self_: *mut QVectorQVariant, item: *const QVariant);
+}
+extern "C" {
+    pub fn qffi_QVectorQVariant_appendSlice(
+        self_: *mut QVectorQVariant,
+        items: *const QVariant,
+        size: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QVectorQVariant_reserveAdditional(
+        self_: *mut QVectorQVariant,
+        additional: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QMapIntInt_init(self_: *mut QMapIntInt);
+}
+extern "C" {
+    pub fn qffi_QMapIntInt_clone(self_: *const QMapIntInt, new_: *mut QMapIntInt);
+}
+extern "C" {
+    pub fn qffi_QMapIntInt_equals(self_: *const QMapIntInt, other: *const QMapIntInt) -> bool;
+}
+extern "C" {
+    pub fn qffi_QMapIntInt_size(self_: *const QMapIntInt) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QMapIntInt_contains(
+        self_: *const QMapIntInt,
+        key: *const ::std::os::raw::c_int,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QMapIntInt_value(
+        self_: *const QMapIntInt,
+        key: *const ::std::os::raw::c_int,
+    ) -> *const ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QMapIntInt_insert(
+        self_: *mut QMapIntInt,
+        key: *const ::std::os::raw::c_int,
+        value: *const ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QMapIntInt_remove(
+        self_: *mut QMapIntInt,
+        key: *const ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QMapIntInt_forEach(
+        self_: *const QMapIntInt,
+        data: *mut ::std::os::raw::c_void,
+        call: QffiMapEntryCall,
+    );
+}
+extern "C" {
+    pub fn qffi_QMapIntQString_init(self_: *mut QMapIntQString);
+}
+extern "C" {
+    pub fn qffi_QMapIntQString_clone(self_: *const QMapIntQString, new_: *mut QMapIntQString);
+}
+extern "C" {
+    pub fn qffi_QMapIntQString_equals(
+        self_: *const QMapIntQString,
+        other: *const QMapIntQString,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QMapIntQString_size(self_: *const QMapIntQString) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QMapIntQString_contains(
+        self_: *const QMapIntQString,
+        key: *const ::std::os::raw::c_int,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QMapIntQString_value(
+        self_: *const QMapIntQString,
+        key: *const ::std::os::raw::c_int,
+    ) -> *const QString;
+}
+extern "C" {
+    pub fn qffi_QMapIntQString_insert(
+        self_: *mut QMapIntQString,
+        key: *const ::std::os::raw::c_int,
+        value: *const QString,
+    );
+}
+extern "C" {
+    pub fn qffi_QMapIntQString_remove(
+        self_: *mut QMapIntQString,
+        key: *const ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QMapIntQString_forEach(
+        self_: *const QMapIntQString,
+        data: *mut ::std::os::raw::c_void,
+        call: QffiMapEntryCall,
+    );
+}
+extern "C" {
+    pub fn qffi_QMapIntQByteArray_init(self_: *mut QMapIntQByteArray);
+}
+extern "C" {
+    pub fn qffi_QMapIntQByteArray_clone(
+        self_: *const QMapIntQByteArray,
+        new_: *mut QMapIntQByteArray,
+    );
+}
+extern "C" {
+    pub fn qffi_QMapIntQByteArray_equals(
+        self_: *const QMapIntQByteArray,
+        other: *const QMapIntQByteArray,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QMapIntQByteArray_size(self_: *const QMapIntQByteArray) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QMapIntQByteArray_contains(
+        self_: *const QMapIntQByteArray,
+        key: *const ::std::os::raw::c_int,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QMapIntQByteArray_value(
+        self_: *const QMapIntQByteArray,
+        key: *const ::std::os::raw::c_int,
+    ) -> *const QByteArray;
+}
+extern "C" {
+    pub fn qffi_QMapIntQByteArray_insert(
+        self_: *mut QMapIntQByteArray,
+        key: *const ::std::os::raw::c_int,
+        value: *const QByteArray,
+    );
+}
+extern "C" {
+    pub fn qffi_QMapIntQByteArray_remove(
+        self_: *mut QMapIntQByteArray,
+        key: *const ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QMapIntQByteArray_forEach(
+        self_: *const QMapIntQByteArray,
+        data: *mut ::std::os::raw::c_void,
+        call: QffiMapEntryCall,
+    );
+}
+extern "C" {
+    pub fn qffi_QMapIntQVariant_init(self_: *mut QMapIntQVariant);
+}
+extern "C" {
+    pub fn qffi_QMapIntQVariant_clone(self_: *const QMapIntQVariant, new_: *mut QMapIntQVariant);
+}
+extern "C" {
+    pub fn qffi_QMapIntQVariant_equals(
+        self_: *const QMapIntQVariant,
+        other: *const QMapIntQVariant,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QMapIntQVariant_size(self_: *const QMapIntQVariant) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QMapIntQVariant_contains(
+        self_: *const QMapIntQVariant,
+        key: *const ::std::os::raw::c_int,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QMapIntQVariant_value(
+        self_: *const QMapIntQVariant,
+        key: *const ::std::os::raw::c_int,
+    ) -> *const QVariant;
+}
+extern "C" {
+    pub fn qffi_QMapIntQVariant_insert(
+        self_: *mut QMapIntQVariant,
+        key: *const ::std::os::raw::c_int,
+        value: *const QVariant,
+    );
+}
+extern "C" {
+    pub fn qffi_QMapIntQVariant_remove(
+        self_: *mut QMapIntQVariant,
+        key: *const ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QMapIntQVariant_forEach(
+        self_: *const QMapIntQVariant,
+        data: *mut ::std::os::raw::c_void,
+        call: QffiMapEntryCall,
+    );
+}
+extern "C" {
+    pub fn qffi_QMapQStringInt_init(self_: *mut QMapQStringInt);
+}
+extern "C" {
+    pub fn qffi_QMapQStringInt_clone(self_: *const QMapQStringInt, new_: *mut QMapQStringInt);
+}
+extern "C" {
+    pub fn qffi_QMapQStringInt_equals(
+        self_: *const QMapQStringInt,
+        other: *const QMapQStringInt,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QMapQStringInt_size(self_: *const QMapQStringInt) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QMapQStringInt_contains(self_: *const QMapQStringInt, key: *const QString) -> bool;
+}
+extern "C" {
+    pub fn qffi_QMapQStringInt_value(
+        self_: *const QMapQStringInt,
+        key: *const QString,
+    ) -> *const ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QMapQStringInt_insert(
+        self_: *mut QMapQStringInt,
+        key: *const QString,
+        value: *const ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QMapQStringInt_remove(
+        self_: *mut QMapQStringInt,
+        key: *const QString,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QMapQStringInt_forEach(
+        self_: *const QMapQStringInt,
+        data: *mut ::std::os::raw::c_void,
+        call: QffiMapEntryCall,
+    );
+}
+extern "C" {
+    pub fn qffi_QMapQStringDouble_init(self_: *mut QMapQStringDouble);
+}
+extern "C" {
+    pub fn qffi_QMapQStringDouble_clone(
+        self_: *const QMapQStringDouble,
+        new_: *mut QMapQStringDouble,
+    );
+}
+extern "C" {
+    pub fn qffi_QMapQStringDouble_equals(
+        self_: *const QMapQStringDouble,
+        other: *const QMapQStringDouble,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QMapQStringDouble_size(self_: *const QMapQStringDouble) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QMapQStringDouble_contains(
+        self_: *const QMapQStringDouble,
+        key: *const QString,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QMapQStringDouble_value(
+        self_: *const QMapQStringDouble,
+        key: *const QString,
+    ) -> *const f64;
+}
+extern "C" {
+    pub fn qffi_QMapQStringDouble_insert(
+        self_: *mut QMapQStringDouble,
+        key: *const QString,
+        value: *const f64,
+    );
+}
+extern "C" {
+    pub fn qffi_QMapQStringDouble_remove(
+        self_: *mut QMapQStringDouble,
+        key: *const QString,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QMapQStringDouble_forEach(
+        self_: *const QMapQStringDouble,
+        data: *mut ::std::os::raw::c_void,
+        call: QffiMapEntryCall,
+    );
+}
+extern "C" {
+    pub fn qffi_QMapQStringQString_init(self_: *mut QMapQStringQString);
+}
+extern "C" {
+    pub fn qffi_QMapQStringQString_clone(
+        self_: *const QMapQStringQString,
+        new_: *mut QMapQStringQString,
+    );
+}
+extern "C" {
+    pub fn qffi_QMapQStringQString_equals(
+        self_: *const QMapQStringQString,
+        other: *const QMapQStringQString,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QMapQStringQString_size(self_: *const QMapQStringQString) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QMapQStringQString_contains(
+        self_: *const QMapQStringQString,
+        key: *const QString,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QMapQStringQString_value(
+        self_: *const QMapQStringQString,
+        key: *const QString,
+    ) -> *const QString;
+}
+extern "C" {
+    pub fn qffi_QMapQStringQString_insert(
+        self_: *mut QMapQStringQString,
+        key: *const QString,
+        value: *const QString,
+    );
+}
+extern "C" {
+    pub fn qffi_QMapQStringQString_remove(
+        self_: *mut QMapQStringQString,
+        key: *const QString,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QMapQStringQString_forEach(
+        self_: *const QMapQStringQString,
+        data: *mut ::std::os::raw::c_void,
+        call: QffiMapEntryCall,
+    );
+}
+extern "C" {
+    pub fn qffi_QMapQStringQVariant_init(self_: *mut QMapQStringQVariant);
+}
+extern "C" {
+    pub fn qffi_QMapQStringQVariant_clone(
+        self_: *const QMapQStringQVariant,
+        new_: *mut QMapQStringQVariant,
+    );
+}
+extern "C" {
+    pub fn qffi_QMapQStringQVariant_equals(
+        self_: *const QMapQStringQVariant,
+        other: *const QMapQStringQVariant,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QMapQStringQVariant_size(
+        self_: *const QMapQStringQVariant,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QMapQStringQVariant_contains(
+        self_: *const QMapQStringQVariant,
+        key: *const QString,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QMapQStringQVariant_value(
+        self_: *const QMapQStringQVariant,
+        key: *const QString,
+    ) -> *const QVariant;
+}
+extern "C" {
+    pub fn qffi_QMapQStringQVariant_insert(
+        self_: *mut QMapQStringQVariant,
+        key: *const QString,
+        value: *const QVariant,
+    );
+}
+extern "C" {
+    pub fn qffi_QMapQStringQVariant_remove(
+        self_: *mut QMapQStringQVariant,
+        key: *const QString,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QMapQStringQVariant_forEach(
+        self_: *const QMapQStringQVariant,
+        data: *mut ::std::os::raw::c_void,
+        call: QffiMapEntryCall,
+    );
+}
+extern "C" {
+    pub fn qffi_QHashIntInt_init(self_: *mut QHashIntInt);
+}
+extern "C" {
+    pub fn qffi_QHashIntInt_clone(self_: *const QHashIntInt, new_: *mut QHashIntInt);
+}
+extern "C" {
+    pub fn qffi_QHashIntInt_equals(self_: *const QHashIntInt, other: *const QHashIntInt) -> bool;
+}
+extern "C" {
+    pub fn qffi_QHashIntInt_size(self_: *const QHashIntInt) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QHashIntInt_contains(
+        self_: *const QHashIntInt,
+        key: *const ::std::os::raw::c_int,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QHashIntInt_value(
+        self_: *const QHashIntInt,
+        key: *const ::std::os::raw::c_int,
+    ) -> *const ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QHashIntInt_insert(
+        self_: *mut QHashIntInt,
+        key: *const ::std::os::raw::c_int,
+        value: *const ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QHashIntInt_remove(
+        self_: *mut QHashIntInt,
+        key: *const ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QHashIntInt_forEach(
+        self_: *const QHashIntInt,
+        data: *mut ::std::os::raw::c_void,
+        call: QffiMapEntryCall,
+    );
+}
+extern "C" {
+    pub fn qffi_QHashIntQString_init(self_: *mut QHashIntQString);
+}
+extern "C" {
+    pub fn qffi_QHashIntQString_clone(self_: *const QHashIntQString, new_: *mut QHashIntQString);
+}
+extern "C" {
+    pub fn qffi_QHashIntQString_equals(
+        self_: *const QHashIntQString,
+        other: *const QHashIntQString,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QHashIntQString_size(self_: *const QHashIntQString) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QHashIntQString_contains(
+        self_: *const QHashIntQString,
+        key: *const ::std::os::raw::c_int,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QHashIntQString_value(
+        self_: *const QHashIntQString,
+        key: *const ::std::os::raw::c_int,
+    ) -> *const QString;
+}
+extern "C" {
+    pub fn qffi_QHashIntQString_insert(
+        self_: *mut QHashIntQString,
+        key: *const ::std::os::raw::c_int,
+        value: *const QString,
+    );
+}
+extern "C" {
+    pub fn qffi_QHashIntQString_remove(
+        self_: *mut QHashIntQString,
+        key: *const ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QHashIntQString_forEach(
+        self_: *const QHashIntQString,
+        data: *mut ::std::os::raw::c_void,
+        call: QffiMapEntryCall,
+    );
+}
+extern "C" {
+    pub fn qffi_QHashIntQByteArray_init(self_: *mut QHashIntQByteArray);
+}
+extern "C" {
+    pub fn qffi_QHashIntQByteArray_clone(
+        self_: *const QHashIntQByteArray,
+        new_: *mut QHashIntQByteArray,
+    );
+}
+extern "C" {
+    pub fn qffi_QHashIntQByteArray_equals(
+        self_: *const QHashIntQByteArray,
+        other: *const QHashIntQByteArray,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QHashIntQByteArray_size(self_: *const QHashIntQByteArray) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QHashIntQByteArray_contains(
+        self_: *const QHashIntQByteArray,
+        key: *const ::std::os::raw::c_int,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QHashIntQByteArray_value(
+        self_: *const QHashIntQByteArray,
+        key: *const ::std::os::raw::c_int,
+    ) -> *const QByteArray;
+}
+extern "C" {
+    pub fn qffi_QHashIntQByteArray_insert(
+        self_: *mut QHashIntQByteArray,
+        key: *const ::std::os::raw::c_int,
+        value: *const QByteArray,
+    );
+}
+extern "C" {
+    pub fn qffi_QHashIntQByteArray_remove(
+        self_: *mut QHashIntQByteArray,
+        key: *const ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QHashIntQByteArray_forEach(
+        self_: *const QHashIntQByteArray,
+        data: *mut ::std::os::raw::c_void,
+        call: QffiMapEntryCall,
+    );
+}
+extern "C" {
+    pub fn qffi_QHashIntQVariant_init(self_: *mut QHashIntQVariant);
+}
+extern "C" {
+    pub fn qffi_QHashIntQVariant_clone(self_: *const QHashIntQVariant, new_: *mut QHashIntQVariant);
+}
+extern "C" {
+    pub fn qffi_QHashIntQVariant_equals(
+        self_: *const QHashIntQVariant,
+        other: *const QHashIntQVariant,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QHashIntQVariant_size(self_: *const QHashIntQVariant) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QHashIntQVariant_contains(
+        self_: *const QHashIntQVariant,
+        key: *const ::std::os::raw::c_int,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QHashIntQVariant_value(
+        self_: *const QHashIntQVariant,
+        key: *const ::std::os::raw::c_int,
+    ) -> *const QVariant;
+}
+extern "C" {
+    pub fn qffi_QHashIntQVariant_insert(
+        self_: *mut QHashIntQVariant,
+        key: *const ::std::os::raw::c_int,
+        value: *const QVariant,
+    );
+}
+extern "C" {
+    pub fn qffi_QHashIntQVariant_remove(
+        self_: *mut QHashIntQVariant,
+        key: *const ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QHashIntQVariant_forEach(
+        self_: *const QHashIntQVariant,
+        data: *mut ::std::os::raw::c_void,
+        call: QffiMapEntryCall,
+    );
+}
+extern "C" {
+    pub fn qffi_QHashQStringInt_init(self_: *mut QHashQStringInt);
+}
+extern "C" {
+    pub fn qffi_QHashQStringInt_clone(self_: *const QHashQStringInt, new_: *mut QHashQStringInt);
+}
+extern "C" {
+    pub fn qffi_QHashQStringInt_equals(
+        self_: *const QHashQStringInt,
+        other: *const QHashQStringInt,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QHashQStringInt_size(self_: *const QHashQStringInt) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QHashQStringInt_contains(
+        self_: *const QHashQStringInt,
+        key: *const QString,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QHashQStringInt_value(
+        self_: *const QHashQStringInt,
+        key: *const QString,
+    ) -> *const ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QHashQStringInt_insert(
+        self_: *mut QHashQStringInt,
+        key: *const QString,
+        value: *const ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn qffi_QHashQStringInt_remove(
+        self_: *mut QHashQStringInt,
+        key: *const QString,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QHashQStringInt_forEach(
+        self_: *const QHashQStringInt,
+        data: *mut ::std::os::raw::c_void,
+        call: QffiMapEntryCall,
+    );
+}
+extern "C" {
+    pub fn qffi_QHashQStringDouble_init(self_: *mut QHashQStringDouble);
+}
+extern "C" {
+    pub fn qffi_QHashQStringDouble_clone(
+        self_: *const QHashQStringDouble,
+        new_: *mut QHashQStringDouble,
+    );
+}
+extern "C" {
+    pub fn qffi_QHashQStringDouble_equals(
+        self_: *const QHashQStringDouble,
+        other: *const QHashQStringDouble,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QHashQStringDouble_size(self_: *const QHashQStringDouble) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QHashQStringDouble_contains(
+        self_: *const QHashQStringDouble,
+        key: *const QString,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QHashQStringDouble_value(
+        self_: *const QHashQStringDouble,
+        key: *const QString,
+    ) -> *const f64;
+}
+extern "C" {
+    pub fn qffi_QHashQStringDouble_insert(
+        self_: *mut QHashQStringDouble,
+        key: *const QString,
+        value: *const f64,
+    );
+}
+extern "C" {
+    pub fn qffi_QHashQStringDouble_remove(
+        self_: *mut QHashQStringDouble,
+        key: *const QString,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QHashQStringDouble_forEach(
+        self_: *const QHashQStringDouble,
+        data: *mut ::std::os::raw::c_void,
+        call: QffiMapEntryCall,
+    );
+}
+extern "C" {
+    pub fn qffi_QHashQStringQString_init(self_: *mut QHashQStringQString);
+}
+extern "C" {
+    pub fn qffi_QHashQStringQString_clone(
+        self_: *const QHashQStringQString,
+        new_: *mut QHashQStringQString,
+    );
+}
+extern "C" {
+    pub fn qffi_QHashQStringQString_equals(
+        self_: *const QHashQStringQString,
+        other: *const QHashQStringQString,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QHashQStringQString_size(
+        self_: *const QHashQStringQString,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QHashQStringQString_contains(
+        self_: *const QHashQStringQString,
+        key: *const QString,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QHashQStringQString_value(
+        self_: *const QHashQStringQString,
+        key: *const QString,
+    ) -> *const QString;
+}
+extern "C" {
+    pub fn qffi_QHashQStringQString_insert(
+        self_: *mut QHashQStringQString,
+        key: *const QString,
+        value: *const QString,
+    );
+}
+extern "C" {
+    pub fn qffi_QHashQStringQString_remove(
+        self_: *mut QHashQStringQString,
+        key: *const QString,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QHashQStringQString_forEach(
+        self_: *const QHashQStringQString,
+        data: *mut ::std::os::raw::c_void,
+        call: QffiMapEntryCall,
+    );
+}
+extern "C" {
+    pub fn qffi_QHashQStringQVariant_init(self_: *mut QHashQStringQVariant);
+}
+extern "C" {
+    pub fn qffi_QHashQStringQVariant_clone(
+        self_: *const QHashQStringQVariant,
+        new_: *mut QHashQStringQVariant,
+    );
+}
+extern "C" {
+    pub fn qffi_QHashQStringQVariant_equals(
+        self_: *const QHashQStringQVariant,
+        other: *const QHashQStringQVariant,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QHashQStringQVariant_size(
+        self_: *const QHashQStringQVariant,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QHashQStringQVariant_contains(
+        self_: *const QHashQStringQVariant,
+        key: *const QString,
+    ) -> bool;
+}
+extern "C" {
+    pub fn qffi_QHashQStringQVariant_value(
+        self_: *const QHashQStringQVariant,
+        key: *const QString,
+    ) -> *const QVariant;
+}
+extern "C" {
+    pub fn qffi_QHashQStringQVariant_insert(
+        self_: *mut QHashQStringQVariant,
+        key: *const QString,
+        value: *const QVariant,
+    );
+}
+extern "C" {
+    pub fn qffi_QHashQStringQVariant_remove(
+        self_: *mut QHashQStringQVariant,
+        key: *const QString,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn qffi_QHashQStringQVariant_forEach(
+        self_: *const QHashQStringQVariant,
+        data: *mut ::std::os::raw::c_void,
+        call: QffiMapEntryCall,
+    );
+}
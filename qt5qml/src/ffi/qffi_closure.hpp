@@ -0,0 +1,115 @@
+#pragma once
+
+#include <QHash>
+#include <QMetaObject>
+#include <QMutex>
+#include <QMutexLocker>
+#include <QObject>
+#include <QThread>
+
+// Receiver of a signal that forwards all calls to a Rust closure.
+//
+// The receiver has no meta object of its own. Its only slot is the first method index after
+// the methods of QObject and is handled by overriding qt_metacall, so no moc run is needed.
+// The receiver is a child of the sender and is deleted together with the sender or when its
+// connection is disconnected with QffiClosureReceiver::disconnect. The closure is dropped in
+// the destructor.
+class QffiClosureReceiver : public QObject {
+public:
+  using Call = void (*)(void* data, void** args);
+  using Drop = void (*)(void* data);
+
+  QffiClosureReceiver(QObject* sender, Call call, void* data, Drop drop)
+    : QObject(sender), call_(call), data_(data), drop_(drop)
+  { }
+
+  ~QffiClosureReceiver() override {
+    if (connection_) {
+      QMutexLocker lock(&mutex());
+      registry().remove(key(connection_));
+    }
+    drop_(data_);
+  }
+
+  int qt_metacall(QMetaObject::Call call, int id, void** args) override {
+    id = QObject::qt_metacall(call, id, args);
+    if (id < 0) {
+      return id;
+    }
+    if (call == QMetaObject::InvokeMetaMethod) {
+      if (id == 0) {
+        call_(data_, args);
+      }
+      return -1;
+    }
+    return id;
+  }
+
+  static QMetaObject::Connection connect(
+      const QObject* sender, const char* signal, Call call, void* data, Drop drop,
+      Qt::ConnectionType type) {
+    // signal is in the format of the SIGNAL macro
+    int index = -1;
+    if (signal[0] == '2') {
+      QByteArray normalized = QMetaObject::normalizedSignature(signal + 1);
+      index = sender->metaObject()->indexOfSignal(normalized.constData());
+    }
+    if (index < 0) {
+      drop(data);
+      return QMetaObject::Connection();
+    }
+
+    auto* receiver = new QffiClosureReceiver(
+      const_cast<QObject*>(sender), call, data, drop);
+    QMetaObject::Connection connection = QMetaObject::connect(
+      sender, index, receiver, QObject::staticMetaObject.methodCount(), type);
+    if (!connection) {
+      delete receiver;
+      return connection;
+    }
+
+    receiver->connection_ = connection;
+    QMutexLocker lock(&mutex());
+    registry().insert(key(connection), receiver);
+    return connection;
+  }
+
+  static bool disconnect(const QMetaObject::Connection& connection) {
+    QffiClosureReceiver* receiver;
+    {
+      QMutexLocker lock(&mutex());
+      receiver = registry().value(key(connection), nullptr);
+    }
+
+    bool result = QObject::disconnect(connection);
+    if (receiver) {
+      if (receiver->thread() == QThread::currentThread()) {
+        delete receiver;
+      } else {
+        receiver->deleteLater();
+      }
+    }
+    return result;
+  }
+
+private:
+  Call call_;
+  void* data_;
+  Drop drop_;
+  QMetaObject::Connection connection_;
+
+  static void* key(const QMetaObject::Connection& connection) {
+    // QMetaObject::Connection only consists of a pointer to the shared connection data
+    return *reinterpret_cast<void* const*>(&connection);
+  }
+
+  static QHash<void*, QffiClosureReceiver*>& registry() {
+    static QHash<void*, QffiClosureReceiver*> instance;
+    return instance;
+  }
+
+  static QMutex& mutex() {
+    static QMutex instance;
+    return instance;
+  }
+};
@@ -315,57 +315,49 @@ impl Drop for QGuiApplication {
     }
 }
 
-impl QHashIntQByteArray {
+impl Drop for QThread {
     #[inline]
-    pub fn new() -> Self {
-        unsafe {
-            let mut ret = MaybeUninit::uninit();
-            qffi_QHashIntQByteArray_init(ret.as_mut_ptr());
-            ret.assume_init()
-        }
+    fn drop(&mut self) {
+        unsafe { qffi_QObject_destroy(self as *mut _ as *mut crate::ffi::QObject) }
     }
 }
-impl Default for QHashIntQByteArray {
+
+impl Drop for QQmlEngine {
     #[inline]
-    fn default() -> Self {
-        QHashIntQByteArray::new()
+    fn drop(&mut self) {
+        unsafe { qffi_QObject_destroy(self as *mut _ as *mut crate::ffi::QObject) }
     }
 }
 
-impl Clone for QHashIntQByteArray {
+impl Drop for QQmlApplicationEngine {
     #[inline]
-    fn clone(&self) -> Self {
-        unsafe {
-            let mut ret = MaybeUninit::uninit();
-            qffi_QHashIntQByteArray_clone(self, ret.as_mut_ptr());
-            ret.assume_init()
-        }
+    fn drop(&mut self) {
+        unsafe { qffi_QObject_destroy(self as *mut _ as *mut crate::ffi::QObject) }
     }
 }
 
-impl PartialEq for QHashIntQByteArray {
+impl Drop for QAbstractItemModel {
     #[inline]
-    fn eq(&self, other: &Self) -> bool {
-        unsafe { qffi_QHashIntQByteArray_equals(self, other) }
+    fn drop(&mut self) {
+        unsafe { qffi_QObject_destroy(self as *mut _ as *mut crate::ffi::QObject) }
     }
 }
-impl Eq for QHashIntQByteArray { }
 
-impl Drop for QThread {
+impl Drop for QffiListModel {
     #[inline]
     fn drop(&mut self) {
         unsafe { qffi_QObject_destroy(self as *mut _ as *mut crate::ffi::QObject) }
     }
 }
 
-impl Drop for QQmlEngine {
+impl Drop for QffiItemModel {
     #[inline]
     fn drop(&mut self) {
         unsafe { qffi_QObject_destroy(self as *mut _ as *mut crate::ffi::QObject) }
     }
 }
 
-impl Drop for QQmlApplicationEngine {
+impl Drop for QffiSortFilterProxyModel {
     #[inline]
     fn drop(&mut self) {
         unsafe { qffi_QObject_destroy(self as *mut _ as *mut crate::ffi::QObject) }
@@ -443,3 +435,1047 @@ impl PartialEq for QStringList {
     }
 }
 impl Eq for QStringList { }
+
+impl QListBool {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QListBool_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QListBool {
+    #[inline]
+    fn default() -> Self {
+        QListBool::new()
+    }
+}
+
+impl Clone for QListBool {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QListBool_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QListBool {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QListBool_equals(self, other) }
+    }
+}
+impl Eq for QListBool { }
+
+impl QListInt {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QListInt_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QListInt {
+    #[inline]
+    fn default() -> Self {
+        QListInt::new()
+    }
+}
+
+impl Clone for QListInt {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QListInt_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QListInt {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QListInt_equals(self, other) }
+    }
+}
+impl Eq for QListInt { }
+
+impl QListLongLong {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QListLongLong_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QListLongLong {
+    #[inline]
+    fn default() -> Self {
+        QListLongLong::new()
+    }
+}
+
+impl Clone for QListLongLong {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QListLongLong_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QListLongLong {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QListLongLong_equals(self, other) }
+    }
+}
+impl Eq for QListLongLong { }
+
+impl QListDouble {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QListDouble_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QListDouble {
+    #[inline]
+    fn default() -> Self {
+        QListDouble::new()
+    }
+}
+
+impl Clone for QListDouble {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QListDouble_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QListDouble {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QListDouble_equals(self, other) }
+    }
+}
+impl Eq for QListDouble { }
+
+impl QListQByteArray {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QListQByteArray_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QListQByteArray {
+    #[inline]
+    fn default() -> Self {
+        QListQByteArray::new()
+    }
+}
+
+impl Clone for QListQByteArray {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QListQByteArray_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QListQByteArray {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QListQByteArray_equals(self, other) }
+    }
+}
+impl Eq for QListQByteArray { }
+
+impl QListQVariant {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QListQVariant_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QListQVariant {
+    #[inline]
+    fn default() -> Self {
+        QListQVariant::new()
+    }
+}
+
+impl Clone for QListQVariant {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QListQVariant_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QListQVariant {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QListQVariant_equals(self, other) }
+    }
+}
+impl Eq for QListQVariant { }
+
+impl QVectorBool {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QVectorBool_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QVectorBool {
+    #[inline]
+    fn default() -> Self {
+        QVectorBool::new()
+    }
+}
+
+impl Clone for QVectorBool {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QVectorBool_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QVectorBool {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QVectorBool_equals(self, other) }
+    }
+}
+impl Eq for QVectorBool { }
+
+impl QVectorInt {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QVectorInt_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QVectorInt {
+    #[inline]
+    fn default() -> Self {
+        QVectorInt::new()
+    }
+}
+
+impl Clone for QVectorInt {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QVectorInt_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QVectorInt {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QVectorInt_equals(self, other) }
+    }
+}
+impl Eq for QVectorInt { }
+
+impl QVectorLongLong {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QVectorLongLong_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QVectorLongLong {
+    #[inline]
+    fn default() -> Self {
+        QVectorLongLong::new()
+    }
+}
+
+impl Clone for QVectorLongLong {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QVectorLongLong_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QVectorLongLong {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QVectorLongLong_equals(self, other) }
+    }
+}
+impl Eq for QVectorLongLong { }
+
+impl QVectorDouble {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QVectorDouble_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QVectorDouble {
+    #[inline]
+    fn default() -> Self {
+        QVectorDouble::new()
+    }
+}
+
+impl Clone for QVectorDouble {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QVectorDouble_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QVectorDouble {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QVectorDouble_equals(self, other) }
+    }
+}
+impl Eq for QVectorDouble { }
+
+impl QVectorQString {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QVectorQString_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QVectorQString {
+    #[inline]
+    fn default() -> Self {
+        QVectorQString::new()
+    }
+}
+
+impl Clone for QVectorQString {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QVectorQString_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QVectorQString {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QVectorQString_equals(self, other) }
+    }
+}
+impl Eq for QVectorQString { }
+
+impl QVectorQByteArray {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QVectorQByteArray_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QVectorQByteArray {
+    #[inline]
+    fn default() -> Self {
+        QVectorQByteArray::new()
+    }
+}
+
+impl Clone for QVectorQByteArray {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QVectorQByteArray_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QVectorQByteArray {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QVectorQByteArray_equals(self, other) }
+    }
+}
+impl Eq for QVectorQByteArray { }
+
+impl QVectorQVariant {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QVectorQVariant_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QVectorQVariant {
+    #[inline]
+    fn default() -> Self {
+        QVectorQVariant::new()
+    }
+}
+
+impl Clone for QVectorQVariant {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QVectorQVariant_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QVectorQVariant {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QVectorQVariant_equals(self, other) }
+    }
+}
+impl Eq for QVectorQVariant { }
+
+impl QMapIntInt {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QMapIntInt_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QMapIntInt {
+    #[inline]
+    fn default() -> Self {
+        QMapIntInt::new()
+    }
+}
+
+impl Clone for QMapIntInt {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QMapIntInt_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QMapIntInt {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QMapIntInt_equals(self, other) }
+    }
+}
+impl Eq for QMapIntInt { }
+
+impl QMapIntQString {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QMapIntQString_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QMapIntQString {
+    #[inline]
+    fn default() -> Self {
+        QMapIntQString::new()
+    }
+}
+
+impl Clone for QMapIntQString {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QMapIntQString_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QMapIntQString {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QMapIntQString_equals(self, other) }
+    }
+}
+impl Eq for QMapIntQString { }
+
+impl QMapIntQByteArray {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QMapIntQByteArray_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QMapIntQByteArray {
+    #[inline]
+    fn default() -> Self {
+        QMapIntQByteArray::new()
+    }
+}
+
+impl Clone for QMapIntQByteArray {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QMapIntQByteArray_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QMapIntQByteArray {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QMapIntQByteArray_equals(self, other) }
+    }
+}
+impl Eq for QMapIntQByteArray { }
+
+impl QMapIntQVariant {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QMapIntQVariant_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QMapIntQVariant {
+    #[inline]
+    fn default() -> Self {
+        QMapIntQVariant::new()
+    }
+}
+
+impl Clone for QMapIntQVariant {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QMapIntQVariant_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QMapIntQVariant {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QMapIntQVariant_equals(self, other) }
+    }
+}
+impl Eq for QMapIntQVariant { }
+
+impl QMapQStringInt {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QMapQStringInt_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QMapQStringInt {
+    #[inline]
+    fn default() -> Self {
+        QMapQStringInt::new()
+    }
+}
+
+impl Clone for QMapQStringInt {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QMapQStringInt_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QMapQStringInt {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QMapQStringInt_equals(self, other) }
+    }
+}
+impl Eq for QMapQStringInt { }
+
+impl QMapQStringDouble {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QMapQStringDouble_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QMapQStringDouble {
+    #[inline]
+    fn default() -> Self {
+        QMapQStringDouble::new()
+    }
+}
+
+impl Clone for QMapQStringDouble {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QMapQStringDouble_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QMapQStringDouble {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QMapQStringDouble_equals(self, other) }
+    }
+}
+impl Eq for QMapQStringDouble { }
+
+impl QMapQStringQString {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QMapQStringQString_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QMapQStringQString {
+    #[inline]
+    fn default() -> Self {
+        QMapQStringQString::new()
+    }
+}
+
+impl Clone for QMapQStringQString {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QMapQStringQString_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QMapQStringQString {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QMapQStringQString_equals(self, other) }
+    }
+}
+impl Eq for QMapQStringQString { }
+
+impl QMapQStringQVariant {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QMapQStringQVariant_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QMapQStringQVariant {
+    #[inline]
+    fn default() -> Self {
+        QMapQStringQVariant::new()
+    }
+}
+
+impl Clone for QMapQStringQVariant {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QMapQStringQVariant_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QMapQStringQVariant {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QMapQStringQVariant_equals(self, other) }
+    }
+}
+impl Eq for QMapQStringQVariant { }
+
+impl QHashIntInt {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QHashIntInt_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QHashIntInt {
+    #[inline]
+    fn default() -> Self {
+        QHashIntInt::new()
+    }
+}
+
+impl Clone for QHashIntInt {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QHashIntInt_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QHashIntInt {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QHashIntInt_equals(self, other) }
+    }
+}
+impl Eq for QHashIntInt { }
+
+impl QHashIntQString {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QHashIntQString_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QHashIntQString {
+    #[inline]
+    fn default() -> Self {
+        QHashIntQString::new()
+    }
+}
+
+impl Clone for QHashIntQString {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QHashIntQString_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QHashIntQString {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QHashIntQString_equals(self, other) }
+    }
+}
+impl Eq for QHashIntQString { }
+
+impl QHashIntQByteArray {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QHashIntQByteArray_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QHashIntQByteArray {
+    #[inline]
+    fn default() -> Self {
+        QHashIntQByteArray::new()
+    }
+}
+
+impl Clone for QHashIntQByteArray {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QHashIntQByteArray_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QHashIntQByteArray {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QHashIntQByteArray_equals(self, other) }
+    }
+}
+impl Eq for QHashIntQByteArray { }
+
+impl QHashIntQVariant {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QHashIntQVariant_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QHashIntQVariant {
+    #[inline]
+    fn default() -> Self {
+        QHashIntQVariant::new()
+    }
+}
+
+impl Clone for QHashIntQVariant {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QHashIntQVariant_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QHashIntQVariant {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QHashIntQVariant_equals(self, other) }
+    }
+}
+impl Eq for QHashIntQVariant { }
+
+impl QHashQStringInt {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QHashQStringInt_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QHashQStringInt {
+    #[inline]
+    fn default() -> Self {
+        QHashQStringInt::new()
+    }
+}
+
+impl Clone for QHashQStringInt {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QHashQStringInt_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QHashQStringInt {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QHashQStringInt_equals(self, other) }
+    }
+}
+impl Eq for QHashQStringInt { }
+
+impl QHashQStringDouble {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QHashQStringDouble_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QHashQStringDouble {
+    #[inline]
+    fn default() -> Self {
+        QHashQStringDouble::new()
+    }
+}
+
+impl Clone for QHashQStringDouble {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QHashQStringDouble_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QHashQStringDouble {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QHashQStringDouble_equals(self, other) }
+    }
+}
+impl Eq for QHashQStringDouble { }
+
+impl QHashQStringQString {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QHashQStringQString_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QHashQStringQString {
+    #[inline]
+    fn default() -> Self {
+        QHashQStringQString::new()
+    }
+}
+
+impl Clone for QHashQStringQString {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QHashQStringQString_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QHashQStringQString {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QHashQStringQString_equals(self, other) }
+    }
+}
+impl Eq for QHashQStringQString { }
+
+impl QHashQStringQVariant {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QHashQStringQVariant_init(ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+impl Default for QHashQStringQVariant {
+    #[inline]
+    fn default() -> Self {
+        QHashQStringQVariant::new()
+    }
+}
+
+impl Clone for QHashQStringQVariant {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ret = MaybeUninit::uninit();
+            qffi_QHashQStringQVariant_clone(self, ret.as_mut_ptr());
+            ret.assume_init()
+        }
+    }
+}
+
+impl PartialEq for QHashQStringQVariant {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qffi_QHashQStringQVariant_equals(self, other) }
+    }
+}
+impl Eq for QHashQStringQVariant { }
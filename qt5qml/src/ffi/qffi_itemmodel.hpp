@@ -0,0 +1,118 @@
+#pragma once
+
+#include <QAbstractItemModel>
+#include <QByteArray>
+#include <QHash>
+#include <QVariant>
+#include <QVector>
+
+// Functions of a Rust item model.
+//
+// Indexes are created from the internal id returned by `index` and `parent`, so the Rust side
+// never needs the model pointer.
+struct QffiItemModelVTable {
+  bool (*index)(const void* data, int row, int column, const QModelIndex* parent, quintptr* id);
+  bool (*parent)(const void* data, const QModelIndex* index, int* row, quintptr* id);
+  int (*rowCount)(const void* data, const QModelIndex* parent);
+  int (*columnCount)(const void* data, const QModelIndex* parent);
+  void (*data)(const void* data, const QModelIndex* index, int role, QVariant* result);
+  bool (*setData)(void* data, const QModelIndex* index, const QVariant* value, int role);
+  int (*flags)(const void* data, const QModelIndex* index);
+  void (*headerData)(
+      const void* data, int section, int orientation, int role, QVariant* result);
+  void (*roleNames)(const void* data, QHash<int, QByteArray>* result);
+  void (*drop)(void* data);
+};
+
+// Item model that forwards all model functions to Rust.
+//
+// Like QffiListModel, the model has no meta object of its own. The Rust data is dropped in the
+// destructor.
+class QffiItemModel : public QAbstractItemModel {
+public:
+  QffiItemModel(QObject* parent, void* data, const QffiItemModelVTable& vtable)
+    : QAbstractItemModel(parent), data_(data), vtable_(vtable)
+  { }
+
+  ~QffiItemModel() override {
+    vtable_.drop(data_);
+  }
+
+  QModelIndex index(int row, int column, const QModelIndex& parent = QModelIndex()) const override {
+    quintptr id = 0;
+    if (row < 0 || column < 0 || !vtable_.index(data_, row, column, &parent, &id)) {
+      return QModelIndex();
+    }
+    return createIndex(row, column, id);
+  }
+
+  QModelIndex parent(const QModelIndex& index) const override {
+    int row = 0;
+    quintptr id = 0;
+    if (!index.isValid() || !vtable_.parent(data_, &index, &row, &id)) {
+      return QModelIndex();
+    }
+    return createIndex(row, 0, id);
+  }
+
+  int rowCount(const QModelIndex& parent = QModelIndex()) const override {
+    return vtable_.rowCount(data_, &parent);
+  }
+
+  int columnCount(const QModelIndex& parent = QModelIndex()) const override {
+    return vtable_.columnCount(data_, &parent);
+  }
+
+  QVariant data(const QModelIndex& index, int role) const override {
+    QVariant result;
+    if (index.isValid()) {
+      vtable_.data(data_, &index, role, &result);
+    }
+    return result;
+  }
+
+  bool setData(const QModelIndex& index, const QVariant& value, int role) override {
+    if (!index.isValid() || !vtable_.setData(data_, &index, &value, role)) {
+      return false;
+    }
+    Q_EMIT dataChanged(index, index, QVector<int>() << role);
+    return true;
+  }
+
+  Qt::ItemFlags flags(const QModelIndex& index) const override {
+    if (!index.isValid()) {
+      return QAbstractItemModel::flags(index);
+    }
+    return Qt::ItemFlags(vtable_.flags(data_, &index));
+  }
+
+  QVariant headerData(int section, Qt::Orientation orientation, int role) const override {
+    QVariant result;
+    vtable_.headerData(data_, section, int(orientation), role, &result);
+    return result;
+  }
+
+  QHash<int, QByteArray> roleNames() const override {
+    QHash<int, QByteArray> result;
+    vtable_.roleNames(data_, &result);
+    return result.isEmpty() ? QAbstractItemModel::roleNames() : result;
+  }
+
+  using QAbstractItemModel::beginInsertRows;
+  using QAbstractItemModel::endInsertRows;
+  using QAbstractItemModel::beginRemoveRows;
+  using QAbstractItemModel::endRemoveRows;
+  using QAbstractItemModel::beginMoveRows;
+  using QAbstractItemModel::endMoveRows;
+  using QAbstractItemModel::beginInsertColumns;
+  using QAbstractItemModel::endInsertColumns;
+  using QAbstractItemModel::beginRemoveColumns;
+  using QAbstractItemModel::endRemoveColumns;
+  using QAbstractItemModel::beginResetModel;
+  using QAbstractItemModel::endResetModel;
+  using QAbstractItemModel::createIndex;
+
+private:
+  void* data_;
+  QffiItemModelVTable vtable_;
+};
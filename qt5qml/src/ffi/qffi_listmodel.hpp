@@ -0,0 +1,60 @@
+#pragma once
+
+#include <QAbstractListModel>
+#include <QByteArray>
+#include <QHash>
+#include <QVariant>
+
+// List model that forwards rowCount and data to Rust.
+//
+// The model has no meta object of its own, so no moc run is needed. The role names are fixed
+// when the model is created. The Rust data is dropped in the destructor.
+class QffiListModel : public QAbstractListModel {
+public:
+  using RowCount = int (*)(const void* data);
+  using Data = void (*)(const void* data, int row, int role, void* result);
+  using Drop = void (*)(void* data);
+
+  QffiListModel(
+      QObject* parent, void* data, RowCount rowCount, Data itemData, Drop drop,
+      QHash<int, QByteArray> roleNames)
+    : QAbstractListModel(parent), data_(data), rowCount_(rowCount), itemData_(itemData),
+      drop_(drop), roleNames_(std::move(roleNames))
+  { }
+
+  ~QffiListModel() override {
+    drop_(data_);
+  }
+
+  int rowCount(const QModelIndex& parent = QModelIndex()) const override {
+    return parent.isValid() ? 0 : rowCount_(data_);
+  }
+
+  QVariant data(const QModelIndex& index, int role) const override {
+    QVariant result;
+    if (index.isValid() && !index.parent().isValid() && index.row() < rowCount_(data_)) {
+      itemData_(data_, index.row(), role, &result);
+    }
+    return result;
+  }
+
+  QHash<int, QByteArray> roleNames() const override {
+    return roleNames_;
+  }
+
+  using QAbstractListModel::beginInsertRows;
+  using QAbstractListModel::endInsertRows;
+  using QAbstractListModel::beginRemoveRows;
+  using QAbstractListModel::endRemoveRows;
+  using QAbstractListModel::beginMoveRows;
+  using QAbstractListModel::endMoveRows;
+  using QAbstractListModel::beginResetModel;
+  using QAbstractListModel::endResetModel;
+
+private:
+  void* data_;
+  RowCount rowCount_;
+  Data itemData_;
+  Drop drop_;
+  QHash<int, QByteArray> roleNames_;
+};
@@ -0,0 +1,55 @@
+#pragma once
+
+#include <QSortFilterProxyModel>
+
+// Functions of a Rust sort filter proxy model.
+//
+// `filterAcceptsRow` and `lessThan` return false, when they do not handle the call and the
+// implementation of QSortFilterProxyModel is used. `filterAcceptsRow` gets the source index of
+// the first column of the row.
+struct QffiSortFilterProxyModelVTable {
+  bool (*filterAcceptsRow)(const void* data, const QModelIndex* sourceIndex, bool* result);
+  bool (*lessThan)(
+      const void* data, const QModelIndex* left, const QModelIndex* right, bool* result);
+  void (*drop)(void* data);
+};
+
+// Sort filter proxy model that forwards filtering and sorting to Rust.
+//
+// Like QffiListModel, the model has no meta object of its own. The Rust data is dropped in the
+// destructor.
+class QffiSortFilterProxyModel : public QSortFilterProxyModel {
+public:
+  QffiSortFilterProxyModel(
+      QObject* parent, void* data, const QffiSortFilterProxyModelVTable& vtable)
+    : QSortFilterProxyModel(parent), data_(data), vtable_(vtable)
+  { }
+
+  ~QffiSortFilterProxyModel() override {
+    vtable_.drop(data_);
+  }
+
+  using QSortFilterProxyModel::invalidateFilter;
+
+protected:
+  bool filterAcceptsRow(int sourceRow, const QModelIndex& sourceParent) const override {
+    bool result = false;
+    QModelIndex sourceIndex = sourceModel()->index(sourceRow, 0, sourceParent);
+    if (vtable_.filterAcceptsRow(data_, &sourceIndex, &result)) {
+      return result;
+    }
+    return QSortFilterProxyModel::filterAcceptsRow(sourceRow, sourceParent);
+  }
+
+  bool lessThan(const QModelIndex& left, const QModelIndex& right) const override {
+    bool result = false;
+    if (vtable_.lessThan(data_, &left, &right, &result)) {
+      return result;
+    }
+    return QSortFilterProxyModel::lessThan(left, right);
+  }
+
+private:
+  void* data_;
+  QffiSortFilterProxyModelVTable vtable_;
+};
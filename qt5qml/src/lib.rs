@@ -61,6 +61,32 @@ macro_rules! signal {
     };
 }
 
+/// Get typed slot connect name.
+///
+/// Like `slot!`, but creates a `TypedSlot` with the argument types inferred from the context.
+/// This is unsafe because the argument types are not checked.
+#[macro_export]
+macro_rules! typed_slot {
+    ($strlit:expr) => {
+        $crate::core::TypedSlot::from_raw(std::ffi::CStr::from_bytes_with_nul_unchecked(
+            concat!("1", $strlit, "\0").as_bytes(),
+        ))
+    };
+}
+
+/// Get typed signal connect name.
+///
+/// Like `signal!`, but creates a `TypedSignal` with the argument types inferred from the
+/// context. This is unsafe because the argument types are not checked.
+#[macro_export]
+macro_rules! typed_signal {
+    ($strlit:expr) => {
+        $crate::core::TypedSignal::from_raw(std::ffi::CStr::from_bytes_with_nul_unchecked(
+            concat!("2", $strlit, "\0").as_bytes(),
+        ))
+    };
+}
+
 macro_rules! impl_qobject_ref {
     ($ty:ty) => {
         impl crate::core::QObjectRef for $ty {
@@ -99,6 +125,7 @@ pub(crate) mod ffi;
 
 pub mod core;
 pub mod gui;
+pub mod panic;
 pub mod qml;
 
 use crate::core::{QObject, QObjectRef};
@@ -149,6 +176,15 @@ impl<T: QObjectRef> QBox<T> {
     pub unsafe fn from_raw(ptr: *mut T) -> Self {
         Self(ptr::NonNull::new(ptr).expect("tried to create a QBox from a null pointer"))
     }
+
+    /// Release ownership of the object.
+    ///
+    /// The object is not deleted anymore, when the box is dropped.
+    pub fn into_raw(self) -> *mut T {
+        let ptr = self.0.as_ptr();
+        std::mem::forget(self);
+        ptr
+    }
 }
 
 unsafe impl<T: QObjectRef> Send for QBox<T> where T: Send {}
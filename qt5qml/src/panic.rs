@@ -0,0 +1,55 @@
+//! Handling of Rust panics in functions called from C++.
+//!
+//! Unwinding into C++ code is undefined behavior, so the generated `extern "C"` functions
+//! call the Rust implementation through one of these functions.
+
+use std::any::Any;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::process::abort;
+
+/// Function called with the name of the C++ entry point and the panic payload.
+pub type PanicHook = fn(function: &str, payload: Box<dyn Any + Send>);
+
+/// Message of a panic payload created by `panic!`.
+pub fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&'static str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "Box<dyn Any>"
+    }
+}
+
+/// Print panic message to stderr.
+pub fn log_panic(function: &str, payload: Box<dyn Any + Send>) {
+    eprintln!(
+        "Rust panic in {} called from C++: {}",
+        function,
+        panic_message(&*payload)
+    );
+}
+
+/// Call `f` and abort the process, when it panics.
+pub fn abort_on_panic<R, F: FnOnce() -> R>(function: &str, f: F) -> R {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result,
+        Err(payload) => {
+            log_panic(function, payload);
+            abort()
+        }
+    }
+}
+
+/// Call `f` and pass a panic to `hook`.
+///
+/// Returns `None`, when `f` panics.
+pub fn catch_panic<R, F: FnOnce() -> R>(function: &str, hook: PanicHook, f: F) -> Option<R> {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => Some(result),
+        Err(payload) => {
+            hook(function, payload);
+            None
+        }
+    }
+}
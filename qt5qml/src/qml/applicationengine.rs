@@ -1,4 +1,4 @@
-use crate::core::{QObject, QObjectList, QUrl};
+use crate::core::{QObject, QObjectList, QObjectRef, QString, QUrl};
 use crate::ffi::QffiWrapper;
 use crate::QBox;
 use std::ptr;
@@ -46,4 +46,17 @@ impl QQmlApplicationEngine {
             })
         }
     }
+
+    /// Make `value` available to QML under `name`.
+    ///
+    /// The engine does not take ownership of `value`.
+    pub fn set_context_property<T: QObjectRef>(&mut self, name: &str, value: &mut T) {
+        unsafe {
+            crate::ffi::qffi_QQmlApplicationEngine_setContextProperty(
+                self.to_inner_mut(),
+                QString::from(name).to_inner(),
+                value.as_qobject_mut().to_inner_mut(),
+            );
+        }
+    }
 }
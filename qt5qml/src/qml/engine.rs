@@ -1,4 +1,32 @@
+use crate::core::QObject;
+use crate::ffi::QffiWrapper;
+use crate::QBox;
+use std::ptr;
+
 #[repr(C)]
 pub struct QQmlEngine(pub(crate) crate::ffi::QQmlEngine);
 impl_ffi_trait!(QQmlEngine);
 impl_qobject_ref!(QQmlEngine);
+
+impl QQmlEngine {
+    pub fn new() -> QBox<QQmlEngine> {
+        unsafe {
+            QBox::from_raw(std::mem::transmute(crate::ffi::qffi_QQmlEngine_init(
+                ptr::null_mut(),
+            )))
+        }
+    }
+
+    /// Instance of the singleton type registered with the id `type_id`.
+    ///
+    /// The instance is created on the first call and owned by the engine. Returns a null
+    /// pointer, when `type_id` is no QObject singleton type.
+    pub fn singleton_instance(&mut self, type_id: i32) -> *mut QObject {
+        unsafe {
+            std::mem::transmute(crate::ffi::qffi_QQmlEngine_singletonInstance(
+                self.to_inner_mut(),
+                type_id,
+            ))
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::{BTreeMap, HashMap};
+
+use qt5qml::core::{QHash, QList, QMap, QString, QVariantMap, QVector, ToQString};
+
+#[test]
+fn check_list_of_int() {
+    let list: QList<i32> = vec![1, 2, 3].into();
+
+    assert_eq!(3, list.len());
+    assert_eq!(Some(&2), list.get(1));
+    assert_eq!(None, list.get(3));
+    assert_eq!(
+        vec![3, 2, 1],
+        list.iter().rev().copied().collect::<Vec<_>>()
+    );
+    assert_eq!(vec![1, 2, 3], Vec::from(&list));
+}
+
+#[test]
+fn check_list_append() {
+    let mut list: QList<f64> = vec![1.0].into();
+    list.append(&vec![2.0, 3.0].into());
+
+    assert_eq!(vec![1.0, 2.0, 3.0], list.to_vec());
+}
+
+#[test]
+fn check_vector_of_qstring() {
+    let mut vector: QVector<QString> = QVector::new();
+    vector.push(&"a".to_qstring());
+    vector.extend_from_slice(&["b".to_qstring(), "c".to_qstring()]);
+
+    assert_eq!(3, vector.len());
+    assert_eq!("b".to_qstring(), vector.as_slice()[1]);
+    assert_eq!(Some(&"c".to_qstring()), vector.get(2));
+}
+
+#[test]
+fn check_map() {
+    let mut map: QMap<QString, i32> = QMap::new();
+    map.insert(&"b".to_qstring(), &2);
+    map.insert(&"a".to_qstring(), &1);
+
+    assert_eq!(2, map.len());
+    assert!(map.contains_key(&"a".to_qstring()));
+    assert_eq!(Some(&2), map.get(&"b".to_qstring()));
+    assert_eq!(None, map.get(&"c".to_qstring()));
+
+    let keys: Vec<String> = map.iter().map(|(key, _)| key.to_string()).collect();
+    assert_eq!(vec!["a", "b"], keys);
+
+    assert!(map.remove(&"a".to_qstring()));
+    assert!(!map.remove(&"a".to_qstring()));
+    assert_eq!(1, map.len());
+}
+
+#[test]
+fn check_map_from_btree_map() {
+    let mut input = BTreeMap::new();
+    input.insert("key".to_qstring(), "value".to_qstring());
+
+    let map: QMap<QString, QString> = input.clone().into();
+
+    assert_eq!(input, BTreeMap::from(&map));
+}
+
+#[test]
+fn check_hash_from_hash_map() {
+    let mut input = HashMap::new();
+    input.insert(1, "one".to_qstring());
+    input.insert(2, "two".to_qstring());
+
+    let hash: QHash<i32, QString> = input.clone().into();
+
+    assert_eq!(2, hash.len());
+    assert_eq!(input, HashMap::from(&hash));
+}
+
+#[test]
+fn check_variant_map() {
+    let map: QVariantMap = vec![("name".to_qstring(), "value".into())]
+        .into_iter()
+        .collect();
+
+    assert_eq!(
+        "value",
+        String::from(map.get(&"name".to_qstring()).unwrap())
+    );
+}
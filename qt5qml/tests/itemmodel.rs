@@ -0,0 +1,160 @@
+use qt5qml::core::{
+    ListItem, QAbstractItemModel, QModelIndex, QObject, QObjectRef, QVariant, RustListModel,
+    RustTreeModel, TreeModel, QT_DISPLAY_ROLE, QT_EDIT_ROLE, QT_USER_ROLE,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+struct Item(String);
+
+impl ListItem for Item {
+    fn role_names() -> Vec<&'static str> {
+        vec!["name"]
+    }
+
+    fn data(&self, role: i32) -> QVariant {
+        if role == QT_USER_ROLE {
+            (&self.0 as &str).into()
+        } else {
+            QVariant::new()
+        }
+    }
+}
+
+/// Editable flat list.
+struct Names(Vec<String>);
+
+impl TreeModel for Names {
+    fn index(&self, row: i32, _column: i32, parent: &QModelIndex) -> Option<usize> {
+        if !parent.is_valid() && (row as usize) < self.0.len() {
+            Some(row as usize)
+        } else {
+            None
+        }
+    }
+
+    fn parent(&self, _index: &QModelIndex) -> Option<(i32, usize)> {
+        None
+    }
+
+    fn row_count(&self, parent: &QModelIndex) -> i32 {
+        if parent.is_valid() {
+            0
+        } else {
+            self.0.len() as i32
+        }
+    }
+
+    fn data(&self, index: &QModelIndex, role: i32) -> QVariant {
+        if role == QT_DISPLAY_ROLE {
+            (&self.0[index.row() as usize] as &str).into()
+        } else {
+            QVariant::new()
+        }
+    }
+
+    fn set_data(&mut self, index: &QModelIndex, value: &QVariant, role: i32) -> bool {
+        if role == QT_EDIT_ROLE {
+            self.0[index.row() as usize] = value.into();
+            true
+        } else {
+            false
+        }
+    }
+}
+
+fn create_list_model() -> RustListModel<Item> {
+    RustListModel::from(vec![Item("a".into()), Item("b".into())])
+}
+
+#[test]
+fn item_model_read() {
+    let list = create_list_model();
+    let model = list.as_item_model();
+
+    assert_eq!(2, model.row_count(&QModelIndex::new()));
+    assert_eq!(1, model.column_count(&QModelIndex::new()));
+
+    let index = model.index(1, 0, &QModelIndex::new());
+    assert!(!model.parent(&index).is_valid());
+    assert_eq!("b", String::from(&model.data(&index, QT_USER_ROLE)));
+    assert!(!model.data(&index, QT_DISPLAY_ROLE).is_valid());
+    assert_eq!(
+        b"name",
+        model.role_names().get(&QT_USER_ROLE).unwrap().as_slice()
+    );
+}
+
+#[test]
+fn item_model_cast() {
+    let list = create_list_model();
+    assert!(QAbstractItemModel::cast(list.as_qobject()).is_some());
+
+    let object = QObject::new();
+    assert!(QAbstractItemModel::cast(&object).is_none());
+}
+
+#[test]
+fn item_model_index_model() {
+    let list = create_list_model();
+    let index = list.as_item_model().index(0, 0, &QModelIndex::new());
+
+    let model = unsafe { index.model_ref() }.unwrap();
+    assert_eq!(2, model.row_count(&QModelIndex::new()));
+    assert!(unsafe { QModelIndex::new().model_ref() }.is_none());
+}
+
+#[test]
+fn item_model_set_data() {
+    let mut tree = RustTreeModel::new(Names(vec!["a".into()]));
+    let changed = Rc::new(RefCell::new(vec![]));
+
+    let changed_ = changed.clone();
+    let connection = tree.connect_typed_fn(
+        QAbstractItemModel::data_changed_signal(),
+        move |(top_left, _, roles)| {
+            changed_
+                .borrow_mut()
+                .push((top_left.row(), roles.as_slice().to_vec()))
+        },
+    );
+    assert!(connection.is_valid());
+
+    let index = tree.index(0, 0, &QModelIndex::new());
+    let model = QAbstractItemModel::cast_mut(tree.as_qobject_mut()).unwrap();
+    assert!(model.set_data(&index, &"b".into(), QT_EDIT_ROLE));
+    assert!(!model.set_data(&index, &"c".into(), QT_USER_ROLE));
+
+    assert_eq!(vec![(0, vec![QT_EDIT_ROLE])], *changed.borrow());
+    assert_eq!("b", String::from(&model.data(&index, QT_DISPLAY_ROLE)));
+}
+
+#[test]
+fn item_model_rows_signals() {
+    let mut list = create_list_model();
+    let events = Rc::new(RefCell::new(vec![]));
+
+    let events_ = events.clone();
+    list.connect_typed_fn(
+        QAbstractItemModel::rows_inserted_signal(),
+        move |(_, first, last)| events_.borrow_mut().push(("inserted", first, last)),
+    );
+    let events_ = events.clone();
+    list.connect_typed_fn(
+        QAbstractItemModel::rows_removed_signal(),
+        move |(_, first, last)| events_.borrow_mut().push(("removed", first, last)),
+    );
+    let events_ = events.clone();
+    list.connect_typed_fn(QAbstractItemModel::model_reset_signal(), move |()| {
+        events_.borrow_mut().push(("reset", -1, -1))
+    });
+
+    list.push(Item("c".into()));
+    list.remove(0);
+    list.clear();
+
+    assert_eq!(
+        vec![("inserted", 2, 2), ("removed", 0, 0), ("reset", -1, -1)],
+        *events.borrow()
+    );
+}
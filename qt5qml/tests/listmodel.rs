@@ -0,0 +1,274 @@
+use qt5qml::core::{
+    ListItem, QAbstractItemModel, QModelIndex, QObjectRef, QVariant, RustListModel, SignalArgs,
+    TypedSignal, QT_USER_ROLE,
+};
+use qt5qml::typed_signal;
+use std::cell::RefCell;
+use std::ffi::CStr;
+use std::rc::Rc;
+
+#[derive(Debug, PartialEq)]
+struct Item {
+    name: String,
+    count: i32,
+}
+
+impl Item {
+    fn new(name: &str, count: i32) -> Self {
+        Self {
+            name: name.into(),
+            count,
+        }
+    }
+}
+
+impl ListItem for Item {
+    fn role_names() -> Vec<&'static str> {
+        vec!["name", "count"]
+    }
+
+    fn data(&self, role: i32) -> QVariant {
+        match role - QT_USER_ROLE {
+            0 => (&self.name as &str).into(),
+            1 => self.count.into(),
+            _ => QVariant::new(),
+        }
+    }
+}
+
+fn rows_signal(name: &str) -> TypedSignal<(QModelIndex, i32, i32)> {
+    unsafe {
+        match name {
+            "inserted" => typed_signal!("rowsInserted(QModelIndex,int,int)"),
+            "removed" => typed_signal!("rowsRemoved(QModelIndex,int,int)"),
+            _ => unreachable!(),
+        }
+    }
+}
+
+fn record_rows(model: &RustListModel<Item>, name: &str) -> Rc<RefCell<Vec<(i32, i32)>>> {
+    let rows = Rc::new(RefCell::new(vec![]));
+    let rows_ = rows.clone();
+    let connection = model.connect_typed_fn(rows_signal(name), move |(_, first, last)| {
+        rows_.borrow_mut().push((first, last))
+    });
+    assert!(connection.is_valid());
+    rows
+}
+
+#[test]
+fn list_model_is_list_model() {
+    let model: RustListModel<Item> = RustListModel::new();
+    assert!(model.inherits(CStr::from_bytes_with_nul(b"QAbstractListModel\0").unwrap()));
+    assert!(model.is_empty());
+}
+
+#[test]
+fn list_model_push_insert() {
+    let mut model = RustListModel::new();
+    let inserted = record_rows(&model, "inserted");
+
+    model.push(Item::new("a", 1));
+    model.push(Item::new("c", 3));
+    model.insert(1, Item::new("b", 2));
+
+    assert_eq!(vec![(0, 0), (1, 1), (1, 1)], *inserted.borrow());
+    assert_eq!(
+        vec!["a", "b", "c"],
+        model
+            .iter()
+            .map(|item| &item.name as &str)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn list_model_remove() {
+    let mut model = RustListModel::from(vec![Item::new("a", 1), Item::new("b", 2)]);
+    let removed = record_rows(&model, "removed");
+
+    assert_eq!(Item::new("a", 1), model.remove(0));
+
+    assert_eq!(vec![(0, 0)], *removed.borrow());
+    assert_eq!(1, model.len());
+    assert_eq!(Some(&Item::new("b", 2)), model.get(0));
+}
+
+#[test]
+fn list_model_set() {
+    let mut model = RustListModel::from(vec![Item::new("a", 1), Item::new("b", 2)]);
+    let changed = Rc::new(RefCell::new(vec![]));
+
+    let changed_ = changed.clone();
+    let signal: TypedSignal<(QModelIndex, QModelIndex)> =
+        unsafe { typed_signal!("dataChanged(QModelIndex,QModelIndex)") };
+    let connection = model.connect_typed_fn(signal, move |(top_left, bottom_right)| {
+        changed_
+            .borrow_mut()
+            .push((top_left.row(), bottom_right.row()))
+    });
+    assert!(connection.is_valid());
+
+    assert_eq!(Item::new("b", 2), model.set(1, Item::new("c", 3)));
+
+    assert_eq!(vec![(1, 1)], *changed.borrow());
+    assert_eq!(Some(&Item::new("c", 3)), model.get(1));
+}
+
+#[test]
+fn list_model_replace_all() {
+    let mut model = RustListModel::from(vec![Item::new("a", 1)]);
+    let resets = Rc::new(RefCell::new(0));
+
+    let resets_ = resets.clone();
+    let signal: TypedSignal<()> = unsafe { typed_signal!("modelReset()") };
+    let connection = model.connect_typed_fn(signal, move |()| *resets_.borrow_mut() += 1);
+    assert!(connection.is_valid());
+
+    let old = model.replace_all(vec![Item::new("b", 2), Item::new("c", 3)]);
+    model.clear();
+
+    assert_eq!(vec![Item::new("a", 1)], old);
+    assert_eq!(2, *resets.borrow());
+    assert!(model.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn list_model_remove_out_of_bounds() {
+    let mut model: RustListModel<Item> = RustListModel::new();
+    model.remove(0);
+}
+
+/// Rows of `model` rebuilt from the signals of the model.
+fn shadow_rows(model: &RustListModel<Item>) -> Rc<RefCell<Vec<String>>> {
+    let rows: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(
+        model.iter().map(|item| item.name.clone()).collect(),
+    ));
+    let item_model = model.as_item_model() as *const QAbstractItemModel;
+    let name = move |row: i32| {
+        let model = unsafe { &*item_model };
+        let index = model.index(row, 0, &QModelIndex::new());
+        String::from(&model.data(&index, QT_USER_ROLE))
+    };
+
+    let rows_ = rows.clone();
+    model.connect_typed_fn(
+        QAbstractItemModel::rows_inserted_signal(),
+        move |(_, first, last)| {
+            for row in first..=last {
+                rows_.borrow_mut().insert(row as usize, name(row));
+            }
+        },
+    );
+    let rows_ = rows.clone();
+    model.connect_typed_fn(
+        QAbstractItemModel::rows_removed_signal(),
+        move |(_, first, last)| {
+            rows_.borrow_mut().drain(first as usize..=last as usize);
+        },
+    );
+    let rows_ = rows.clone();
+    model.connect_typed_fn(
+        QAbstractItemModel::rows_moved_signal(),
+        move |(_, first, last, _, destination)| {
+            let mut rows = rows_.borrow_mut();
+            let moved: Vec<String> = rows.drain(first as usize..=last as usize).collect();
+            let destination = if destination > last {
+                destination - (last - first + 1)
+            } else {
+                destination
+            };
+            let destination = destination as usize;
+            rows.splice(destination..destination, moved);
+        },
+    );
+    let rows_ = rows.clone();
+    model.connect_typed_fn(
+        QAbstractItemModel::data_changed_signal(),
+        move |(top_left, bottom_right, _)| {
+            for row in top_left.row()..=bottom_right.row() {
+                rows_.borrow_mut()[row as usize] = name(row);
+            }
+        },
+    );
+    rows
+}
+
+fn items(names: &[&str]) -> Vec<Item> {
+    names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| Item::new(name, i as i32))
+        .collect()
+}
+
+fn count_signal<A: SignalArgs>(
+    model: &RustListModel<Item>,
+    signal: TypedSignal<A>,
+) -> Rc<RefCell<i32>> {
+    let count = Rc::new(RefCell::new(0));
+    let count_ = count.clone();
+    model.connect_typed_fn(signal, move |_| *count_.borrow_mut() += 1);
+    count
+}
+
+#[test]
+fn list_model_update_all() {
+    let mut model = RustListModel::from(items(&["a", "b", "c", "d", "e"]));
+    let rows = shadow_rows(&model);
+    let resets = count_signal(&model, QAbstractItemModel::model_reset_signal());
+
+    let updates: &[&[&str]] = &[
+        &["a", "c", "d", "e"],
+        &["e", "a", "c", "d"],
+        &["e", "x", "a", "y", "c", "d"],
+        &["d", "c", "y", "a", "x", "e"],
+        &["z"],
+        &[],
+        &["a", "b"],
+    ];
+    for &update in updates {
+        model.update_all(items(update), |item| item.name.clone());
+        assert_eq!(update, &rows.borrow()[..]);
+        assert_eq!(
+            update,
+            &model
+                .iter()
+                .map(|item| &item.name as &str)
+                .collect::<Vec<_>>()[..]
+        );
+    }
+    assert_eq!(0, *resets.borrow());
+}
+
+#[test]
+fn list_model_update_all_moves() {
+    let mut model = RustListModel::from(items(&["a", "b", "c", "d"]));
+    let moves = count_signal(&model, QAbstractItemModel::rows_moved_signal());
+    let changes = count_signal(&model, QAbstractItemModel::data_changed_signal());
+
+    let mut new_items = items(&["b", "c", "d", "a"]);
+    for item in &mut new_items {
+        item.count = model
+            .iter()
+            .find(|old| old.name == item.name)
+            .unwrap()
+            .count;
+    }
+    model.update_all(new_items, |item| item.name.clone());
+
+    assert_eq!(1, *moves.borrow());
+    assert_eq!(0, *changes.borrow());
+}
+
+#[test]
+fn list_model_update_all_duplicate_keys() {
+    let mut model = RustListModel::from(items(&["a", "b"]));
+    let resets = count_signal(&model, QAbstractItemModel::model_reset_signal());
+
+    model.update_all(items(&["a", "a"]), |item| item.name.clone());
+
+    assert_eq!(1, *resets.borrow());
+    assert_eq!(2, model.len());
+}
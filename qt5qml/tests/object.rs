@@ -1,4 +1,10 @@
 use qt5qml::core::{ConnectionType, QObject, QObjectRef, QTimer};
+use qt5qml::{signal, QBox};
+use std::cell::Cell;
+use std::ptr;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 
 #[test]
@@ -132,3 +138,156 @@ fn object_disconnect_nothing() {
     );
     assert!(!success);
 }
+
+#[test]
+fn object_connect_fn() {
+    let object = QObject::new();
+    let calls = Rc::new(Cell::new(0));
+
+    let calls_ = calls.clone();
+    let connection = object.connect_fn(QObject::destroyed_signal(), move |_| {
+        calls_.set(calls_.get() + 1)
+    });
+    assert!(connection.is_valid());
+
+    drop(object);
+    assert_eq!(1, calls.get());
+}
+
+#[test]
+fn object_connect_fn_args() {
+    let object = QObject::new();
+    let sender = Rc::new(Cell::new(ptr::null_mut()));
+
+    let sender_ = sender.clone();
+    object.connect_fn(QObject::destroyed_signal(), move |args| {
+        sender_.set(unsafe { *args.get::<*mut QObject>(0) })
+    });
+
+    let object_ptr = &*object as *const QObject as *mut QObject;
+    drop(object);
+    assert_eq!(object_ptr, sender.get());
+}
+
+#[test]
+fn object_connect_fn_unknown_signal() {
+    let object = QObject::new();
+    let calls = Rc::new(Cell::new(0));
+
+    let calls_ = calls.clone();
+    let connection = object.connect_fn(signal!("unknownSignal()"), move |_| {
+        calls_.set(calls_.get() + 1)
+    });
+    assert!(!connection.is_valid());
+    assert_eq!(1, Rc::strong_count(&calls));
+}
+
+#[test]
+fn object_connect_fn_dropped_with_sender() {
+    let object = QObject::new();
+    let calls = Rc::new(Cell::new(0));
+
+    let calls_ = calls.clone();
+    object.connect_fn(QObject::object_name_changed_signal(), move |_| {
+        calls_.set(calls_.get() + 1)
+    });
+    assert_eq!(2, Rc::strong_count(&calls));
+
+    drop(object);
+    assert_eq!(1, Rc::strong_count(&calls));
+}
+
+#[test]
+fn object_connect_fn_dropped_on_disconnect() {
+    let object = QObject::new();
+    let calls = Rc::new(Cell::new(0));
+
+    let calls_ = calls.clone();
+    let connection = object.connect_fn(QObject::object_name_changed_signal(), move |_| {
+        calls_.set(calls_.get() + 1)
+    });
+    assert_eq!(2, Rc::strong_count(&calls));
+
+    assert!(QObject::disconnect(&connection));
+    assert_eq!(1, Rc::strong_count(&calls));
+    assert!(!QObject::disconnect(&connection));
+}
+
+#[test]
+fn object_connect_fn_with_type() {
+    let object = QObject::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let calls_ = calls.clone();
+    let connection = object.connect_fn_with_type(
+        QObject::destroyed_signal(),
+        move |_| {
+            calls_.fetch_add(1, Ordering::SeqCst);
+        },
+        ConnectionType::default(),
+    );
+    assert!(connection.is_valid());
+    assert_eq!(2, Arc::strong_count(&calls));
+
+    drop(object);
+    assert_eq!(1, calls.load(Ordering::SeqCst));
+    assert_eq!(1, Arc::strong_count(&calls));
+}
+
+#[test]
+fn object_connect_typed() {
+    let object = QObject::new();
+    let mut timer = QTimer::new();
+    timer.set_interval(Duration::from_secs(10));
+
+    let connection = object.connect_typed(
+        QObject::destroyed_signal(),
+        timer.as_qobject(),
+        QTimer::start_slot(),
+        ConnectionType::default(),
+    );
+    assert!(connection.is_valid());
+
+    assert!(!timer.is_active());
+    drop(object);
+    assert!(timer.is_active());
+}
+
+#[test]
+fn object_connect_typed_signal_signal() {
+    let object1 = QObject::new();
+    let object2 = QObject::new();
+
+    let connection = object1.connect_typed(
+        QObject::destroyed_signal(),
+        &object2,
+        QObject::destroyed_signal().into(),
+        ConnectionType::default(),
+    );
+    assert!(connection.is_valid());
+}
+
+#[test]
+fn object_connect_typed_fn() {
+    let object = QObject::new();
+    let sender = Rc::new(Cell::new(ptr::null_mut()));
+
+    let sender_ = sender.clone();
+    let connection =
+        object.connect_typed_fn(QObject::destroyed_signal(), move |(obj,)| sender_.set(obj));
+    assert!(connection.is_valid());
+
+    let object_ptr = &*object as *const QObject as *mut QObject;
+    drop(object);
+    assert_eq!(object_ptr, sender.get());
+}
+
+#[test]
+fn object_box_into_raw() {
+    let object = QObject::new();
+    let object_ptr = &*object as *const QObject as *mut QObject;
+
+    let raw = object.into_raw();
+    assert_eq!(object_ptr, raw);
+    drop(unsafe { QBox::from_raw(raw) });
+}
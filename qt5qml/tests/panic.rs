@@ -0,0 +1,42 @@
+use qt5qml::panic::{abort_on_panic, catch_panic, panic_message};
+use std::any::Any;
+use std::cell::RefCell;
+
+thread_local! {
+    static CAUGHT: RefCell<Vec<String>> = const { RefCell::new(vec![]) };
+}
+
+fn record_panic(function: &str, payload: Box<dyn Any + Send>) {
+    let message = format!("{}: {}", function, panic_message(&*payload));
+    CAUGHT.with(|caught| caught.borrow_mut().push(message));
+}
+
+#[test]
+fn catch_panic_returns_result() {
+    assert_eq!(
+        Some(42),
+        catch_panic("Qffi_Test_value", record_panic, || 42)
+    );
+}
+
+#[test]
+fn catch_panic_calls_hook() {
+    let result: Option<i32> = catch_panic("Qffi_Test_value", record_panic, || panic!("broken"));
+
+    assert_eq!(None, result);
+    CAUGHT.with(|caught| assert_eq!(vec!["Qffi_Test_value: broken"], *caught.borrow()));
+}
+
+#[test]
+fn catch_panic_formatted_message() {
+    let result: Option<()> =
+        catch_panic("Qffi_Test_value", record_panic, || panic!("value {}", 42));
+
+    assert_eq!(None, result);
+    CAUGHT.with(|caught| assert_eq!(vec!["Qffi_Test_value: value 42"], *caught.borrow()));
+}
+
+#[test]
+fn abort_on_panic_returns_result() {
+    assert_eq!("ok", abort_on_panic("Qffi_Test_value", || "ok"));
+}
@@ -0,0 +1,105 @@
+use qt5qml::core::{
+    ListItem, QModelIndex, QVariant, RustListModel, RustSortFilterProxyModel, SortOrder,
+    QT_USER_ROLE,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+struct Item(&'static str);
+
+impl ListItem for Item {
+    fn role_names() -> Vec<&'static str> {
+        vec!["name"]
+    }
+
+    fn data(&self, role: i32) -> QVariant {
+        if role == QT_USER_ROLE {
+            self.0.into()
+        } else {
+            QVariant::new()
+        }
+    }
+}
+
+fn create_source() -> RustListModel<Item> {
+    RustListModel::from(vec![
+        Item("banana"),
+        Item("cherry"),
+        Item("apple"),
+        Item("date"),
+    ])
+}
+
+fn names(proxy: &RustSortFilterProxyModel) -> Vec<String> {
+    let model = proxy.as_item_model();
+    (0..model.row_count(&QModelIndex::new()))
+        .map(|row| {
+            let index = model.index(row, 0, &QModelIndex::new());
+            String::from(&model.data(&index, QT_USER_ROLE))
+        })
+        .collect()
+}
+
+fn name(index: &QModelIndex) -> String {
+    String::from(&unsafe { index.data(QT_USER_ROLE) })
+}
+
+#[test]
+fn proxy_without_callbacks() {
+    let mut source = create_source();
+    let mut proxy = RustSortFilterProxyModel::new();
+    assert!(proxy.source_model().is_none());
+
+    proxy.set_source_model(source.as_item_model_mut());
+
+    assert!(proxy.source_model().is_some());
+    assert_eq!(vec!["banana", "cherry", "apple", "date"], names(&proxy));
+}
+
+#[test]
+fn proxy_filter() {
+    let mut source = create_source();
+    let mut proxy = RustSortFilterProxyModel::new();
+    proxy.set_source_model(source.as_item_model_mut());
+
+    let needle = Rc::new(RefCell::new("a"));
+    let needle_ = needle.clone();
+    proxy.set_filter(move |index| name(index).contains(*needle_.borrow()));
+    assert_eq!(vec!["banana", "apple", "date"], names(&proxy));
+
+    *needle.borrow_mut() = "e";
+    proxy.invalidate_filter();
+    assert_eq!(vec!["cherry", "apple", "date"], names(&proxy));
+
+    source.push(Item("elderberry"));
+    assert_eq!(vec!["cherry", "apple", "date", "elderberry"], names(&proxy));
+}
+
+#[test]
+fn proxy_sort() {
+    let mut source = create_source();
+    let mut proxy = RustSortFilterProxyModel::new();
+    proxy.set_source_model(source.as_item_model_mut());
+
+    proxy.set_less_than(|left, right| name(left).len() < name(right).len());
+    proxy.sort(0, SortOrder::Ascending);
+    assert_eq!(vec!["date", "apple", "banana", "cherry"], names(&proxy));
+
+    proxy.sort(0, SortOrder::Descending);
+    assert_eq!(vec!["banana", "cherry", "apple", "date"], names(&proxy));
+}
+
+#[test]
+fn proxy_map_to_source() {
+    let mut source = create_source();
+    let mut proxy = RustSortFilterProxyModel::new();
+    proxy.set_source_model(source.as_item_model_mut());
+    proxy.set_filter(|index| index.row() % 2 == 1);
+
+    let proxy_index = proxy.as_item_model().index(1, 0, &QModelIndex::new());
+    let source_index = proxy.map_to_source(&proxy_index);
+
+    assert_eq!(3, source_index.row());
+    assert_eq!("date", name(&source_index));
+    assert_eq!(proxy_index, proxy.map_from_source(&source_index));
+}
@@ -0,0 +1,180 @@
+use qt5qml::core::{
+    ItemFlags, QModelIndex, QObjectRef, QVariant, RustTreeModel, TreeModel, TypedSignal,
+    QT_DISPLAY_ROLE,
+};
+use qt5qml::typed_signal;
+use std::cell::RefCell;
+use std::ffi::CStr;
+use std::rc::Rc;
+
+struct Node {
+    name: String,
+    parent: Option<usize>,
+    children: Vec<usize>,
+}
+
+/// Tree with nodes identified by their position in `nodes`.
+#[derive(Default)]
+struct Tree {
+    nodes: Vec<Node>,
+    roots: Vec<usize>,
+}
+
+impl Tree {
+    fn add(&mut self, parent: Option<usize>, name: &str) -> usize {
+        let id = self.nodes.len();
+        self.nodes.push(Node {
+            name: name.into(),
+            parent,
+            children: vec![],
+        });
+        match parent {
+            Some(parent) => self.nodes[parent].children.push(id),
+            None => self.roots.push(id),
+        }
+        id
+    }
+
+    fn children(&self, parent: &QModelIndex) -> &[usize] {
+        if parent.is_valid() {
+            &self.nodes[parent.internal_id()].children
+        } else {
+            &self.roots
+        }
+    }
+}
+
+impl TreeModel for Tree {
+    fn index(&self, row: i32, _column: i32, parent: &QModelIndex) -> Option<usize> {
+        self.children(parent).get(row as usize).copied()
+    }
+
+    fn parent(&self, index: &QModelIndex) -> Option<(i32, usize)> {
+        let parent = self.nodes[index.internal_id()].parent?;
+        let siblings = match self.nodes[parent].parent {
+            Some(grandparent) => &self.nodes[grandparent].children,
+            None => &self.roots,
+        };
+        let row = siblings.iter().position(|&id| id == parent).unwrap();
+        Some((row as i32, parent))
+    }
+
+    fn row_count(&self, parent: &QModelIndex) -> i32 {
+        self.children(parent).len() as i32
+    }
+
+    fn data(&self, index: &QModelIndex, role: i32) -> QVariant {
+        if role == QT_DISPLAY_ROLE {
+            (&self.nodes[index.internal_id()].name as &str).into()
+        } else {
+            QVariant::new()
+        }
+    }
+
+    fn flags(&self, index: &QModelIndex) -> ItemFlags {
+        let flags = ItemFlags::ITEM_IS_SELECTABLE | ItemFlags::ITEM_IS_ENABLED;
+        if self.nodes[index.internal_id()].children.is_empty() {
+            flags | ItemFlags::ITEM_NEVER_HAS_CHILDREN
+        } else {
+            flags
+        }
+    }
+}
+
+fn create_tree() -> Tree {
+    let mut tree = Tree::default();
+    let a = tree.add(None, "a");
+    tree.add(Some(a), "a1");
+    tree.add(Some(a), "a2");
+    tree.add(None, "b");
+    tree
+}
+
+fn display(index: &QModelIndex) -> String {
+    String::from(&unsafe { index.data(QT_DISPLAY_ROLE) })
+}
+
+#[test]
+fn tree_model_is_item_model() {
+    let model = RustTreeModel::new(create_tree());
+    assert!(model.inherits(CStr::from_bytes_with_nul(b"QAbstractItemModel\0").unwrap()));
+}
+
+#[test]
+fn tree_model_navigation() {
+    let model = RustTreeModel::new(create_tree());
+
+    let a = model.index(0, 0, &QModelIndex::new());
+    assert!(a.is_valid());
+    assert_eq!("a", display(&a));
+    unsafe {
+        assert!(!a.parent().is_valid());
+
+        let a2 = a.child(1, 0);
+        assert_eq!("a2", display(&a2));
+        assert_eq!(a, a2.parent());
+        assert_eq!("a1", display(&a2.sibling(0, 0)));
+
+        let b = a.sibling(1, 0);
+        assert_eq!("b", display(&b));
+        assert!(!b.child(0, 0).is_valid());
+    }
+    assert!(!model.index(2, 0, &QModelIndex::new()).is_valid());
+}
+
+#[test]
+fn tree_model_flags() {
+    let model = RustTreeModel::new(create_tree());
+    let a = model.index(0, 0, &QModelIndex::new());
+
+    unsafe {
+        assert!(!a.flags().contains(ItemFlags::ITEM_NEVER_HAS_CHILDREN));
+        assert!(a
+            .child(0, 0)
+            .flags()
+            .contains(ItemFlags::ITEM_IS_ENABLED | ItemFlags::ITEM_NEVER_HAS_CHILDREN));
+    }
+}
+
+#[test]
+fn tree_model_insert_rows() {
+    let mut model = RustTreeModel::new(create_tree());
+    let inserted = Rc::new(RefCell::new(vec![]));
+
+    let inserted_ = inserted.clone();
+    let signal: TypedSignal<(QModelIndex, i32, i32)> =
+        unsafe { typed_signal!("rowsInserted(QModelIndex,int,int)") };
+    let connection = model.connect_typed_fn(signal, move |(parent, first, last)| {
+        inserted_.borrow_mut().push((display(&parent), first, last))
+    });
+    assert!(connection.is_valid());
+
+    let b = model.index(1, 0, &QModelIndex::new());
+    let b_id = b.internal_id();
+    model.insert_rows(&b, 0, 0, |tree| {
+        tree.add(Some(b_id), "b1");
+    });
+
+    assert_eq!(vec![("b".to_string(), 0, 0)], *inserted.borrow());
+    assert_eq!("b1", display(&model.index(0, 0, &b)));
+}
+
+#[test]
+fn tree_model_update() {
+    let mut model = RustTreeModel::new(create_tree());
+    let changed = Rc::new(RefCell::new(vec![]));
+
+    let changed_ = changed.clone();
+    let signal: TypedSignal<(QModelIndex, QModelIndex)> =
+        unsafe { typed_signal!("dataChanged(QModelIndex,QModelIndex)") };
+    let connection = model.connect_typed_fn(signal, move |(top_left, _)| {
+        changed_.borrow_mut().push(display(&top_left))
+    });
+    assert!(connection.is_valid());
+
+    let a1 = model.index(0, 0, &model.index(0, 0, &QModelIndex::new()));
+    let a1_id = a1.internal_id();
+    model.update(&a1, &a1, |tree| tree.nodes[a1_id].name = "c".into());
+
+    assert_eq!(vec!["c".to_string()], *changed.borrow());
+}
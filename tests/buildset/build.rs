@@ -0,0 +1,37 @@
+use qobject_compiler::{QObjectBuild, QObjectBuildSet, QObjectMethod, QObjectProp, QtInstallation};
+
+fn main() {
+    let qt = QtInstallation::find(&["Core"]).unwrap();
+    qt.link();
+    let moc = qt.moc_config();
+    let cpp = qt.cc_build();
+
+    let mut first = QObjectBuild::new("First");
+    let mut second = QObjectBuild::new("Second");
+    let first_ptr = first.type_ref().with_mut_ptr();
+    let second_ptr = second.type_ref().with_mut_ptr();
+
+    first
+        .property(
+            QObjectProp::new_with_type(second_ptr.clone(), "second")
+                .read("second")
+                .write("setSecond"),
+        )
+        .method(QObjectMethod::new("second").ret_type(second_ptr.clone()))
+        .method(QObjectMethod::new("setSecond").arg_with_type("value", second_ptr))
+        .qml(false);
+    second
+        .property(
+            QObjectProp::new_with_type(first_ptr.clone(), "first")
+                .read("first")
+                .write("setFirst"),
+        )
+        .method(QObjectMethod::new("first").ret_type(first_ptr.clone()))
+        .method(QObjectMethod::new("setFirst").arg_with_type("value", first_ptr))
+        .qml(false);
+
+    QObjectBuildSet::new("TestObjects")
+        .add(&first)
+        .add(&second)
+        .build(&cpp, &moc);
+}
@@ -0,0 +1,77 @@
+use std::ptr;
+
+include!(concat!(env!("OUT_DIR"), "/qffi_TestObjects.rs"));
+
+pub struct FirstPrivate {
+    second: *mut Second,
+}
+
+impl FirstImpl for FirstPrivate {
+    fn new(_qobject: *mut First) -> Self {
+        Self {
+            second: ptr::null_mut(),
+        }
+    }
+
+    fn second(&self) -> *mut Second {
+        self.second
+    }
+
+    fn set_second(&mut self, value: *mut Second) {
+        self.second = value;
+    }
+}
+
+pub struct SecondPrivate {
+    first: *mut First,
+}
+
+impl SecondImpl for SecondPrivate {
+    fn new(_qobject: *mut Second) -> Self {
+        Self {
+            first: ptr::null_mut(),
+        }
+    }
+
+    fn first(&self) -> *mut First {
+        self.first
+    }
+
+    fn set_first(&mut self, value: *mut First) {
+        self.first = value;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qt5qml::core::QObjectRef;
+
+    #[test]
+    fn reference_each_other() {
+        let mut first = First::new();
+        let mut second = Second::new();
+        let first_ptr = &mut *first as *mut First;
+        let second_ptr = &mut *second as *mut Second;
+
+        first.get_private().set_second(second_ptr);
+        second.get_private().set_first(first_ptr);
+
+        assert_eq!(second_ptr, first.get_private().second());
+        assert_eq!(first_ptr, second.get_private().first());
+    }
+
+    #[test]
+    fn property_types() {
+        let first = First::new();
+        let second = Second::new();
+
+        let prop = first.meta_object().own_properties().next().unwrap();
+        assert_eq!("second", prop.name().to_str().unwrap());
+        assert_eq!("Second*", prop.type_name().to_str().unwrap());
+
+        let prop = second.meta_object().own_properties().next().unwrap();
+        assert_eq!("first", prop.name().to_str().unwrap());
+        assert_eq!("First*", prop.type_name().to_str().unwrap());
+    }
+}
@@ -0,0 +1,46 @@
+use qobject_compiler::{
+    QObjectBuild, QObjectEnum, QObjectMethod, QObjectProp, QmlModule, QtInstallation,
+};
+
+fn main() {
+    let qt = QtInstallation::find(&["Core"]).unwrap();
+    qt.link();
+    let moc = qt.moc_config();
+    let cpp = qt.cc_build();
+
+    let mut build = QObjectBuild::new("TestObject");
+    build
+        .enum_(
+            QObjectEnum::new("Mode")
+                .value("Off")
+                .value("On")
+                .value_with("Auto", 10),
+        )
+        .flags(
+            "Options",
+            QObjectEnum::new("Option")
+                .value("Bold")
+                .value("Italic")
+                .value("Underline"),
+        );
+    let mode = build.enum_type_ref("Mode");
+    let options = build.enum_type_ref("Options");
+    build
+        .property(
+            QObjectProp::new_with_type(mode.clone(), "mode")
+                .read("mode")
+                .write("setMode"),
+        )
+        .property(
+            QObjectProp::new_with_type(options.clone(), "options")
+                .read("options")
+                .write("setOptions"),
+        )
+        .method(QObjectMethod::new("mode").ret_type(mode.clone()))
+        .method(QObjectMethod::new("setMode").arg_with_type("value", mode))
+        .method(QObjectMethod::new("options").ret_type(options.clone()))
+        .method(QObjectMethod::new("setOptions").arg_with_type("value", options))
+        .qml(false)
+        .qml_module(QmlModule::new("test.enums", 1, 0))
+        .build(&cpp, &moc);
+}
@@ -0,0 +1,115 @@
+#![allow(unused)]
+
+include!(concat!(env!("OUT_DIR"), "/qffi_TestObject.rs"));
+
+pub struct TestObjectPrivate {
+    _qobject: *mut TestObject,
+    mode: TestObjectMode,
+    options: TestObjectOptions,
+}
+
+impl TestObjectImpl for TestObjectPrivate {
+    fn new(qobject: *mut TestObject) -> Self {
+        Self {
+            _qobject: qobject,
+            mode: TestObjectMode::Auto,
+            options: TestObjectOptions::Bold | TestObjectOptions::Underline,
+        }
+    }
+
+    fn mode(&self) -> TestObjectMode {
+        self.mode
+    }
+
+    fn set_mode(&mut self, value: TestObjectMode) {
+        self.mode = value;
+    }
+
+    fn options(&self) -> TestObjectOptions {
+        self.options
+    }
+
+    fn set_options(&mut self, value: TestObjectOptions) {
+        self.options = value;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qt5qml::core::{QMetaObject, QMetaProperty, QObjectRef};
+    use std::collections::HashMap;
+    use std::convert::TryInto;
+
+    fn get_props(obj: &QMetaObject) -> HashMap<String, QMetaProperty> {
+        obj.own_properties()
+            .map(|e| (e.name().to_str().unwrap().to_owned(), e))
+            .collect()
+    }
+
+    #[test]
+    fn enum_values() {
+        assert_eq!(0, TestObjectMode::Off.value());
+        assert_eq!(1, TestObjectMode::On.value());
+        assert_eq!(10, TestObjectMode::Auto.value());
+        assert_eq!(TestObjectMode::Off, TestObjectMode::default());
+    }
+
+    #[test]
+    fn flag_values() {
+        assert_eq!(1, TestObjectOptions::Bold.bits());
+        assert_eq!(2, TestObjectOptions::Italic.bits());
+        assert_eq!(4, TestObjectOptions::Underline.bits());
+        assert!((TestObjectOptions::Bold | TestObjectOptions::Italic)
+            .contains(TestObjectOptions::Italic));
+    }
+
+    #[test]
+    fn enum_property() {
+        let obj = TestObject::new();
+        let props = get_props(obj.meta_object());
+        let mode = props.get("mode").unwrap();
+
+        assert!(mode.is_enum_type());
+        assert!(!mode.is_flag_type());
+        let value: i32 = mode.read(obj.as_qobject()).try_into().unwrap();
+        assert_eq!(10, value);
+    }
+
+    #[test]
+    fn flags_property() {
+        let obj = TestObject::new();
+        let props = get_props(obj.meta_object());
+        let options = props.get("options").unwrap();
+
+        assert!(options.is_enum_type());
+        assert!(options.is_flag_type());
+        let value: i32 = options.read(obj.as_qobject()).try_into().unwrap();
+        assert_eq!(5, value);
+    }
+
+    #[test]
+    fn write_enum_property() {
+        let mut obj = TestObject::new();
+        let props = get_props(obj.meta_object());
+
+        assert!(props
+            .get("mode")
+            .unwrap()
+            .write(obj.as_qobject_mut(), &1.into()));
+        assert_eq!(TestObjectMode::On, obj.get_private().mode());
+    }
+
+    #[test]
+    fn qml_type_description() {
+        let qmltypes = include_str!(concat!(env!("OUT_DIR"), "/plugins.qmltypes"));
+        let qmldir = include_str!(concat!(env!("OUT_DIR"), "/qmldir"));
+
+        assert!(qmltypes.contains("name: \"TestObject\""));
+        assert!(qmltypes.contains("name: \"Mode\""));
+        assert!(qmltypes.contains("\"Auto\": 10"));
+        assert!(qmltypes.contains("name: \"Options\""));
+        assert!(qmltypes.contains("Property { name: \"mode\"; type: \"TestObject::Mode\" }"));
+        assert!(qmldir.starts_with("module test.enums\n"));
+    }
+}
@@ -0,0 +1,32 @@
+use qobject_compiler::{
+    QGadgetBuild, QObjectBuild, QObjectBuildSet, QObjectMethod, QObjectProp, QObjectSignal,
+    QtInstallation,
+};
+
+fn main() {
+    let qt = QtInstallation::find(&["Core"]).unwrap();
+    qt.link();
+    let moc = qt.moc_config();
+    let cpp = qt.cc_build();
+
+    let mut point = QGadgetBuild::new("Point");
+    point
+        .property(QObjectProp::new::<i32>("x"))
+        .property(QObjectProp::new::<i32>("y"))
+        .method(QObjectMethod::new("manhattanLength").ret::<i32>().const_());
+
+    let mut object = QObjectBuild::new("TestObject");
+    object
+        .property(
+            QObjectProp::new_with_type(point.type_ref(), "point")
+                .notify("pointChanged")
+                .auto(),
+        )
+        .signal(QObjectSignal::new("pointChanged"))
+        .qml(false);
+
+    QObjectBuildSet::new("gadgets")
+        .add_gadget(&point)
+        .add(&object)
+        .build(&cpp, &moc);
+}
@@ -0,0 +1,100 @@
+#![allow(unused)]
+
+include!(concat!(env!("OUT_DIR"), "/qffi_gadgets.rs"));
+
+#[derive(Clone, Default)]
+pub struct PointPrivate {
+    x: i32,
+    y: i32,
+}
+
+impl PointImpl for PointPrivate {
+    fn x(&self) -> i32 {
+        self.x
+    }
+
+    fn set_x(&mut self, value: i32) {
+        self.x = value;
+    }
+
+    fn y(&self) -> i32 {
+        self.y
+    }
+
+    fn set_y(&mut self, value: i32) {
+        self.y = value;
+    }
+
+    fn manhattan_length(&self) -> i32 {
+        self.x.abs() + self.y.abs()
+    }
+}
+
+pub struct TestObjectPrivate {
+    _qobject: *mut TestObject,
+}
+
+impl TestObjectImpl for TestObjectPrivate {
+    fn new(qobject: *mut TestObject) -> Self {
+        Self { _qobject: qobject }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::c_void;
+
+    fn point(x: i32, y: i32) -> Point {
+        Point::new(PointPrivate { x, y })
+    }
+
+    #[test]
+    fn test_clone() {
+        let p1 = point(1, 2);
+        let mut p2 = p1.clone();
+        p2.set_x(3);
+
+        assert_eq!(1, p1.x());
+        assert_eq!(3, p2.x());
+    }
+
+    #[test]
+    fn test_variant() {
+        let variant = point(1, 2).to_variant();
+        assert!(variant.is_valid());
+
+        let p = Point::from_variant(&variant).unwrap();
+        assert_eq!(1, p.x());
+        assert_eq!(2, p.y());
+    }
+
+    #[test]
+    fn test_variant_wrong_type() {
+        let variant = qt5qml::core::QVariant::from("text");
+        assert!(Point::from_variant(&variant).is_none());
+    }
+
+    #[test]
+    fn test_meta_object() {
+        let meta = Point::static_meta_object();
+        assert_eq!("Point", meta.class_name().to_str().unwrap());
+        assert_eq!(2, meta.property_count());
+
+        let p = point(4, 5);
+        let y = unsafe {
+            meta.property(1)
+                .read_on_gadget(&*(&p as *const Point as *const c_void))
+        };
+        assert!(y.is_valid());
+    }
+
+    #[test]
+    fn test_property_by_value() {
+        let mut object = TestObject::new();
+        object.set_point(&point(1, 2));
+
+        let p = object.point();
+        assert_eq!(3, p.manhattan_length());
+    }
+}
@@ -1,16 +1,11 @@
-use qobject_compiler::moc::MocConfig;
 use qobject_compiler::typeref::{QModelIndex, QVariant, TypeRef};
-use qobject_compiler::{CcBuild, QObjectBuild, QObjectMethod};
+use qobject_compiler::{QObjectBuild, QObjectMethod, QtInstallation};
 
 fn main() {
-    let config = pkg_config::probe_library("Qt5Core").unwrap();
-
-    let mut moc = MocConfig::new();
-    let mut cpp = CcBuild::new();
-    for include in &config.include_paths {
-        cpp.include(include);
-        moc.include_path(include);
-    }
+    let qt = QtInstallation::find(&["Core"]).unwrap();
+    qt.link();
+    let moc = qt.moc_config();
+    let cpp = qt.cc_build();
 
     QObjectBuild::new("TestObject")
         .inherit(TypeRef::qt_core_object("QAbstractListModel"))
@@ -1,25 +1,148 @@
 #![allow(unused)]
 
-use qt5qml::core::{QModelIndex, QVariant};
+use qt5qml::core::{QModelIndex, QVariant, QVector};
 include!(concat!(env!("OUT_DIR"), "/qffi_TestObject.rs"));
 
 pub struct TestObjectPrivate {
-    _qobject: *mut TestObject,
+    qobject: *mut TestObject,
+    items: Vec<String>,
+}
+
+impl TestObjectImpl for TestObjectPrivate {
+    fn new(qobject: *mut TestObject) -> Self {
+        Self {
+            qobject,
+            items: vec![],
+        }
+    }
+
+    fn row_count(&self, _parent: &QModelIndex) -> i32 {
+        self.items.len() as i32
+    }
+
+    fn data(&self, index: &QModelIndex, _role: i32) -> QVariant {
+        match self.items.get(index.row() as usize) {
+            Some(item) => (item as &str).into(),
+            None => QVariant::new(),
+        }
+    }
 }
 
 impl TestObjectPrivate {
-    pub fn new(qobject: *mut TestObject) -> Self {
-        Self { _qobject: qobject }
+    pub fn push(&mut self, item: &str) {
+        let row = self.items.len() as i32;
+        let qobject = unsafe { &mut *self.qobject };
+        qobject.begin_insert_rows(&QModelIndex::new(), row, row);
+        self.items.push(item.into());
+        qobject.end_insert_rows();
     }
 
-    pub fn row_count(&self, _parent: &QModelIndex) -> i32 {
-        0
+    pub fn set(&mut self, row: i32, item: &str) {
+        self.items[row as usize] = item.into();
+        let qobject = unsafe { &mut *self.qobject };
+        let index = qobject.create_index(row, 0, 0);
+        qobject.data_changed(&index, &index, &QVector::new());
     }
 
-    pub fn data(&self, _parent: &QModelIndex, _role: i32) -> QVariant {
-        QVariant::new()
+    pub fn clear(&mut self) {
+        let qobject = unsafe { &mut *self.qobject };
+        qobject.begin_reset_model();
+        self.items.clear();
+        qobject.end_reset_model();
     }
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+    use qt5qml::core::{QAbstractItemModel, QObjectRef, RustSortFilterProxyModel, TypedSignal};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn check_insert_rows() {
+        let mut object = TestObject::new();
+        let inserted = Rc::new(RefCell::new(vec![]));
+
+        let inserted_ = inserted.clone();
+        let signal: TypedSignal<(QModelIndex, i32, i32)> =
+            unsafe { qt5qml::typed_signal!("rowsInserted(QModelIndex,int,int)") };
+        let connection = object.connect_typed_fn(signal, move |(_, first, last)| {
+            inserted_.borrow_mut().push((first, last))
+        });
+        assert!(connection.is_valid());
+
+        object.get_private().push("a");
+        object.get_private().push("b");
+
+        assert_eq!(vec![(0, 0), (1, 1)], *inserted.borrow());
+        assert_eq!(2, object.get_private().row_count(&QModelIndex::new()));
+    }
+
+    #[test]
+    fn check_create_index() {
+        let mut object = TestObject::new();
+        let index = object.create_index(2, 0, 42);
+
+        assert!(index.is_valid());
+        assert_eq!(2, index.row());
+        assert_eq!(0, index.column());
+        assert_eq!(42, index.internal_id());
+    }
+
+    #[test]
+    fn check_data_changed() {
+        let mut object = TestObject::new();
+        let changed = Rc::new(RefCell::new(vec![]));
+
+        let changed_ = changed.clone();
+        let connection = object.connect_typed_fn(
+            TestObject::data_changed_signal(),
+            move |(top_left, bottom_right, _)| {
+                changed_
+                    .borrow_mut()
+                    .push((top_left.row(), bottom_right.row()))
+            },
+        );
+        assert!(connection.is_valid());
+
+        object.get_private().push("a");
+        object.get_private().set(0, "b");
+
+        assert_eq!(vec![(0, 0)], *changed.borrow());
+    }
+
+    #[test]
+    fn check_reset_model() {
+        let mut object = TestObject::new();
+        let resets = Rc::new(RefCell::new(0));
+
+        let resets_ = resets.clone();
+        let signal: TypedSignal<()> = unsafe { qt5qml::typed_signal!("modelReset()") };
+        let connection = object.connect_typed_fn(signal, move |()| *resets_.borrow_mut() += 1);
+        assert!(connection.is_valid());
+
+        object.get_private().push("a");
+        object.get_private().clear();
+
+        assert_eq!(1, *resets.borrow());
+        assert_eq!(0, object.get_private().row_count(&QModelIndex::new()));
+    }
+
+    #[test]
+    fn check_sort_filter_proxy() {
+        let mut object = TestObject::new();
+        object.get_private().push("a");
+        object.get_private().push("bb");
+        object.get_private().push("c");
+
+        let mut proxy = RustSortFilterProxyModel::new();
+        proxy.set_source_model(QAbstractItemModel::cast_mut(object.as_qobject_mut()).unwrap());
+        proxy.set_filter(|index| String::from(&unsafe { index.data(0) }).len() == 1);
+
+        let model = proxy.as_item_model();
+        assert_eq!(2, model.row_count(&QModelIndex::new()));
+        let index = model.index(1, 0, &QModelIndex::new());
+        assert_eq!("c", String::from(&model.data(&index, 0)));
+    }
+}
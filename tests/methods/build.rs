@@ -1,16 +1,11 @@
-use qobject_compiler::moc::MocConfig;
 use qobject_compiler::typeref::{QObject, QString};
-use qobject_compiler::{CcBuild, Include, QObjectBuild, QObjectMethod, TypeRef};
+use qobject_compiler::{Include, QObjectBuild, QObjectMethod, QtInstallation, TypeRef};
 
 fn main() {
-    let config = pkg_config::probe_library("Qt5Core").unwrap();
-
-    let mut moc = MocConfig::new();
-    let mut cpp = CcBuild::new();
-    for include in &config.include_paths {
-        cpp.include(include);
-        moc.include_path(include);
-    }
+    let qt = QtInstallation::find(&["Core"]).unwrap();
+    qt.link();
+    let moc = qt.moc_config();
+    let cpp = qt.cc_build();
 
     QObjectBuild::new("TestObject")
         .method(QObjectMethod::new("methodSafeReturn").ret::<i32>())
@@ -42,6 +37,11 @@ fn main() {
                 .proxy("QObject")
                 .ret::<QString>(),
         )
+        .method(
+            QObjectMethod::new("setObjectName")
+                .proxy("QObject")
+                .arg::<QString>("name"),
+        )
         .method(QObjectMethod::new("dumpObjectInfo").proxy("QObject"))
         .qml(false)
         .build(&cpp, &moc);
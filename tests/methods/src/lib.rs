@@ -13,27 +13,29 @@ pub struct TestObjectPrivate {
     _qobject: *mut TestObject,
 }
 
-impl TestObjectPrivate {
-    pub fn new(qobject: *mut TestObject) -> Self {
+impl TestObjectImpl for TestObjectPrivate {
+    fn new(qobject: *mut TestObject) -> Self {
         Self { _qobject: qobject }
     }
 
-    pub fn method_safe_return(&mut self) -> i32 {
+    fn method_safe_return(&mut self) -> i32 {
         42
     }
 
-    pub fn method_unsafe_return(&mut self) -> QString {
+    fn method_unsafe_return(&mut self) -> QString {
         "Hello".into()
     }
 
-    pub fn method_with_args(&mut self, _value1: &QString, value2: u64) -> i32 {
+    fn method_with_args(&mut self, _value1: &QString, value2: u64) -> i32 {
         value2 as i32
     }
 
-    pub fn custom_event(&mut self, _arg: *mut c_void) {
+    fn custom_event(&mut self, _arg: *mut c_void) {
         unimplemented!()
     }
+}
 
+impl TestObjectPrivate {
     pub fn sender(&self) -> *mut QObject {
         unsafe { &mut *self._qobject }.sender()
     }
@@ -42,6 +44,10 @@ impl TestObjectPrivate {
         unsafe { &mut *self._qobject }.object_name()
     }
 
+    pub fn set_object_name(&mut self, name: QString) {
+        unsafe { &mut *self._qobject }.set_object_name(name)
+    }
+
     pub fn dump_object_info(&self) {
         unsafe { &mut *self._qobject }.dump_object_info()
     }
@@ -91,6 +97,15 @@ mod tests {
         assert_eq!("", &private.object_name().to_string());
     }
 
+    #[test]
+    fn check_proxy_by_value_arg() {
+        let mut object = TestObject::new();
+        let private = object.get_private();
+
+        private.set_object_name("name".to_qstring());
+        assert_eq!("name", &private.object_name().to_string());
+    }
+
     #[test]
     fn check_proxy_void_return() {
         let mut object = TestObject::new();
@@ -0,0 +1,16 @@
+use qobject_compiler::typeref::QString;
+use qobject_compiler::{PanicPolicy, QObjectBuild, QObjectMethod, QtInstallation};
+
+fn main() {
+    let qt = QtInstallation::find(&["Core"]).unwrap();
+    qt.link();
+    let moc = qt.moc_config();
+    let cpp = qt.cc_build();
+
+    QObjectBuild::new("TestObject")
+        .slot(QObjectMethod::new("succeed").ret::<QString>())
+        .slot(QObjectMethod::new("fail").ret::<QString>())
+        .panic_policy(PanicPolicy::Hook("crate::record_panic".into()))
+        .qml(false)
+        .build(&cpp, &moc);
+}
@@ -0,0 +1,68 @@
+#![allow(unused)]
+
+use qt5qml::core::{QMetaObject, QObjectRef, QString};
+use qt5qml::cstr;
+use qt5qml::panic::panic_message;
+use std::any::Any;
+use std::cell::RefCell;
+
+include!(concat!(env!("OUT_DIR"), "/qffi_TestObject.rs"));
+
+thread_local! {
+    static PANICS: RefCell<Vec<String>> = const { RefCell::new(vec![]) };
+}
+
+pub fn record_panic(function: &str, payload: Box<dyn Any + Send>) {
+    let message = format!("{}: {}", function, panic_message(&*payload));
+    PANICS.with(|panics| panics.borrow_mut().push(message));
+}
+
+pub struct TestObjectPrivate {
+    _qobject: *mut TestObject,
+}
+
+impl TestObjectImpl for TestObjectPrivate {
+    fn new(qobject: *mut TestObject) -> Self {
+        Self { _qobject: qobject }
+    }
+
+    fn succeed(&mut self) -> QString {
+        "succeeded".into()
+    }
+
+    fn fail(&mut self) -> QString {
+        panic!("failed on purpose")
+    }
+}
+
+fn invoke(object: &mut TestObject, method: &std::ffi::CStr) -> String {
+    let mut ret = QString::from("not called");
+    let success = unsafe {
+        QMetaObject::build_invoke_method(object.as_qobject_mut(), method)
+            .ret::<QString>(&mut ret)
+            .invoke()
+    };
+    assert!(success);
+    ret.to_string()
+}
+
+#[test]
+fn no_panic() {
+    let mut object = TestObject::new();
+
+    assert_eq!("succeeded", invoke(&mut object, cstr!("succeed")));
+    PANICS.with(|panics| assert!(panics.borrow().is_empty()));
+}
+
+#[test]
+fn panic_is_forwarded_to_hook() {
+    let mut object = TestObject::new();
+
+    assert_eq!("", invoke(&mut object, cstr!("fail")));
+    PANICS.with(|panics| {
+        assert_eq!(
+            vec!["Qffi_TestObject_fail: failed on purpose"],
+            *panics.borrow()
+        )
+    });
+}
@@ -1,16 +1,11 @@
-use qobject_compiler::moc::MocConfig;
-use qobject_compiler::typeref::{QString, TypeRef, TypeRefTrait};
-use qobject_compiler::{CcBuild, QObjectBuild, QObjectMethod, QObjectProp};
+use qobject_compiler::typeref::{QList, QMap, QString, QVariant, TypeRef, TypeRefTrait};
+use qobject_compiler::{QObjectBuild, QObjectMethod, QObjectProp, QObjectSignal, QtInstallation};
 
 fn main() {
-    let config = pkg_config::probe_library("Qt5Core").unwrap();
-
-    let mut moc = MocConfig::new();
-    let mut cpp = CcBuild::new();
-    for include in &config.include_paths {
-        cpp.include(include);
-        moc.include_path(include);
-    }
+    let qt = QtInstallation::find(&["Core"]).unwrap();
+    qt.link();
+    let moc = qt.moc_config();
+    let cpp = qt.cc_build();
 
     QObjectBuild::new("TestObject")
         .property(
@@ -19,9 +14,41 @@ fn main() {
                 .write("set_prop_rw"),
         )
         .property(QObjectProp::new_with_type(TypeRef::qstring(), "prop_r").read("prop_r"))
+        .property(
+            QObjectProp::new::<i32>("prop_attrs")
+                .read("prop_rw")
+                .write("set_prop_rw")
+                .reset("reset_prop_rw")
+                .revision(1)
+                .designable(false)
+                .stored(false)
+                .user()
+                .final_(),
+        )
+        .property(QObjectProp::new::<i32>("prop_member").member("m_prop_member"))
+        .property(
+            QObjectProp::new::<i32>("propAuto")
+                .auto()
+                .notify("propAutoChanged"),
+        )
+        .property(
+            QObjectProp::new::<QList<i32>>("prop_list")
+                .read("prop_list")
+                .write("set_prop_list"),
+        )
+        .property(QObjectProp::new::<QMap<QString, QVariant>>("prop_map").read("prop_map"))
+        .signal(QObjectSignal::new("propAutoChanged"))
         .method(QObjectMethod::new("prop_r").ret::<QString>())
         .method(QObjectMethod::new("prop_rw").ret::<i32>())
         .method(QObjectMethod::new("set_prop_rw").arg::<i32>("value"))
+        .method(QObjectMethod::new("reset_prop_rw"))
+        .method(QObjectMethod::new("prop_list").ret::<QList<i32>>().const_())
+        .method(QObjectMethod::new("set_prop_list").arg::<&QList<i32>>("value"))
+        .method(
+            QObjectMethod::new("prop_map")
+                .ret::<QMap<QString, QVariant>>()
+                .const_(),
+        )
         .qml(false)
         .build(&cpp, &moc);
 }
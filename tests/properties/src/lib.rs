@@ -5,36 +5,58 @@ include!(concat!(env!("OUT_DIR"), "/qffi_TestObject.rs"));
 pub struct TestObjectPrivate {
     _qobject: *mut TestObject,
     prop_rw: i32,
+    prop_list: qt5qml::core::QList<i32>,
 }
 
-impl TestObjectPrivate {
-    pub fn new(qobject: *mut TestObject) -> Self {
+impl TestObjectImpl for TestObjectPrivate {
+    fn new(qobject: *mut TestObject) -> Self {
         Self {
             _qobject: qobject,
             prop_rw: 42,
+            prop_list: vec![1, 2].into(),
         }
     }
 
-    pub fn prop_r(&self) -> qt5qml::core::QString {
+    fn prop_r(&self) -> qt5qml::core::QString {
         "Hello Qt!".into()
     }
 
-    pub fn prop_rw(&self) -> i32 {
+    fn prop_rw(&self) -> i32 {
         self.prop_rw
     }
 
-    pub fn set_prop_rw(&mut self, value: i32) {
+    fn set_prop_rw(&mut self, value: i32) {
         self.prop_rw = value;
     }
+
+    fn reset_prop_rw(&mut self) {
+        self.prop_rw = 42;
+    }
+
+    fn prop_list(&self) -> qt5qml::core::QList<i32> {
+        self.prop_list.clone()
+    }
+
+    fn set_prop_list(&mut self, value: &qt5qml::core::QList<i32>) {
+        self.prop_list = value.clone();
+    }
+
+    fn prop_map(&self) -> qt5qml::core::QVariantMap {
+        let mut map = qt5qml::core::QVariantMap::new();
+        map.insert(&"answer".into(), &42.into());
+        map
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use core::ptr;
-    use qt5qml::core::{QMetaObject, QMetaProperty, QObjectRef, QVariant};
+    use qt5qml::core::{ConnectionType, QMetaObject, QMetaProperty, QObjectRef, QTimer, QVariant};
+    use qt5qml::signal;
     use std::collections::HashMap;
     use std::ffi::CString;
+    use std::time::Duration;
 
     fn get_props(obj: &QMetaObject) -> HashMap<String, QMetaProperty> {
         obj.own_properties()
@@ -115,4 +137,102 @@ mod tests {
             .unwrap()
             .write(obj.as_qobject_mut(), &value));
     }
+
+    #[test]
+    fn default_attributes() {
+        let obj = TestObject::new();
+        let props = get_props(obj.meta_object());
+        let prop = props.get("prop_rw").unwrap();
+
+        assert!(!prop.is_resettable());
+        assert!(prop.is_designable());
+        assert!(prop.is_scriptable());
+        assert!(prop.is_stored());
+        assert!(!prop.is_user());
+        assert!(!prop.is_final());
+        assert_eq!(0, prop.revision());
+    }
+
+    #[test]
+    fn attributes() {
+        let obj = TestObject::new();
+        let props = get_props(obj.meta_object());
+        let prop = props.get("prop_attrs").unwrap();
+
+        assert!(prop.is_resettable());
+        assert!(!prop.is_designable());
+        assert!(!prop.is_stored());
+        assert!(prop.is_user());
+        assert!(prop.is_final());
+        assert_eq!(1, prop.revision());
+    }
+
+    #[test]
+    fn reset_prop() {
+        let mut obj = TestObject::new();
+        let props = get_props(obj.meta_object());
+        let prop = props.get("prop_attrs").unwrap();
+
+        assert!(prop.write(obj.as_qobject_mut(), &5.into()));
+        assert_eq!(prop.read(obj.as_qobject()), 5.into());
+        assert!(prop.reset(obj.as_qobject_mut()));
+        assert_eq!(prop.read(obj.as_qobject()), 42.into());
+    }
+
+    #[test]
+    fn member_prop() {
+        let mut obj = TestObject::new();
+        let props = get_props(obj.meta_object());
+        let prop = props.get("prop_member").unwrap();
+
+        assert_eq!(prop.read(obj.as_qobject()), 0.into());
+        assert!(prop.write(obj.as_qobject_mut(), &7.into()));
+        assert_eq!(prop.read(obj.as_qobject()), 7.into());
+    }
+
+    #[test]
+    fn container_props() {
+        let mut obj = TestObject::new();
+        let props = get_props(obj.meta_object());
+        let list = props.get("prop_list").unwrap();
+        let map = props.get("prop_map").unwrap();
+
+        let value = list.read(obj.as_qobject());
+        assert!(value.is_valid());
+        assert!(list.write(obj.as_qobject_mut(), &value));
+        assert_eq!(list.read(obj.as_qobject()), value);
+        assert!(map.read(obj.as_qobject()).is_valid());
+    }
+
+    #[test]
+    fn auto_prop() {
+        let mut obj = TestObject::new();
+        let props = get_props(obj.meta_object());
+        let prop = props.get("propAuto").unwrap();
+
+        assert_eq!(0, obj.prop_auto());
+        obj.set_prop_auto(3);
+        assert_eq!(3, obj.prop_auto());
+        assert_eq!(prop.read(obj.as_qobject()), 3.into());
+        assert!(prop.write(obj.as_qobject_mut(), &4.into()));
+        assert_eq!(4, obj.prop_auto());
+    }
+
+    #[test]
+    fn auto_prop_notifies_on_change() {
+        let mut obj = TestObject::new();
+        let mut timer = QTimer::new();
+        timer.set_interval(Duration::from_secs(10));
+        obj.connect(
+            signal!("propAutoChanged()"),
+            timer.as_qobject(),
+            QTimer::start_slot(),
+            ConnectionType::default(),
+        );
+
+        obj.set_prop_auto(0);
+        assert!(!timer.is_active());
+        obj.set_prop_auto(1);
+        assert!(timer.is_active());
+    }
 }
@@ -0,0 +1,13 @@
+use qobject_compiler::{QObjectBuild, QObjectProp, QtInstallation};
+
+fn main() {
+    let qt = QtInstallation::find(&["Core", "Qml"]).unwrap();
+    qt.link();
+    let moc = qt.moc_config();
+    let cpp = qt.cc_build();
+
+    QObjectBuild::new("TestObject")
+        .property(QObjectProp::new::<i32>("number").auto())
+        .qml(true)
+        .build(&cpp, &moc);
+}
@@ -0,0 +1,58 @@
+#![allow(unused)]
+
+include!(concat!(env!("OUT_DIR"), "/qffi_TestObject.rs"));
+
+pub struct TestObjectPrivate;
+
+impl TestObjectImpl for TestObjectPrivate {
+    fn new(_qobject: *mut TestObject) -> Self {
+        Self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qt5qml::core::{QApplicationFactory, QCoreApplication, QObject, QObjectRef};
+    use qt5qml::cstr;
+    use qt5qml::qml::QQmlEngine;
+    use qt5qml::QBox;
+    use std::convert::TryInto;
+
+    fn first() -> QBox<TestObject> {
+        let mut obj = TestObject::new();
+        obj.set_number(1);
+        obj
+    }
+
+    fn second() -> QBox<TestObject> {
+        let mut obj = TestObject::new();
+        obj.set_number(2);
+        obj
+    }
+
+    fn number(obj: *mut QObject) -> i32 {
+        let obj = unsafe { &*obj };
+        let prop = obj
+            .meta_object()
+            .own_properties()
+            .find(|prop| prop.name().to_bytes() == b"number")
+            .unwrap();
+        prop.read(obj).try_into().unwrap()
+    }
+
+    #[test]
+    fn register_two_singletons() {
+        let _app = QCoreApplication::new_from_env_args();
+
+        let first_id =
+            TestObject::register_singleton(cstr!("test.first"), 1, 0, cstr!("Test"), first);
+        let second_id =
+            TestObject::register_singleton(cstr!("test.second"), 1, 0, cstr!("Test"), second);
+        assert_ne!(first_id, second_id);
+
+        let mut engine = QQmlEngine::new();
+        assert_eq!(1, number(engine.singleton_instance(first_id)));
+        assert_eq!(2, number(engine.singleton_instance(second_id)));
+    }
+}
@@ -1,18 +1,15 @@
-use qobject_compiler::moc::MocConfig;
-use qobject_compiler::{CcBuild, QObjectBuild, QObjectSignal};
+use qobject_compiler::{QObjectBuild, QObjectSignal, QtInstallation};
 
 fn main() {
-    let config = pkg_config::probe_library("Qt5Core").unwrap();
-
-    let mut moc = MocConfig::new();
-    let mut cpp = CcBuild::new();
-    for include in &config.include_paths {
-        cpp.include(include);
-        moc.include_path(include);
-    }
+    let qt = QtInstallation::find(&["Core"]).unwrap();
+    qt.link();
+    let moc = qt.moc_config();
+    let cpp = qt.cc_build();
 
     QObjectBuild::new("TestObject")
         .signal(QObjectSignal::new("signal0"))
+        .signal(QObjectSignal::new("signal1").arg::<i32>("value"))
+        .signal(QObjectSignal::new("nameChanged").arg::<String>("name"))
         .qml(false)
         .build(&cpp, &moc);
 }
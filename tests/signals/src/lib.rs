@@ -6,11 +6,13 @@ pub struct TestObjectPrivate {
     qobject: *mut TestObject,
 }
 
-impl TestObjectPrivate {
-    pub fn new(qobject: *mut TestObject) -> Self {
+impl TestObjectImpl for TestObjectPrivate {
+    fn new(qobject: *mut TestObject) -> Self {
         Self { qobject }
     }
+}
 
+impl TestObjectPrivate {
     pub fn emit_signal_0(&mut self) {
         unsafe { (&mut *self.qobject).signal0() };
     }
@@ -23,3 +25,40 @@ fn test_signal() {
     let mut object = TestObject::new();
     object.signal0();
 }
+
+#[test]
+fn test_typed_signal() {
+    use qt5qml::core::QObjectRef;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let mut object = TestObject::new();
+    let value = Rc::new(Cell::new(0));
+
+    let value_ = value.clone();
+    let connection =
+        object.connect_typed_fn(TestObject::signal1_signal(), move |(v,)| value_.set(v));
+    assert!(connection.is_valid());
+
+    object.signal1(42);
+    assert_eq!(42, value.get());
+}
+
+#[test]
+fn test_signal_with_rust_type() {
+    use qt5qml::core::QObjectRef;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let mut object = TestObject::new();
+    let value = Rc::new(RefCell::new(String::new()));
+
+    let value_ = value.clone();
+    let connection = object.connect_typed_fn(TestObject::name_changed_signal(), move |(name,)| {
+        *value_.borrow_mut() = name.to_string()
+    });
+    assert!(connection.is_valid());
+
+    object.name_changed("name".to_string());
+    assert_eq!("name", *value.borrow());
+}
@@ -1,16 +1,11 @@
-use qobject_compiler::moc::MocConfig;
 use qobject_compiler::typeref::QString;
-use qobject_compiler::{CcBuild, QObjectBuild, QObjectMethod, QObjectProp};
+use qobject_compiler::{QObjectBuild, QObjectMethod, QObjectProp, QtInstallation};
 
 fn main() {
-    let config = pkg_config::probe_library("Qt5Core").unwrap();
-
-    let mut moc = MocConfig::new();
-    let mut cpp = CcBuild::new();
-    for include in &config.include_paths {
-        cpp.include(include);
-        moc.include_path(include);
-    }
+    let qt = QtInstallation::find(&["Core"]).unwrap();
+    qt.link();
+    let moc = qt.moc_config();
+    let cpp = qt.cc_build();
 
     QObjectBuild::new("TestObject")
         .property(QObjectProp::new::<i32>("slotCalls").read("slotCalls"))
@@ -22,6 +17,21 @@ fn main() {
                 .arg::<&QString>("arg")
                 .ret::<QString>(),
         )
+        .slot(
+            QObjectMethod::new("echoValueSlot")
+                .arg::<QString>("arg")
+                .ret::<QString>(),
+        )
+        .slot(
+            QObjectMethod::new("greet")
+                .arg::<&str>("name")
+                .ret::<String>(),
+        )
+        .slot(
+            QObjectMethod::new("orDefault")
+                .arg::<Option<QString>>("value")
+                .ret::<Option<QString>>(),
+        )
         .qml(false)
         .build(&cpp, &moc);
 }
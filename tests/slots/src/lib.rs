@@ -13,30 +13,45 @@ pub struct TestObjectPrivate {
     slot_calls: i32,
 }
 
-impl TestObjectPrivate {
-    pub fn new(qobject: *mut TestObject) -> Self {
+impl TestObjectImpl for TestObjectPrivate {
+    fn new(qobject: *mut TestObject) -> Self {
         Self {
             _qobject: qobject,
             slot_calls: 0,
         }
     }
 
-    pub fn slot_calls(&self) -> i32 {
+    fn slot_calls(&self) -> i32 {
         self.slot_calls
     }
 
-    pub fn slot(&mut self) {
+    fn slot(&mut self) {
         self.slot_calls += 1;
     }
 
-    pub fn slot_with_args(&mut self, _arg: &QString) {
+    fn slot_with_args(&mut self, _arg: &QString) {
         self.slot_calls += 1;
     }
 
-    pub fn echo_slot(&mut self, arg: &QString) -> QString {
+    fn echo_slot(&mut self, arg: &QString) -> QString {
         self.slot_calls += 1;
         QString::clone(arg)
     }
+
+    fn echo_value_slot(&mut self, arg: QString) -> QString {
+        self.slot_calls += 1;
+        arg
+    }
+
+    fn greet(&mut self, name: &str) -> String {
+        self.slot_calls += 1;
+        format!("Hello {}!", name)
+    }
+
+    fn or_default(&mut self, value: Option<QString>) -> Option<QString> {
+        self.slot_calls += 1;
+        value.or_else(|| Some("default".into()))
+    }
 }
 
 fn get_props(obj: &QMetaObject) -> HashMap<String, QMetaProperty> {
@@ -103,3 +118,54 @@ fn check_invoke_method_rtype() {
         assert_eq!("<!>", ret.to_string());
     }
 }
+
+#[test]
+fn check_invoke_method_by_value_arg() {
+    unsafe {
+        let mut object = TestObject::new();
+        let mut ret: QString = QString::new();
+        let success =
+            QMetaObject::build_invoke_method(object.as_qobject_mut(), cstr!("echoValueSlot"))
+                .arg::<QString>(&"<!>".to_qstring())
+                .ret::<QString>(&mut ret)
+                .invoke();
+        assert!(success);
+        assert_eq!(1, slot_calls(object.as_qobject_mut()));
+        assert_eq!("<!>", ret.to_string());
+    }
+}
+
+#[test]
+fn check_invoke_method_rust_types() {
+    unsafe {
+        let mut object = TestObject::new();
+        let mut ret: QString = QString::new();
+        let success = QMetaObject::build_invoke_method(object.as_qobject_mut(), cstr!("greet"))
+            .arg::<QString>(&"World".to_qstring())
+            .ret::<QString>(&mut ret)
+            .invoke();
+        assert!(success);
+        assert_eq!("Hello World!", ret.to_string());
+    }
+}
+
+#[test]
+fn check_invoke_method_option() {
+    unsafe {
+        let mut object = TestObject::new();
+        let mut ret: QString = QString::new();
+        let success = QMetaObject::build_invoke_method(object.as_qobject_mut(), cstr!("orDefault"))
+            .arg::<QString>(&QString::new())
+            .ret::<QString>(&mut ret)
+            .invoke();
+        assert!(success);
+        assert_eq!("default", ret.to_string());
+
+        let success = QMetaObject::build_invoke_method(object.as_qobject_mut(), cstr!("orDefault"))
+            .arg::<QString>(&"value".to_qstring())
+            .ret::<QString>(&mut ret)
+            .invoke();
+        assert!(success);
+        assert_eq!("value", ret.to_string());
+    }
+}
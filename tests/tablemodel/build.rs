@@ -0,0 +1,60 @@
+use qobject_compiler::typeref::{ItemFlags, Orientation, QModelIndex, QVariant, TypeRef};
+use qobject_compiler::{QObjectBuild, QObjectMethod, QtInstallation};
+
+fn main() {
+    let qt = QtInstallation::find(&["Core"]).unwrap();
+    qt.link();
+    let moc = qt.moc_config();
+    let cpp = qt.cc_build();
+
+    QObjectBuild::new("TestObject")
+        .inherit(TypeRef::qt_core_object("QAbstractTableModel"))
+        .method(
+            QObjectMethod::new("rowCount")
+                .const_()
+                .override_()
+                .arg::<&QModelIndex>("parent")
+                .ret::<i32>(),
+        )
+        .method(
+            QObjectMethod::new("columnCount")
+                .const_()
+                .override_()
+                .arg::<&QModelIndex>("parent")
+                .ret::<i32>(),
+        )
+        .method(
+            QObjectMethod::new("data")
+                .const_()
+                .override_()
+                .arg::<&QModelIndex>("index")
+                .arg::<i32>("role")
+                .ret::<QVariant>(),
+        )
+        .method(
+            QObjectMethod::new("headerData")
+                .const_()
+                .override_()
+                .arg::<i32>("section")
+                .arg::<Orientation>("orientation")
+                .arg::<i32>("role")
+                .ret::<QVariant>(),
+        )
+        .method(
+            QObjectMethod::new("setData")
+                .override_()
+                .arg::<&QModelIndex>("index")
+                .arg::<&QVariant>("value")
+                .arg::<i32>("role")
+                .ret::<bool>(),
+        )
+        .method(
+            QObjectMethod::new("flags")
+                .const_()
+                .override_()
+                .arg::<&QModelIndex>("index")
+                .ret::<ItemFlags>(),
+        )
+        .qml(false)
+        .build(&cpp, &moc);
+}
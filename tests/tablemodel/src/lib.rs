@@ -0,0 +1,289 @@
+#![allow(unused)]
+
+use qt5qml::core::{
+    ItemFlags, Orientation, QModelIndex, QVariant, QVector, QT_DISPLAY_ROLE, QT_EDIT_ROLE,
+};
+include!(concat!(env!("OUT_DIR"), "/qffi_TestObject.rs"));
+
+const HEADERS: [&str; 2] = ["Name", "Value"];
+
+pub struct TestObjectPrivate {
+    qobject: *mut TestObject,
+    rows: Vec<[String; 2]>,
+}
+
+impl TestObjectImpl for TestObjectPrivate {
+    fn new(qobject: *mut TestObject) -> Self {
+        Self {
+            qobject,
+            rows: vec![],
+        }
+    }
+
+    fn row_count(&self, parent: &QModelIndex) -> i32 {
+        if parent.is_valid() {
+            0
+        } else {
+            self.rows.len() as i32
+        }
+    }
+
+    fn column_count(&self, parent: &QModelIndex) -> i32 {
+        if parent.is_valid() {
+            0
+        } else {
+            HEADERS.len() as i32
+        }
+    }
+
+    fn data(&self, index: &QModelIndex, role: i32) -> QVariant {
+        if role != QT_DISPLAY_ROLE && role != QT_EDIT_ROLE {
+            return QVariant::new();
+        }
+
+        match self.rows.get(index.row() as usize) {
+            Some(row) => (&row[index.column() as usize] as &str).into(),
+            None => QVariant::new(),
+        }
+    }
+
+    fn header_data(&self, section: i32, orientation: Orientation, role: i32) -> QVariant {
+        if role != QT_DISPLAY_ROLE {
+            return QVariant::new();
+        }
+
+        match orientation {
+            Orientation::HORIZONTAL => HEADERS.get(section as usize).copied().into(),
+            Orientation::VERTICAL => (section + 1).to_string().into(),
+            _ => QVariant::new(),
+        }
+    }
+
+    fn set_data(&mut self, index: &QModelIndex, value: &QVariant, role: i32) -> bool {
+        if role != QT_EDIT_ROLE || !self.flags(index).contains(ItemFlags::ITEM_IS_EDITABLE) {
+            return false;
+        }
+
+        self.rows[index.row() as usize][index.column() as usize] = value.into();
+        let qobject = unsafe { &mut *self.qobject };
+        qobject.data_changed(index, index, &QVector::new());
+        true
+    }
+
+    fn flags(&self, index: &QModelIndex) -> ItemFlags {
+        let flags = ItemFlags::ITEM_IS_SELECTABLE | ItemFlags::ITEM_IS_ENABLED;
+        if !index.is_valid() {
+            ItemFlags::NO_ITEM_FLAGS
+        } else if index.column() == 1 {
+            flags | ItemFlags::ITEM_IS_EDITABLE
+        } else {
+            flags
+        }
+    }
+}
+
+impl TestObjectPrivate {
+    pub fn push(&mut self, name: &str, value: &str) {
+        let row = self.rows.len() as i32;
+        let qobject = unsafe { &mut *self.qobject };
+        qobject.begin_insert_rows(&QModelIndex::new(), row, row);
+        self.rows.push([name.into(), value.into()]);
+        qobject.end_insert_rows();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qt5qml::core::{QAbstractItemModel, QMetaObject, QObjectRef};
+    use qt5qml::cstr;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn create_table() -> qt5qml::QBox<TestObject> {
+        let mut object = TestObject::new();
+        object.get_private().push("a", "1");
+        object.get_private().push("b", "2");
+        object
+    }
+
+    fn invoke_index(object: &mut TestObject, row: i32, column: i32) -> QModelIndex {
+        let mut index = QModelIndex::new();
+        let success = unsafe {
+            QMetaObject::build_invoke_method(object.as_qobject_mut(), cstr!("index"))
+                .arg(&row)
+                .arg(&column)
+                .arg(&QModelIndex::new())
+                .ret(&mut index)
+                .invoke()
+        };
+        assert!(success);
+        index
+    }
+
+    fn invoke_data(object: &mut TestObject, index: &QModelIndex, role: i32) -> String {
+        let mut data = QVariant::new();
+        let success = unsafe {
+            QMetaObject::build_invoke_method(object.as_qobject_mut(), cstr!("data"))
+                .arg(index)
+                .arg(&role)
+                .ret(&mut data)
+                .invoke()
+        };
+        assert!(success);
+        String::from(&data)
+    }
+
+    fn invoke_header_data(
+        object: &mut TestObject,
+        section: i32,
+        orientation: Orientation,
+    ) -> String {
+        let mut data = QVariant::new();
+        let success = unsafe {
+            QMetaObject::build_invoke_method(object.as_qobject_mut(), cstr!("headerData"))
+                .arg(&section)
+                .arg(&orientation)
+                .arg(&QT_DISPLAY_ROLE)
+                .ret(&mut data)
+                .invoke()
+        };
+        assert!(success);
+        String::from(&data)
+    }
+
+    #[test]
+    fn check_dimensions() {
+        let mut object = create_table();
+
+        let mut rows: i32 = 0;
+        let success = unsafe {
+            QMetaObject::build_invoke_method(object.as_qobject_mut(), cstr!("rowCount"))
+                .arg(&QModelIndex::new())
+                .ret(&mut rows)
+                .invoke()
+        };
+        assert!(success);
+        assert_eq!(2, rows);
+
+        let mut columns: i32 = 0;
+        let success = unsafe {
+            QMetaObject::build_invoke_method(object.as_qobject_mut(), cstr!("columnCount"))
+                .arg(&QModelIndex::new())
+                .ret(&mut columns)
+                .invoke()
+        };
+        assert!(success);
+        assert_eq!(2, columns);
+    }
+
+    #[test]
+    fn check_data() {
+        let mut object = create_table();
+
+        let index = invoke_index(&mut object, 1, 0);
+        assert_eq!(1, index.row());
+        assert_eq!(0, index.column());
+        assert_eq!("b", invoke_data(&mut object, &index, QT_DISPLAY_ROLE));
+
+        let index = invoke_index(&mut object, 0, 1);
+        assert_eq!("1", invoke_data(&mut object, &index, QT_EDIT_ROLE));
+
+        assert!(!invoke_index(&mut object, 2, 0).is_valid());
+        assert!(!invoke_index(&mut object, 0, 2).is_valid());
+    }
+
+    #[test]
+    fn check_header_data() {
+        let mut object = create_table();
+
+        assert_eq!(
+            "Name",
+            invoke_header_data(&mut object, 0, Orientation::HORIZONTAL)
+        );
+        assert_eq!(
+            "Value",
+            invoke_header_data(&mut object, 1, Orientation::HORIZONTAL)
+        );
+        assert_eq!(
+            "",
+            invoke_header_data(&mut object, 2, Orientation::HORIZONTAL)
+        );
+        assert_eq!(
+            "2",
+            invoke_header_data(&mut object, 1, Orientation::VERTICAL)
+        );
+    }
+
+    #[test]
+    fn check_flags() {
+        let mut object = create_table();
+
+        let index = invoke_index(&mut object, 0, 1);
+        let mut flags = ItemFlags::NO_ITEM_FLAGS;
+        let success = unsafe {
+            QMetaObject::build_invoke_method(object.as_qobject_mut(), cstr!("flags"))
+                .arg(&index)
+                .ret(&mut flags)
+                .invoke()
+        };
+        assert!(success);
+        assert!(flags.contains(ItemFlags::ITEM_IS_ENABLED | ItemFlags::ITEM_IS_EDITABLE));
+
+        let index = invoke_index(&mut object, 0, 0);
+        unsafe {
+            assert!(!index.flags().contains(ItemFlags::ITEM_IS_EDITABLE));
+            assert_eq!(ItemFlags::NO_ITEM_FLAGS, QModelIndex::new().flags());
+        }
+    }
+
+    #[test]
+    fn check_set_data() {
+        let mut object = create_table();
+        let changed = Rc::new(RefCell::new(vec![]));
+
+        let changed_ = changed.clone();
+        let connection = object.connect_typed_fn(
+            TestObject::data_changed_signal(),
+            move |(top_left, _, _)| {
+                changed_
+                    .borrow_mut()
+                    .push((top_left.row(), top_left.column()))
+            },
+        );
+        assert!(connection.is_valid());
+
+        for &(column, expected) in &[(1, true), (0, false)] {
+            let index = invoke_index(&mut object, 1, column);
+            let mut success = !expected;
+            let invoked = unsafe {
+                QMetaObject::build_invoke_method(object.as_qobject_mut(), cstr!("setData"))
+                    .arg(&index)
+                    .arg(&QVariant::from("3"))
+                    .arg(&QT_EDIT_ROLE)
+                    .ret(&mut success)
+                    .invoke()
+            };
+            assert!(invoked);
+            assert_eq!(expected, success);
+        }
+
+        assert_eq!(vec![(1, 1)], *changed.borrow());
+        let index = invoke_index(&mut object, 1, 1);
+        assert_eq!("3", invoke_data(&mut object, &index, QT_DISPLAY_ROLE));
+        let index = invoke_index(&mut object, 1, 0);
+        assert_eq!("b", invoke_data(&mut object, &index, QT_DISPLAY_ROLE));
+    }
+
+    #[test]
+    fn check_item_model() {
+        let object = create_table();
+        let model = QAbstractItemModel::cast(object.as_qobject()).unwrap();
+
+        assert_eq!(2, model.column_count(&QModelIndex::new()));
+        assert_eq!(
+            "Value",
+            String::from(&model.header_data(1, Orientation::HORIZONTAL, QT_DISPLAY_ROLE))
+        );
+    }
+}